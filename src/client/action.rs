@@ -0,0 +1,358 @@
+use crate::errors::SeqeraError;
+use crate::models::action::{
+    Action, CreateActionRequest, CreateActionResponse, DescribeActionResponse, LaunchActionRequest,
+    LaunchActionResponse, ListActionsResponse, UpdateActionRequest,
+};
+use crate::models::common::{Validate, WorkspaceId};
+
+impl super::SeqeraClient {
+    pub async fn list_actions(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+    ) -> Result<ListActionsResponse, SeqeraError> {
+        let mut url = self.base_url.join("actions")?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string())
+            .append_pair("attributes", "labels");
+
+        let request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        Ok(response.json().await?)
+    }
+
+    pub async fn get_action(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        action_id: &str,
+    ) -> Result<Action, SeqeraError> {
+        let mut url = self.base_url.join(&format!("actions/{}", action_id))?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string());
+
+        let request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        let wrapper: DescribeActionResponse = response.json().await?;
+        Ok(wrapper.action)
+    }
+
+    pub async fn find_action_by_name(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        name: &str,
+    ) -> Result<Option<Action>, SeqeraError> {
+        let workspace_id = workspace_id.into();
+        let actions = self.list_actions(workspace_id).await?;
+        match actions.actions.into_iter().find(|a| a.name == name) {
+            Some(summary) => Ok(Some(self.get_action(workspace_id, &summary.id).await?)),
+            None => Ok(None),
+        }
+    }
+
+    pub async fn validate_action_name(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        name: &str,
+    ) -> Result<(), SeqeraError> {
+        let mut url = self.base_url.join("actions/validate")?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string())
+            .append_pair("name", name);
+
+        let request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let _ = self.handle_response(request).await?;
+        Ok(())
+    }
+
+    /// Creates an action and returns its ID
+    pub async fn create_action(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        request: CreateActionRequest,
+    ) -> Result<String, SeqeraError> {
+        request.validate()?;
+        let mut url = self.base_url.join("actions")?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string());
+
+        let request = self.client
+            .post(url)
+            .header("Authorization", self.auth_header())
+            .json(&request)
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        let wrapper: CreateActionResponse = response.json().await?;
+        Ok(wrapper.action_id)
+    }
+
+    pub async fn update_action(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        action_id: &str,
+        request: UpdateActionRequest,
+    ) -> Result<(), SeqeraError> {
+        request.validate()?;
+        let mut url = self.base_url.join(&format!("actions/{}", action_id))?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string());
+
+        let request = self.client
+            .put(url)
+            .header("Authorization", self.auth_header())
+            .json(&request)
+            .build()?;
+
+        let _ = self.handle_response(request).await?;
+        Ok(())
+    }
+
+    /// Pauses an action so that events no longer launch it
+    pub async fn pause_action(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        action_id: &str,
+    ) -> Result<(), SeqeraError> {
+        self.set_action_paused(workspace_id.into(), action_id, true).await
+    }
+
+    pub async fn resume_action(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        action_id: &str,
+    ) -> Result<(), SeqeraError> {
+        self.set_action_paused(workspace_id.into(), action_id, false).await
+    }
+
+    async fn set_action_paused(
+        &self,
+        workspace_id: WorkspaceId,
+        action_id: &str,
+        pause: bool,
+    ) -> Result<(), SeqeraError> {
+        let mut url = self.base_url.join(&format!("actions/{}/pause", action_id))?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.0.to_string())
+            .append_pair("pause", &pause.to_string());
+
+        let request = self.client
+            .post(url)
+            .header("Authorization", self.auth_header())
+            .json(&serde_json::json!({}))
+            .build()?;
+
+        let _ = self.handle_response(request).await?;
+        Ok(())
+    }
+
+    pub async fn delete_action(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        action_id: &str,
+    ) -> Result<(), SeqeraError> {
+        let mut url = self.base_url.join(&format!("actions/{}", action_id))?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string());
+
+        let request = self.client
+            .delete(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let _ = self.handle_response(request).await?;
+        Ok(())
+    }
+
+    /// Triggers a Tower webhook action, overriding pipeline parameters with
+    /// `params`. Returns the ID of the launched workflow.
+    pub async fn trigger_action(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        action_id: &str,
+        params: serde_json::Map<String, serde_json::Value>,
+    ) -> Result<String, SeqeraError> {
+        let mut url = self.base_url.join(&format!("actions/{}/launch", action_id))?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string());
+
+        let request = self.client
+            .post(url)
+            .header("Authorization", self.auth_header())
+            .json(&LaunchActionRequest { params })
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        let wrapper: LaunchActionResponse = response.json().await?;
+        Ok(wrapper.workflow_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::action::{ActionLaunch, ActionSource, ActionStatus};
+    use serde_json::json;
+    use wiremock::matchers::{body_json, header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn action_body() -> serde_json::Value {
+        json!({
+            "id": "act-1",
+            "name": "rnaseq-ci",
+            "source": "tower",
+            "status": "ACTIVE",
+            "hookId": "hook-1",
+            "hookUrl": "https://api.cloud.seqera.io/actions/act-1/launch?workspaceId=123",
+            "launch": {
+                "computeEnvId": "ce-1",
+                "pipeline": "https://github.com/nf-core/rnaseq",
+                "revision": "3.14.0"
+            },
+            "labels": []
+        })
+    }
+
+    #[tokio::test]
+    async fn test_list_and_find_actions() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/actions"))
+            .and(query_param("workspaceId", "123"))
+            .and(header("authorization", "Bearer test-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "actions": [{
+                    "id": "act-1",
+                    "name": "rnaseq-ci",
+                    "pipeline": "https://github.com/nf-core/rnaseq",
+                    "source": "tower",
+                    "status": "ACTIVE",
+                    "endpoint": "https://api.cloud.seqera.io/actions/act-1/launch?workspaceId=123"
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/actions/act-1"))
+            .and(query_param("workspaceId", "123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "action": action_body() })))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let actions = client.list_actions(123).await.unwrap().actions;
+        assert_eq!(actions[0].status, ActionStatus::Active);
+
+        let action = client.find_action_by_name(123, "rnaseq-ci").await.unwrap().unwrap();
+        assert_eq!(action.source, ActionSource::Tower);
+        assert_eq!(
+            action.hook_url.as_deref(),
+            Some("https://api.cloud.seqera.io/actions/act-1/launch?workspaceId=123")
+        );
+        assert_eq!(action.launch.revision.as_deref(), Some("3.14.0"));
+    }
+
+    #[tokio::test]
+    async fn test_create_action() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/actions"))
+            .and(query_param("workspaceId", "123"))
+            .and(body_json(json!({
+                "name": "rnaseq-push",
+                "source": "github",
+                "launch": { "pipeline": "https://github.com/nf-core/rnaseq", "computeEnvId": "ce-1" }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "actionId": "act-2" })))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let launch = ActionLaunch {
+            compute_env_id: Some("ce-1".to_string()),
+            pipeline: "https://github.com/nf-core/rnaseq".to_string(),
+            ..Default::default()
+        };
+        let id = client
+            .create_action(123, CreateActionRequest::github("rnaseq-push", launch))
+            .await
+            .unwrap();
+        assert_eq!(id, "act-2");
+    }
+
+    #[tokio::test]
+    async fn test_pause_resume_and_delete_action() {
+        let mock_server = MockServer::start().await;
+
+        for pause in ["true", "false"] {
+            Mock::given(method("POST"))
+                .and(path("/actions/act-1/pause"))
+                .and(query_param("workspaceId", "123"))
+                .and(query_param("pause", pause))
+                .respond_with(ResponseTemplate::new(204))
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+        }
+
+        Mock::given(method("DELETE"))
+            .and(path("/actions/act-1"))
+            .and(query_param("workspaceId", "123"))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        assert!(client.pause_action(123, "act-1").await.is_ok());
+        assert!(client.resume_action(123, "act-1").await.is_ok());
+        assert!(client.delete_action(123, "act-1").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_trigger_action() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/actions/act-1/launch"))
+            .and(query_param("workspaceId", "123"))
+            .and(body_json(json!({ "params": { "input": "s3://bucket/samples.csv" } })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "workflowId": "wf-1" })))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let mut params = serde_json::Map::new();
+        params.insert("input".to_string(), json!("s3://bucket/samples.csv"));
+        let workflow_id = client.trigger_action(123, "act-1", params).await.unwrap();
+        assert_eq!(workflow_id, "wf-1");
+    }
+}
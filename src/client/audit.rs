@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+use crate::errors::SeqeraError;
+use crate::models::audit::{AccessMatrix, WorkspaceParticipants};
+use crate::models::common::ParticipantType;
+
+impl super::SeqeraClient {
+    /// Walks every workspace, participant, team and team member of an
+    /// organization and works out each user's effective role per workspace
+    pub async fn get_access_matrix(&self, org_id: i64) -> Result<AccessMatrix, SeqeraError> {
+        let org = self.get_organization(org_id).await?;
+        let org_members = self.list_all_organization_members(org_id, 100, None).await?;
+        let workspaces = self.list_workspaces(org_id).await?;
+
+        let mut participants = Vec::new();
+        let mut team_members = HashMap::new();
+        for workspace in workspaces.workspaces {
+            let workspace_participants = self.list_all_workspace_participants(org_id, workspace.id, 100).await?;
+            for participant in &workspace_participants {
+                let Some(team_id) = participant.team_id else { continue };
+                if participant.participant_type == ParticipantType::Team && !team_members.contains_key(&team_id) {
+                    let members = self.list_all_team_members(org_id, team_id, 100, None).await?;
+                    team_members.insert(team_id, members);
+                }
+            }
+            participants.push(WorkspaceParticipants {
+                workspace: workspace.name,
+                participants: workspace_participants,
+            });
+        }
+
+        Ok(AccessMatrix::build(org.name, &org_members, &participants, &team_members))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::common::WspRole;
+    use crate::models::member::fixtures::member_json;
+    use serde_json::json;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_get_access_matrix() {
+        let mock_server = MockServer::start().await;
+
+        let routes = [
+            ("/orgs/123", json!({ "organization": { "orgId": 123, "name": "acme" } })),
+            ("/orgs/123/members", json!({ "members": [member_json(1, "olivia", "owner")], "totalSize": 1 })),
+            ("/orgs/123/workspaces", json!({
+                "workspaces": [{ "id": 456, "name": "production", "fullName": "Production", "visibility": "PRIVATE" }]
+            })),
+            ("/orgs/123/workspaces/456/participants", json!({
+                "participants": [
+                    { "participantId": 1, "userName": "alice", "wspRole": "launch", "type": "MEMBER" },
+                    { "participantId": 2, "teamId": 7, "teamName": "bioinfo", "wspRole": "admin", "type": "TEAM" }
+                ],
+                "totalSize": 2
+            })),
+            ("/orgs/123/teams/7/members", json!({ "members": [member_json(2, "alice", "member")], "totalSize": 1 })),
+        ];
+        for (route, body) in routes {
+            Mock::given(method("GET"))
+                .and(path(route))
+                .respond_with(ResponseTemplate::new(200).set_body_json(body))
+                .mount(&mock_server)
+                .await;
+        }
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let matrix = client.get_access_matrix(123).await.unwrap();
+        assert_eq!(matrix.organization, "acme");
+        assert_eq!(matrix.effective_role("alice", "production"), Some(&WspRole::Admin));
+        assert_eq!(matrix.effective_role("olivia", "production"), Some(&WspRole::Owner));
+    }
+}
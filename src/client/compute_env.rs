@@ -1,9 +1,12 @@
+use std::time::Duration;
 use crate::errors::SeqeraError;
-use crate::models::common::WorkspaceId;
+use crate::models::common::{Validate, WorkspaceId};
 use crate::models::compute_env::{
-    ComputeEnvDetails, ComputeEnvStatus, GetComputeEnvResponse, ListComputeEnvsResponse,
+    ComputeEnvDetails, ComputeEnvStatus, CreateComputeEnvRequest, CreateComputeEnvResponse,
+    GetComputeEnvResponse, GetPrimaryComputeEnvResponse, ListComputeEnvsResponse,
     UpdateComputeEnvRequest,
 };
+use crate::models::service_info::Feature;
 
 impl super::SeqeraClient {
     pub async fn list_compute_envs(
@@ -64,17 +67,94 @@ impl super::SeqeraClient {
         url.query_pairs_mut()
             .append_pair("workspaceId", &workspace_id.into().0.to_string());
 
-        let response = self
+        let request = self
             .client
             .get(url)
             .header("Authorization", self.auth_header())
-            .send()
-            .await?;
+            .build()?;
 
+        let response = self.handle_response(request).await?;
         let wrapper: GetComputeEnvResponse = response.json().await?;
         Ok(wrapper.compute_env)
     }
 
+    /// Returns the workspace's primary compute environment, if one is set
+    pub async fn get_primary_compute_env(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+    ) -> Result<Option<ComputeEnvDetails>, SeqeraError> {
+        let mut url = self.base_url.join("compute-envs/primary")?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string());
+
+        let request = self
+            .client
+            .get(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        let wrapper: GetPrimaryComputeEnvResponse = response.json().await?;
+        Ok(wrapper.compute_env)
+    }
+
+    /// Makes a compute environment the default for launches in its workspace
+    pub async fn set_primary_compute_env(
+        &self,
+        compute_env_id: impl AsRef<str>,
+        workspace_id: impl Into<WorkspaceId>,
+    ) -> Result<(), SeqeraError> {
+        let mut url = self
+            .base_url
+            .join(&format!("compute-envs/{}/primary", compute_env_id.as_ref()))?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string());
+
+        let request = self
+            .client
+            .post(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let _ = self.handle_response(request).await?;
+        Ok(())
+    }
+
+    /// Polls a compute environment until it becomes `AVAILABLE`.
+    ///
+    /// Returns early with [`SeqeraError::ComputeEnvFailed`] if the environment
+    /// ends up `ERRORED` or `INVALID`, and with [`SeqeraError::Timeout`] if
+    /// `timeout` elapses first.
+    pub async fn wait_for_compute_env(
+        &self,
+        compute_env_id: impl AsRef<str>,
+        workspace_id: impl Into<WorkspaceId>,
+        poll_interval: Duration,
+        timeout: Option<Duration>,
+    ) -> Result<ComputeEnvDetails, SeqeraError> {
+        let compute_env_id = compute_env_id.as_ref();
+        let workspace_id = workspace_id.into();
+
+        super::poll_until(
+            poll_interval,
+            timeout,
+            || format!("compute environment {} to become AVAILABLE", compute_env_id),
+            move || async move {
+                let ce = self.get_compute_env(compute_env_id, workspace_id).await?;
+                match ce.status {
+                    ComputeEnvStatus::Available => Ok(Some(ce)),
+                    ComputeEnvStatus::Errored | ComputeEnvStatus::Invalid => Err(SeqeraError::ComputeEnvFailed {
+                        id: ce.id,
+                        status: ce.status.to_string(),
+                        message: ce.message.unwrap_or_else(|| "no message".to_string()),
+                    }),
+                    ComputeEnvStatus::Creating => Ok(None),
+                }
+            },
+        )
+        .await
+    }
+
     pub async fn update_compute_env(
         &self,
         compute_env_id: impl AsRef<str>,
@@ -97,6 +177,56 @@ impl super::SeqeraClient {
 
         Ok(())
     }
+
+    /// Creates a compute environment and returns its ID
+    pub async fn create_compute_env(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        request: CreateComputeEnvRequest,
+    ) -> Result<String, SeqeraError> {
+        request.validate()?;
+        if request.compute_env.config.uses_fusion() {
+            self.require_feature(Feature::Fusion)?;
+        }
+        if request.compute_env.config.uses_wave() {
+            self.require_feature(Feature::Wave)?;
+        }
+        let mut url = self.base_url.join("compute-envs")?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string());
+
+        let request = self
+            .client
+            .post(url)
+            .header("Authorization", self.auth_header())
+            .json(&request)
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        let wrapper: CreateComputeEnvResponse = response.json().await?;
+        Ok(wrapper.compute_env_id)
+    }
+
+    pub async fn delete_compute_env(
+        &self,
+        compute_env_id: impl AsRef<str>,
+        workspace_id: impl Into<WorkspaceId>,
+    ) -> Result<(), SeqeraError> {
+        let mut url = self
+            .base_url
+            .join(&format!("compute-envs/{}", compute_env_id.as_ref()))?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string());
+
+        let request = self
+            .client
+            .delete(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let _ = self.handle_response(request).await?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -127,7 +257,14 @@ mod tests {
                     "visibility": "PRIVATE",
                     "workDir": "/work",
                     "credentialsId": "cred-1",
-                    "region": "us-east-1"
+                    "region": "us-east-1",
+                    "labels": [{
+                        "id": 1,
+                        "name": "owner",
+                        "value": "genomics",
+                        "resource": true,
+                        "isDefault": false
+                    }]
                 }]
             })))
             .mount(&mock_server)
@@ -142,7 +279,7 @@ mod tests {
         let ce = &response.compute_envs[0];
         assert_eq!(ce.id, "ce-1");
         assert_eq!(ce.name, "test-env");
-        assert_eq!(ce.platform, "aws-batch");
+        assert_eq!(ce.platform, crate::models::platform::PlatformId::AwsBatch);
         assert!(matches!(ce.status, ComputeEnvStatus::Available));
         assert_eq!(ce.message, Some("Ready".to_string()));
         assert!(ce.primary.unwrap_or(false));
@@ -151,6 +288,9 @@ mod tests {
         assert_eq!(ce.work_dir, "/work");
         assert_eq!(ce.credentials_id, "cred-1");
         assert_eq!(ce.region, Some("us-east-1".into()));
+        let labels = ce.labels.as_ref().unwrap();
+        assert_eq!(labels[0].name, "owner");
+        assert_eq!(labels[0].value.as_deref(), Some("genomics"));
     }
 
     #[tokio::test]
@@ -253,4 +393,277 @@ mod tests {
         let result = client.update_compute_env("ce-1", 123, request).await;
         assert!(result.is_ok());
     }
+
+    fn slurm_config() -> serde_json::Value {
+        json!({
+            "discriminator": "slurm-platform",
+            "workDir": "/scratch/work",
+            "environment": [],
+            "launchDir": "/scratch/launch",
+            "userName": "nf",
+            "hostName": "login.hpc.example.com",
+            "port": 22,
+            "headQueue": "long",
+            "computeQueue": "normal",
+            "maxQueueSize": 100,
+            "headJobOptions": "",
+            "propagateHeadJobOptions": false
+        })
+    }
+
+    #[tokio::test]
+    async fn test_create_compute_env() {
+        let mock_server = MockServer::start().await;
+
+        let mut expected_config = slurm_config();
+        expected_config["preRunScript"] = json!(null);
+        expected_config["postRunScript"] = json!(null);
+        expected_config["nextflowConfig"] = json!(null);
+
+        Mock::given(method("POST"))
+            .and(path("/compute-envs"))
+            .and(query_param("workspaceId", "123"))
+            .and(header("authorization", "Bearer test-token"))
+            .and(body_json(json!({
+                "computeEnv": {
+                    "name": "hpc",
+                    "description": "On-prem cluster",
+                    "platform": "slurm-platform",
+                    "credentialsId": "cred-1",
+                    "config": expected_config
+                },
+                "labelIds": [1]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "computeEnvId": "ce-new"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url("test-token".to_string(), &mock_server.uri())
+            .unwrap();
+
+        let config: crate::models::compute_env::ComputeConfig = serde_json::from_value(slurm_config()).unwrap();
+        let request = CreateComputeEnvRequest::new("hpc", "cred-1", config)
+            .description("On-prem cluster")
+            .label_ids(vec![1]);
+
+        let id = client.create_compute_env(123, request).await.unwrap();
+        assert_eq!(id, "ce-new");
+    }
+
+    #[test]
+    fn test_create_compute_env_from_definition_file() {
+        let definition = json!({
+            "computeEnv": {
+                "name": "hpc",
+                "credentialsId": "cred-1",
+                "config": slurm_config()
+            }
+        });
+        let request: CreateComputeEnvRequest = serde_json::from_value(definition).unwrap();
+        assert_eq!(request.compute_env.config.platform(), crate::models::platform::PlatformId::Slurm);
+        assert!(request.label_ids.is_empty());
+        assert!(request.validate().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_delete_compute_env() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/compute-envs/ce-1"))
+            .and(query_param("workspaceId", "123"))
+            .and(header("authorization", "Bearer test-token"))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url("test-token".to_string(), &mock_server.uri())
+            .unwrap();
+
+        let result = client.delete_compute_env("ce-1", 123).await;
+        assert!(result.is_ok());
+    }
+
+    fn compute_env_body(status: &str, message: Option<&str>) -> serde_json::Value {
+        json!({
+            "computeEnv": {
+                "id": "ce-1",
+                "name": "hpc",
+                "platform": "slurm-platform",
+                "config": slurm_config(),
+                "dateCreated": "2023-01-01T00:00:00Z",
+                "lastUpdated": "2023-01-01T00:00:00Z",
+                "lastUsed": null,
+                "status": status,
+                "message": message,
+                "credentialsId": "cred-1",
+                "orgId": 1,
+                "workspaceId": 123
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_compute_env() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/compute-envs/ce-1"))
+            .and(query_param("workspaceId", "123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(compute_env_body("CREATING", None)))
+            .up_to_n_times(2)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/compute-envs/ce-1"))
+            .and(query_param("workspaceId", "123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(compute_env_body("AVAILABLE", None)))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url("test-token".to_string(), &mock_server.uri())
+            .unwrap();
+
+        let ce = client
+            .wait_for_compute_env("ce-1", 123, Duration::from_millis(10), Some(Duration::from_secs(5)))
+            .await
+            .unwrap();
+        assert_eq!(ce.status, ComputeEnvStatus::Available);
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_compute_env_errored() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/compute-envs/ce-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(
+                compute_env_body("ERRORED", Some("Unable to create Batch queue")),
+            ))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url("test-token".to_string(), &mock_server.uri())
+            .unwrap();
+
+        let result = client
+            .wait_for_compute_env("ce-1", 123, Duration::from_millis(10), None)
+            .await;
+        match result {
+            Err(SeqeraError::ComputeEnvFailed { status, message, .. }) => {
+                assert_eq!(status, "ERRORED");
+                assert_eq!(message, "Unable to create Batch queue");
+            }
+            other => panic!("Expected ComputeEnvFailed, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_compute_env_timeout() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/compute-envs/ce-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(compute_env_body("CREATING", None)))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url("test-token".to_string(), &mock_server.uri())
+            .unwrap();
+
+        let result = client
+            .wait_for_compute_env("ce-1", 123, Duration::from_millis(10), Some(Duration::from_millis(50)))
+            .await;
+        assert!(matches!(result, Err(SeqeraError::Timeout { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_compute_env_polls_at_deadline() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/compute-envs/ce-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(compute_env_body("CREATING", None)))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/compute-envs/ce-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(compute_env_body("AVAILABLE", None)))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url("test-token".to_string(), &mock_server.uri())
+            .unwrap();
+
+        // The interval is longer than the timeout: the second poll happens at the deadline
+        let ce = client
+            .wait_for_compute_env("ce-1", 123, Duration::from_secs(10), Some(Duration::from_millis(50)))
+            .await
+            .unwrap();
+        assert_eq!(ce.status, ComputeEnvStatus::Available);
+    }
+
+    #[tokio::test]
+    async fn test_get_primary_compute_env() {
+        let mock_server = MockServer::start().await;
+
+        let mut body = compute_env_body("AVAILABLE", None);
+        body["computeEnv"]["primary"] = json!(true);
+
+        Mock::given(method("GET"))
+            .and(path("/compute-envs/primary"))
+            .and(query_param("workspaceId", "123"))
+            .and(header("authorization", "Bearer test-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url("test-token".to_string(), &mock_server.uri())
+            .unwrap();
+
+        let ce = client.get_primary_compute_env(123).await.unwrap().unwrap();
+        assert_eq!(ce.id, "ce-1");
+        assert_eq!(ce.primary, Some(true));
+    }
+
+    #[tokio::test]
+    async fn test_get_primary_compute_env_unset() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/compute-envs/primary"))
+            .and(query_param("workspaceId", "123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url("test-token".to_string(), &mock_server.uri())
+            .unwrap();
+
+        assert!(client.get_primary_compute_env(123).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_set_primary_compute_env() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/compute-envs/ce-1/primary"))
+            .and(query_param("workspaceId", "123"))
+            .and(header("authorization", "Bearer test-token"))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url("test-token".to_string(), &mock_server.uri())
+            .unwrap();
+
+        assert!(client.set_primary_compute_env("ce-1", 123).await.is_ok());
+    }
 }
@@ -0,0 +1,373 @@
+use crate::errors::SeqeraError;
+use crate::models::common::{Validate, WorkspaceId};
+use crate::models::platform::PlatformId;
+use crate::models::credentials::{
+    CreateCredentialsRequest, CreateCredentialsResponse, Credentials, DescribeCredentialsResponse,
+    ListCredentialsResponse, UpdateCredentialsRequest,
+};
+
+impl super::SeqeraClient {
+    pub async fn list_credentials(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        platform_id: Option<PlatformId>,
+    ) -> Result<ListCredentialsResponse, SeqeraError> {
+        let mut url = self.base_url.join("credentials")?;
+
+        let mut query_pairs = url.query_pairs_mut();
+        query_pairs.append_pair("workspaceId", &workspace_id.into().0.to_string());
+        if let Some(platform_id) = platform_id {
+            query_pairs.append_pair("platformId", platform_id.as_str());
+        }
+        drop(query_pairs);
+
+        let request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        Ok(response.json().await?)
+    }
+
+    pub async fn get_credentials(
+        &self,
+        credentials_id: impl AsRef<str>,
+        workspace_id: impl Into<WorkspaceId>,
+    ) -> Result<Credentials, SeqeraError> {
+        let mut url = self
+            .base_url
+            .join(&format!("credentials/{}", credentials_id.as_ref()))?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string());
+
+        let request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        let wrapper: DescribeCredentialsResponse = response.json().await?;
+        Ok(wrapper.credentials)
+    }
+
+    pub async fn validate_credentials_name(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        name: &str,
+    ) -> Result<(), SeqeraError> {
+        let mut url = self.base_url.join("credentials/validate")?;
+
+        let mut query_pairs = url.query_pairs_mut();
+        query_pairs.append_pair("workspaceId", &workspace_id.into().0.to_string());
+        query_pairs.append_pair("name", name);
+        drop(query_pairs);
+
+        let request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let _ = self.handle_response(request).await?;
+        Ok(())
+    }
+
+    /// Creates credentials and returns the new credentials ID
+    pub async fn create_credentials(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        request: CreateCredentialsRequest,
+    ) -> Result<String, SeqeraError> {
+        request.validate()?;
+        let mut url = self.base_url.join("credentials")?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string());
+
+        let request = self.client
+            .post(url)
+            .header("Authorization", self.auth_header())
+            .json(&request)
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        let wrapper: CreateCredentialsResponse = response.json().await?;
+        Ok(wrapper.credentials_id)
+    }
+
+    pub async fn update_credentials(
+        &self,
+        credentials_id: impl AsRef<str>,
+        workspace_id: impl Into<WorkspaceId>,
+        request: UpdateCredentialsRequest,
+    ) -> Result<(), SeqeraError> {
+        request.validate()?;
+        let mut url = self
+            .base_url
+            .join(&format!("credentials/{}", credentials_id.as_ref()))?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string());
+
+        let request = self.client
+            .put(url)
+            .header("Authorization", self.auth_header())
+            .json(&request)
+            .build()?;
+
+        let _ = self.handle_response(request).await?;
+        Ok(())
+    }
+
+    pub async fn delete_credentials(
+        &self,
+        credentials_id: impl AsRef<str>,
+        workspace_id: impl Into<WorkspaceId>,
+    ) -> Result<(), SeqeraError> {
+        let mut url = self
+            .base_url
+            .join(&format!("credentials/{}", credentials_id.as_ref()))?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string());
+
+        let request = self.client
+            .delete(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let _ = self.handle_response(request).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::common::Secret;
+    use crate::models::credentials::*;
+    use serde_json::json;
+    use wiremock::matchers::{body_json, header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_list_credentials() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/credentials"))
+            .and(query_param("workspaceId", "123"))
+            .and(query_param("platformId", "aws-batch"))
+            .and(header("authorization", "Bearer test-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "credentials": [{
+                    "id": "cred-1",
+                    "name": "aws-keys",
+                    "description": "AWS access",
+                    "provider": "aws",
+                    "category": "cloud",
+                    "lastUsed": "2023-01-01T00:00:00Z",
+                    "dateCreated": "2022-12-01T00:00:00Z",
+                    "lastUpdated": "2022-12-02T00:00:00Z",
+                    "keys": {
+                        "discriminator": "aws",
+                        "accessKey": "AKIAEXAMPLE",
+                        "assumeRoleArn": "arn:aws:iam::123456789012:role/tower"
+                    }
+                }, {
+                    "id": "cred-2",
+                    "name": "codecommit",
+                    "provider": "codecommit",
+                    "keys": { "discriminator": "codecommit" }
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let response = client.list_credentials(123, Some(crate::models::platform::PlatformId::AwsBatch)).await.unwrap();
+        assert_eq!(response.credentials.len(), 2);
+
+        let creds = &response.credentials[0];
+        assert_eq!(creds.id, "cred-1");
+        assert_eq!(creds.provider, CredentialsProvider::Aws);
+        match &creds.keys {
+            Some(CredentialKeys::Aws(keys)) => {
+                assert_eq!(keys.access_key.as_deref(), Some("AKIAEXAMPLE"));
+                assert!(keys.secret_key.is_none());
+                assert_eq!(
+                    keys.assume_role_arn.as_deref(),
+                    Some("arn:aws:iam::123456789012:role/tower")
+                );
+            }
+            other => panic!("Expected AWS keys, got {:?}", other),
+        }
+
+        let creds = &response.credentials[1];
+        assert_eq!(creds.provider, CredentialsProvider::Other);
+        assert!(matches!(creds.keys, Some(CredentialKeys::Other)));
+    }
+
+    #[tokio::test]
+    async fn test_get_credentials() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/credentials/cred-1"))
+            .and(query_param("workspaceId", "123"))
+            .and(header("authorization", "Bearer test-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "credentials": {
+                    "id": "cred-1",
+                    "name": "azure",
+                    "provider": "azure",
+                    "keys": {
+                        "discriminator": "azure",
+                        "batchName": "mybatch",
+                        "storageName": "mystorage"
+                    }
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let creds = client.get_credentials("cred-1", 123).await.unwrap();
+        assert_eq!(creds.name, "azure");
+        match creds.keys {
+            Some(CredentialKeys::Azure(keys)) => {
+                assert_eq!(keys.batch_name, "mybatch");
+                assert_eq!(keys.storage_name, "mystorage");
+            }
+            other => panic!("Expected Azure keys, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_credentials() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/credentials"))
+            .and(query_param("workspaceId", "123"))
+            .and(header("authorization", "Bearer test-token"))
+            .and(body_json(json!({
+                "credentials": {
+                    "name": "my-registry",
+                    "description": "Docker Hub",
+                    "provider": "container-reg",
+                    "keys": {
+                        "discriminator": "container-reg",
+                        "userName": "robot",
+                        "password": "hunter2",
+                        "registry": "docker.io"
+                    }
+                }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "credentialsId": "cred-new"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let request = CreateCredentialsRequest::new(
+            "my-registry",
+            CredentialKeys::ContainerRegistry(ContainerRegistryKeys {
+                user_name: "robot".to_string(),
+                password: Some(Secret::new("hunter2")),
+                registry: "docker.io".to_string(),
+            }),
+        )
+        .description("Docker Hub");
+
+        let id = client.create_credentials(123, request).await.unwrap();
+        assert_eq!(id, "cred-new");
+    }
+
+    #[tokio::test]
+    async fn test_update_credentials() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("PUT"))
+            .and(path("/credentials/cred-1"))
+            .and(query_param("workspaceId", "123"))
+            .and(header("authorization", "Bearer test-token"))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let request = UpdateCredentialsRequest {
+            credentials: CredentialsInner {
+                id: Some("cred-1".to_string()),
+                name: "k8s".to_string(),
+                description: None,
+                provider: CredentialsProvider::Kubernetes,
+                base_url: None,
+                keys: CredentialKeys::Kubernetes(KubernetesKeys {
+                    token: Some(Secret::new("token")),
+                    ..Default::default()
+                }),
+            },
+        };
+
+        let result = client.update_credentials("cred-1", 123, request).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_delete_credentials() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/credentials/cred-1"))
+            .and(query_param("workspaceId", "123"))
+            .and(header("authorization", "Bearer test-token"))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let result = client.delete_credentials("cred-1", 123).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_validate_credentials_name() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/credentials/validate"))
+            .and(query_param("workspaceId", "123"))
+            .and(query_param("name", "aws-keys"))
+            .and(header("authorization", "Bearer test-token"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let result = client.validate_credentials_name(123, "aws-keys").await;
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,576 @@
+use url::{ParseError, Url};
+use crate::errors::SeqeraError;
+use crate::models::common::{ValidationError, WorkspaceId};
+use crate::models::data_link::{
+    DataLink, DataLinkContentResponse, DataLinkDownloadUrlResponse,
+    DataLinkFinishMultiPartUploadRequest, DataLinkItem, DataLinkMultiPartUploadRequest,
+    DataLinkMultiPartUploadResponse, DescribeDataLinkResponse, ListDataLinksResponse, UploadEtag,
+};
+use crate::models::service_info::Feature;
+
+impl super::SeqeraClient {
+    /// Builds a `data-links/{id}/<action>[/<path>]` URL, percent-encoding
+    /// each segment of `path` so that it can't change the endpoint
+    fn data_link_url(
+        &self,
+        data_link_id: &str,
+        action: &str,
+        path: Option<&str>,
+        workspace_id: WorkspaceId,
+    ) -> Result<Url, SeqeraError> {
+        self.require_feature(Feature::DataLinks)?;
+        let segments: Vec<&str> = path.unwrap_or_default().split('/').filter(|s| !s.is_empty()).collect();
+        if segments.iter().any(|s| *s == "." || *s == "..") {
+            return Err(ValidationError::Invalid { field: "path", reason: "must not contain . or .. segments" }.into());
+        }
+        let mut url = self.base_url.join("data-links")?;
+        url.path_segments_mut()
+            .map_err(|_| ParseError::RelativeUrlWithCannotBeABaseBase)?
+            .push(data_link_id)
+            .extend(action.split('/'))
+            .extend(segments);
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.0.to_string());
+        Ok(url)
+    }
+
+    pub async fn list_data_links(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        credentials_id: Option<&str>,
+        search: Option<&str>,
+        max: Option<i32>,
+        offset: Option<i32>,
+    ) -> Result<ListDataLinksResponse, SeqeraError> {
+        self.require_feature(Feature::DataLinks)?;
+        let url = self.base_url.join("data-links")?;
+        let mut request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header())
+            .query(&[("workspaceId", workspace_id.into().0)]);
+
+        if let Some(credentials_id) = credentials_id {
+            request = request.query(&[("credentialsId", credentials_id)]);
+        }
+        if let Some(search) = search {
+            request = request.query(&[("search", search)]);
+        }
+        if let Some(max) = max {
+            request = request.query(&[("max", max)]);
+        }
+        if let Some(offset) = offset {
+            request = request.query(&[("offset", offset)]);
+        }
+
+        let request = request.build()?;
+        let response = self.unsupported_if_missing(
+            Feature::DataLinks,
+            self.handle_response(request).await,
+        )?;
+        Ok(response.json().await?)
+    }
+
+    /// Re-discovers the buckets reachable with the workspace credentials.
+    ///
+    /// Limit the refresh to one set of credentials with `credentials_id`.
+    pub async fn refresh_data_links(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        credentials_id: Option<&str>,
+    ) -> Result<(), SeqeraError> {
+        self.require_feature(Feature::DataLinks)?;
+        let mut url = self.base_url.join("data-links/cache/refresh")?;
+
+        let mut query_pairs = url.query_pairs_mut();
+        query_pairs.append_pair("workspaceId", &workspace_id.into().0.to_string());
+        if let Some(credentials_id) = credentials_id {
+            query_pairs.append_pair("credentialsId", credentials_id);
+        }
+        drop(query_pairs);
+
+        let request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let _ = self.handle_response(request).await?;
+        Ok(())
+    }
+
+    pub async fn get_data_link(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        data_link_id: &str,
+    ) -> Result<DataLink, SeqeraError> {
+        self.require_feature(Feature::DataLinks)?;
+        let mut url = self.base_url.join(&format!("data-links/{}", data_link_id))?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string());
+
+        let request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        let wrapper: DescribeDataLinkResponse = response.json().await?;
+        Ok(wrapper.data_link)
+    }
+
+    pub async fn find_data_link_by_name(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        name: &str,
+    ) -> Result<Option<DataLink>, SeqeraError> {
+        let links = self
+            .list_data_links(workspace_id, None, Some(name), None, None)
+            .await?;
+        Ok(links.data_links.into_iter().find(|l| l.name == name))
+    }
+
+    /// Lists one page of the contents of `path` inside a data link.
+    ///
+    /// Pass the `next_page_token` of the previous page to continue a listing.
+    pub async fn browse_data_link(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        data_link_id: &str,
+        path: Option<&str>,
+        search: Option<&str>,
+        next_page_token: Option<&str>,
+    ) -> Result<DataLinkContentResponse, SeqeraError> {
+        let mut url = self.data_link_url(data_link_id, "browse", path, workspace_id.into())?;
+
+        let mut query_pairs = url.query_pairs_mut();
+        if let Some(search) = search {
+            query_pairs.append_pair("search", search);
+        }
+        if let Some(token) = next_page_token {
+            query_pairs.append_pair("nextPageToken", token);
+        }
+        drop(query_pairs);
+
+        let request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        Ok(response.json().await?)
+    }
+
+    /// Lists the full contents of `path`, following page tokens
+    pub async fn browse_data_link_all(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        data_link_id: &str,
+        path: Option<&str>,
+    ) -> Result<Vec<DataLinkItem>, SeqeraError> {
+        let workspace_id = workspace_id.into();
+        let mut items = Vec::new();
+        let mut token: Option<String> = None;
+
+        loop {
+            let page = self
+                .browse_data_link(workspace_id, data_link_id, path, None, token.as_deref())
+                .await?;
+            items.extend(page.objects);
+            match page.next_page_token {
+                Some(next) if !next.is_empty() => token = Some(next),
+                _ => break,
+            }
+        }
+
+        Ok(items)
+    }
+
+    /// Returns a pre-signed URL for a file in a data link
+    pub async fn generate_data_link_download_url(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        data_link_id: &str,
+        file_path: &str,
+        preview: bool,
+    ) -> Result<String, SeqeraError> {
+        let mut url = self.data_link_url(data_link_id, "generate-download-url", None, workspace_id.into())?;
+        url.query_pairs_mut()
+            .append_pair("filePath", file_path)
+            .append_pair("preview", &preview.to_string());
+
+        let request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        let wrapper: DataLinkDownloadUrlResponse = response.json().await?;
+        Ok(wrapper.url)
+    }
+
+    /// Downloads a file from a data link through a pre-signed URL
+    pub async fn download_from_data_link(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        data_link_id: &str,
+        file_path: &str,
+    ) -> Result<Vec<u8>, SeqeraError> {
+        let download_url = self
+            .generate_data_link_download_url(workspace_id, data_link_id, file_path, false)
+            .await?;
+
+        let request = self.client.get(download_url.as_str()).build()?;
+        let response = self.handle_response(request).await?;
+        Ok(response.bytes().await?.to_vec())
+    }
+
+    /// Starts a multipart upload of `file_name` into `dir_path`
+    pub async fn start_data_link_upload(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        data_link_id: &str,
+        dir_path: Option<&str>,
+        request: DataLinkMultiPartUploadRequest,
+    ) -> Result<DataLinkMultiPartUploadResponse, SeqeraError> {
+        let url = self.data_link_url(data_link_id, "upload", dir_path, workspace_id.into())?;
+        let request = self.client
+            .post(url)
+            .header("Authorization", self.auth_header())
+            .json(&request)
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        Ok(response.json().await?)
+    }
+
+    /// Completes, or with `with_error` set aborts, a multipart upload
+    pub async fn finish_data_link_upload(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        data_link_id: &str,
+        dir_path: Option<&str>,
+        request: DataLinkFinishMultiPartUploadRequest,
+    ) -> Result<(), SeqeraError> {
+        let url = self.data_link_url(data_link_id, "upload/finish", dir_path, workspace_id.into())?;
+        let request = self.client
+            .post(url)
+            .header("Authorization", self.auth_header())
+            .json(&request)
+            .build()?;
+
+        let _ = self.handle_response(request).await?;
+        Ok(())
+    }
+
+    /// Uploads `content` as `file_name` into `dir_path` of a data link.
+    ///
+    /// The content is split evenly across the pre-signed part URLs returned by
+    /// the Platform. If any part fails the upload is aborted before the error
+    /// is returned.
+    pub async fn upload_to_data_link(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        data_link_id: &str,
+        dir_path: Option<&str>,
+        file_name: &str,
+        content: Vec<u8>,
+        content_type: Option<&str>,
+    ) -> Result<(), SeqeraError> {
+        let workspace_id = workspace_id.into();
+        let upload = self
+            .start_data_link_upload(
+                workspace_id,
+                data_link_id,
+                dir_path,
+                DataLinkMultiPartUploadRequest {
+                    file_name: file_name.to_string(),
+                    content_length: content.len() as i64,
+                    content_type: content_type.map(str::to_string),
+                },
+            )
+            .await?;
+
+        let result = self.put_upload_parts(&upload.upload_urls, content).await;
+        let (tags, with_error) = match &result {
+            Ok(tags) => (tags.clone(), false),
+            Err(_) => (Vec::new(), true),
+        };
+
+        let finish = DataLinkFinishMultiPartUploadRequest {
+            file_name: file_name.to_string(),
+            upload_id: upload.upload_id,
+            tags,
+            with_error,
+        };
+        self.finish_data_link_upload(workspace_id, data_link_id, dir_path, finish)
+            .await?;
+
+        result.map(|_| ())
+    }
+
+    async fn put_upload_parts(
+        &self,
+        upload_urls: &[String],
+        content: Vec<u8>,
+    ) -> Result<Vec<UploadEtag>, SeqeraError> {
+        if upload_urls.is_empty() {
+            return Err(ValidationError::Invalid { field: "uploadUrls", reason: "the server returned none" }.into());
+        }
+        let part_size = content.len().div_ceil(upload_urls.len()).max(1);
+        let mut parts: Vec<&[u8]> = content.chunks(part_size).collect();
+        if parts.is_empty() {
+            parts.push(&[]);
+        }
+
+        let mut tags = Vec::with_capacity(parts.len());
+        for (index, (part, upload_url)) in parts.into_iter().zip(upload_urls).enumerate() {
+            let request = self.client
+                .put(upload_url.as_str())
+                .body(part.to_vec())
+                .build()?;
+
+            let response = self.handle_response(request).await?;
+            let e_tag = response
+                .headers()
+                .get("etag")
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or_default()
+                .to_string();
+            tags.push(UploadEtag { e_tag, part_number: index as i32 + 1 });
+        }
+
+        Ok(tags)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use wiremock::matchers::{body_json, header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_list_and_refresh_data_links() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/data-links"))
+            .and(query_param("workspaceId", "123"))
+            .and(query_param("search", "my-bucket"))
+            .and(header("authorization", "Bearer test-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "dataLinks": [{
+                    "id": "v1-cloud-abc",
+                    "name": "my-bucket",
+                    "resourceRef": "s3://my-bucket",
+                    "type": "S3-bucket",
+                    "provider": "aws",
+                    "region": "eu-west-1",
+                    "credentials": []
+                }],
+                "totalSize": 1
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/data-links/cache/refresh"))
+            .and(query_param("workspaceId", "123"))
+            .and(query_param("credentialsId", "c1"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        assert!(client.refresh_data_links(123, Some("c1")).await.is_ok());
+        let link = client.find_data_link_by_name(123, "my-bucket").await.unwrap().unwrap();
+        assert_eq!(link.id, "v1-cloud-abc");
+        assert_eq!(link.resource_ref, "s3://my-bucket");
+    }
+
+    #[tokio::test]
+    async fn test_browse_data_link_all_follows_pages() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/data-links/dl-1/browse/results"))
+            .and(query_param("workspaceId", "123"))
+            .and(query_param("nextPageToken", "page-2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "originalPath": "results",
+                "objects": [{ "type": "FILE", "name": "b.txt", "size": 2 }]
+            })))
+            .with_priority(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/data-links/dl-1/browse/results"))
+            .and(query_param("workspaceId", "123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "originalPath": "results",
+                "objects": [
+                    { "type": "FOLDER", "name": "multiqc" },
+                    { "type": "FILE", "name": "a.txt", "size": 1, "mimeType": "text/plain" }
+                ],
+                "nextPageToken": "page-2"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let items = client.browse_data_link_all(123, "dl-1", Some("/results/")).await.unwrap();
+        let names: Vec<&str> = items.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(names, vec!["multiqc", "a.txt", "b.txt"]);
+    }
+
+    #[tokio::test]
+    async fn test_download_from_data_link() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/data-links/dl-1/generate-download-url"))
+            .and(query_param("workspaceId", "123"))
+            .and(query_param("filePath", "results/a.txt"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "url": format!("{}/signed/a.txt", mock_server.uri())
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/signed/a.txt"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("hello"))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let content = client.download_from_data_link(123, "dl-1", "results/a.txt").await.unwrap();
+        assert_eq!(content, b"hello");
+    }
+
+    #[tokio::test]
+    async fn test_upload_to_data_link() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/data-links/dl-1/upload/inputs"))
+            .and(query_param("workspaceId", "123"))
+            .and(body_json(json!({
+                "fileName": "samples.csv",
+                "contentLength": 5,
+                "contentType": "text/csv"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "uploadId": "up-1",
+                "uploadUrls": [
+                    format!("{}/part/1", mock_server.uri()),
+                    format!("{}/part/2", mock_server.uri())
+                ]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("PUT"))
+            .and(path("/part/1"))
+            .respond_with(ResponseTemplate::new(200).insert_header("etag", "\"e1\""))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("PUT"))
+            .and(path("/part/2"))
+            .respond_with(ResponseTemplate::new(200).insert_header("etag", "\"e2\""))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/data-links/dl-1/upload/finish/inputs"))
+            .and(query_param("workspaceId", "123"))
+            .and(body_json(json!({
+                "fileName": "samples.csv",
+                "uploadId": "up-1",
+                "tags": [
+                    { "eTag": "\"e1\"", "partNumber": 1 },
+                    { "eTag": "\"e2\"", "partNumber": 2 }
+                ],
+                "withError": false
+            })))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        client
+            .upload_to_data_link(123, "dl-1", Some("inputs"), "samples.csv", b"a,b\n1".to_vec(), Some("text/csv"))
+            .await
+            .unwrap();
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let parts: Vec<&[u8]> = requests
+            .iter()
+            .filter(|r| r.method.to_string() == "PUT")
+            .map(|r| r.body.as_slice())
+            .collect();
+        assert_eq!(parts, vec![b"a,b".as_slice(), b"\n1".as_slice()]);
+    }
+
+    #[tokio::test]
+    async fn test_upload_without_upload_urls_is_aborted() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/data-links/dl-1/upload"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "uploadId": "up-1", "uploadUrls": [] })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/data-links/dl-1/upload/finish"))
+            .and(body_json(json!({ "fileName": "a.txt", "uploadId": "up-1", "tags": [], "withError": true })))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let result = client.upload_to_data_link(123, "dl-1", None, "a.txt", b"a".to_vec(), None).await;
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_data_link_url_encodes_path() {
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            "https://api.example.com/api/",
+        ).unwrap();
+
+        let url = client
+            .data_link_url("dl-1", "upload/finish", Some("/runs/#1 ?50%/"), 123.into())
+            .unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://api.example.com/api/data-links/dl-1/upload/finish/runs/%231%20%3F50%25?workspaceId=123"
+        );
+        assert!(client.data_link_url("dl-1", "browse", Some("runs/../secrets"), 123.into()).is_err());
+    }
+}
@@ -0,0 +1,360 @@
+use reqwest::multipart::{Form, Part};
+use crate::errors::SeqeraError;
+use crate::models::common::{Validate, WorkspaceId};
+use crate::models::dataset::{
+    CreateDatasetRequest, Dataset, DatasetFile, DatasetVersion, DescribeDatasetResponse,
+    ListDatasetVersionsResponse, ListDatasetsResponse, UpdateDatasetRequest,
+    UploadDatasetVersionResponse,
+};
+
+impl super::SeqeraClient {
+    pub async fn list_datasets(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+    ) -> Result<ListDatasetsResponse, SeqeraError> {
+        let mut url = self.base_url.join("datasets")?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string());
+
+        let request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        Ok(response.json().await?)
+    }
+
+    pub async fn get_dataset(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        dataset_id: &str,
+    ) -> Result<Dataset, SeqeraError> {
+        let mut url = self.base_url.join(&format!("datasets/{}/metadata", dataset_id))?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string());
+
+        let request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        let wrapper: DescribeDatasetResponse = response.json().await?;
+        Ok(wrapper.dataset)
+    }
+
+    pub async fn find_dataset_by_name(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        name: &str,
+    ) -> Result<Option<Dataset>, SeqeraError> {
+        let datasets = self.list_datasets(workspace_id).await?;
+        Ok(datasets.datasets.into_iter().find(|d| d.name == name))
+    }
+
+    pub async fn create_dataset(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        request: CreateDatasetRequest,
+    ) -> Result<Dataset, SeqeraError> {
+        request.validate()?;
+        let mut url = self.base_url.join("datasets")?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string());
+
+        let request = self.client
+            .post(url)
+            .header("Authorization", self.auth_header())
+            .json(&request)
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        let wrapper: DescribeDatasetResponse = response.json().await?;
+        Ok(wrapper.dataset)
+    }
+
+    pub async fn update_dataset(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        dataset_id: &str,
+        request: UpdateDatasetRequest,
+    ) -> Result<(), SeqeraError> {
+        request.validate()?;
+        let mut url = self.base_url.join(&format!("datasets/{}", dataset_id))?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string());
+
+        let request = self.client
+            .put(url)
+            .header("Authorization", self.auth_header())
+            .json(&request)
+            .build()?;
+
+        let _ = self.handle_response(request).await?;
+        Ok(())
+    }
+
+    pub async fn delete_dataset(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        dataset_id: &str,
+    ) -> Result<(), SeqeraError> {
+        let mut url = self.base_url.join(&format!("datasets/{}", dataset_id))?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string());
+
+        let request = self.client
+            .delete(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let _ = self.handle_response(request).await?;
+        Ok(())
+    }
+
+    /// Uploads a checked CSV/TSV file as the next version of a dataset
+    pub async fn upload_dataset_version(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        dataset_id: &str,
+        file: DatasetFile,
+    ) -> Result<DatasetVersion, SeqeraError> {
+        let mut url = self.base_url.join(&format!("datasets/{}/upload", dataset_id))?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string())
+            .append_pair("header", &file.has_header.to_string());
+
+        let part = Part::text(file.content)
+            .file_name(file.file_name)
+            .mime_str(file.format.media_type())?;
+        let form = Form::new().part("file", part);
+
+        let request = self.client
+            .post(url)
+            .header("Authorization", self.auth_header())
+            .multipart(form)
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        let wrapper: UploadDatasetVersionResponse = response.json().await?;
+        Ok(wrapper.version)
+    }
+
+    pub async fn list_dataset_versions(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        dataset_id: &str,
+    ) -> Result<ListDatasetVersionsResponse, SeqeraError> {
+        let mut url = self.base_url.join(&format!("datasets/{}/versions", dataset_id))?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string());
+
+        let request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        Ok(response.json().await?)
+    }
+
+    /// Downloads the file stored for one version of a dataset
+    pub async fn download_dataset_version(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        dataset_id: &str,
+        version: i64,
+        file_name: &str,
+    ) -> Result<Vec<u8>, SeqeraError> {
+        let mut url = self.base_url.join(&format!(
+            "datasets/{}/v/{}/n/{}",
+            dataset_id, version, file_name
+        ))?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string());
+
+        let request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        Ok(response.bytes().await?.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::dataset::DatasetFormat;
+    use serde_json::json;
+    use wiremock::matchers::{body_json, header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn dataset_body() -> serde_json::Value {
+        json!({
+            "id": "ds1",
+            "name": "samplesheet",
+            "description": "RNA-seq samples",
+            "mediaType": "text/csv",
+            "deleted": false,
+            "lastUpdated": "2024-01-01T00:00:00Z",
+            "dateCreated": "2024-01-01T00:00:00Z"
+        })
+    }
+
+    #[tokio::test]
+    async fn test_create_and_list_datasets() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/datasets"))
+            .and(query_param("workspaceId", "123"))
+            .and(body_json(json!({ "name": "samplesheet", "description": "RNA-seq samples" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "dataset": dataset_body() })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/datasets"))
+            .and(query_param("workspaceId", "123"))
+            .and(header("authorization", "Bearer test-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "datasets": [dataset_body()] })))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let request = CreateDatasetRequest {
+            name: "samplesheet".to_string(),
+            description: Some("RNA-seq samples".to_string()),
+        };
+        let dataset = client.create_dataset(123, request).await.unwrap();
+        assert_eq!(dataset.id, "ds1");
+
+        let found = client.find_dataset_by_name(123, "samplesheet").await.unwrap();
+        assert_eq!(found.unwrap().media_type.as_deref(), Some("text/csv"));
+    }
+
+    #[tokio::test]
+    async fn test_create_dataset_invalid_name() {
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            "http://localhost",
+        ).unwrap();
+
+        let request = CreateDatasetRequest { name: "bad name".to_string(), description: None };
+        let result = client.create_dataset(123, request).await;
+        assert!(matches!(result, Err(SeqeraError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_upload_dataset_version() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/datasets/ds1/upload"))
+            .and(query_param("workspaceId", "123"))
+            .and(query_param("header", "true"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "version": {
+                    "datasetId": "ds1",
+                    "hasHeader": true,
+                    "version": 1,
+                    "fileName": "samples.csv",
+                    "mediaType": "text/csv",
+                    "url": "https://example.com/workspaces/123/datasets/ds1/v/1/n/samples.csv"
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let file = DatasetFile::new("samples.csv", DatasetFormat::Csv, "sample,fastq\nS1,a.fq\n", None).unwrap();
+        let version = client.upload_dataset_version(123, "ds1", file).await.unwrap();
+        assert_eq!(version.version, 1);
+        assert!(version.has_header);
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let body = String::from_utf8_lossy(&requests[0].body);
+        assert!(body.contains("name=\"file\"; filename=\"samples.csv\""));
+        assert!(body.contains("sample,fastq\nS1,a.fq\n"));
+    }
+
+    #[tokio::test]
+    async fn test_list_and_download_dataset_versions() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/datasets/ds1/versions"))
+            .and(query_param("workspaceId", "123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "versions": [{
+                    "datasetId": "ds1",
+                    "version": 2,
+                    "fileName": "samples.csv",
+                    "url": "https://example.com/ds1/v/2/n/samples.csv"
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/datasets/ds1/v/2/n/samples.csv"))
+            .and(query_param("workspaceId", "123"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("sample\nS1\n"))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let versions = client.list_dataset_versions(123, "ds1").await.unwrap().versions;
+        assert_eq!(versions[0].version, 2);
+
+        let content = client
+            .download_dataset_version(123, "ds1", 2, &versions[0].file_name)
+            .await
+            .unwrap();
+        assert_eq!(content, b"sample\nS1\n");
+    }
+
+    #[tokio::test]
+    async fn test_update_and_delete_dataset() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("PUT"))
+            .and(path("/datasets/ds1"))
+            .and(query_param("workspaceId", "123"))
+            .and(body_json(json!({ "name": "renamed" })))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/datasets/ds1"))
+            .and(query_param("workspaceId", "123"))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let request = UpdateDatasetRequest { name: "renamed".to_string(), description: None };
+        assert!(client.update_dataset(123, "ds1", request).await.is_ok());
+        assert!(client.delete_dataset(123, "ds1").await.is_ok());
+    }
+}
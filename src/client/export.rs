@@ -0,0 +1,148 @@
+use crate::errors::SeqeraError;
+use crate::models::common::{OrgId, ValidationError, WorkspaceId};
+use crate::models::export::{PipelineExport, WorkspaceExport};
+use crate::models::label::LabelType;
+use crate::models::pipeline_secret::SecretScope;
+
+impl super::SeqeraClient {
+    /// Gathers a workspace's configuration for export: the workspace,
+    /// participants, compute environments, pipelines with their launch
+    /// configurations, labels, secret names, actions and dataset metadata.
+    /// Lists are sorted and volatile fields stripped, so that exporting an
+    /// unchanged workspace gives the same documents.
+    pub async fn export_workspace(
+        &self,
+        org_id: impl Into<OrgId>,
+        workspace_id: impl Into<WorkspaceId>,
+    ) -> Result<WorkspaceExport, SeqeraError> {
+        let org_id = org_id.into();
+        let workspace_id = workspace_id.into();
+        let mut export = WorkspaceExport::default();
+
+        let workspace = self
+            .list_workspaces(org_id)
+            .await?
+            .workspaces
+            .into_iter()
+            .find(|w| w.id == workspace_id)
+            .ok_or(ValidationError::Invalid { field: "workspaceId", reason: "no such workspace in the organization" })?;
+        export.insert("workspace", &workspace)?;
+
+        let mut participants = self.list_all_workspace_participants(org_id, workspace_id, 100).await?;
+        participants.sort_by(|a, b| {
+            (a.participant_type.to_string(), a.name()).cmp(&(b.participant_type.to_string(), b.name()))
+        });
+        export.insert("participants", &participants)?;
+
+        let mut labels = self.list_all_labels(workspace_id, LabelType::All, 100).await?;
+        labels.sort_by(|a, b| (&a.name, &a.value).cmp(&(&b.name, &b.value)));
+        export.insert("labels", &labels)?;
+
+        let mut secrets: Vec<String> = self
+            .list_pipeline_secrets(SecretScope::Workspace(workspace_id))
+            .await?
+            .pipeline_secrets
+            .into_iter()
+            .map(|s| s.name)
+            .collect();
+        secrets.sort();
+        export.insert("secrets", &secrets)?;
+
+        let mut datasets: Vec<_> = self
+            .list_datasets(workspace_id)
+            .await?
+            .datasets
+            .into_iter()
+            .filter(|d| !d.deleted)
+            .collect();
+        datasets.sort_by(|a, b| a.name.cmp(&b.name));
+        export.insert("datasets", &datasets)?;
+
+        let mut compute_envs = Vec::new();
+        for compute_env in self.list_compute_envs(workspace_id, None).await?.compute_envs {
+            compute_envs.push(self.get_compute_env(&compute_env.id, workspace_id).await?);
+        }
+        export.insert_each("compute-envs", &compute_envs, |ce| &ce.name, |ce| ce.id.clone())?;
+
+        let mut pipelines = Vec::new();
+        for pipeline in self.list_all_pipelines(workspace_id, 100).await? {
+            let launch = self.get_pipeline_launch(workspace_id, pipeline.pipeline_id).await?;
+            pipelines.push(PipelineExport { pipeline, launch });
+        }
+        export.insert_each("pipelines", &pipelines, |p| &p.pipeline.name, |p| p.pipeline.pipeline_id.to_string())?;
+
+        let mut actions = Vec::new();
+        for action in self.list_actions(workspace_id).await?.actions {
+            actions.push(self.get_action(workspace_id, &action.id).await?);
+        }
+        export.insert_each("actions", &actions, |a| &a.name, |a| a.id.clone())?;
+
+        Ok(export)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::fixtures::mount_json;
+    use crate::models::export::ExportFormat;
+    use serde_json::json;
+    use wiremock::MockServer;
+
+    #[tokio::test]
+    async fn test_export_workspace() {
+        let mock_server = MockServer::start().await;
+
+        mount_json(&mock_server, "/orgs/1/workspaces", json!({
+            "workspaces": [{ "id": 10, "name": "genomics", "fullName": "Genomics", "visibility": "PRIVATE" }]
+        })).await;
+        mount_json(&mock_server, "/orgs/1/workspaces/10/participants", json!({
+            "participants": [
+                { "participantId": 2, "userName": "zoe", "wspRole": "launch", "type": "MEMBER" },
+                { "participantId": 1, "userName": "adam", "wspRole": "admin", "type": "MEMBER" }
+            ],
+            "totalSize": 2
+        })).await;
+        mount_json(&mock_server, "/labels", json!({
+            "labels": [{ "id": 3, "name": "project", "resource": false, "isDefault": false }],
+            "totalSize": 1
+        })).await;
+        mount_json(&mock_server, "/pipeline-secrets", json!({
+            "pipelineSecrets": [
+                { "id": 2, "name": "TOKEN", "lastUsed": "2024-05-01T00:00:00Z" },
+                { "id": 1, "name": "API_KEY" }
+            ]
+        })).await;
+        mount_json(&mock_server, "/datasets", json!({
+            "datasets": [{ "id": "d1", "name": "samples", "deleted": false, "dateCreated": "2024-01-01T00:00:00Z" }]
+        })).await;
+        mount_json(&mock_server, "/compute-envs", json!({ "computeEnvs": [] })).await;
+        mount_json(&mock_server, "/pipelines", json!({
+            "pipelines": [{ "pipelineId": 5, "name": "rnaseq", "lastUpdated": "2024-03-01T00:00:00Z" }],
+            "totalSize": 1
+        })).await;
+        mount_json(&mock_server, "/pipelines/5/launch", json!({
+            "launch": { "pipeline": "https://github.com/nf-core/rnaseq", "dateCreated": "2024-03-01T00:00:00Z" }
+        })).await;
+        mount_json(&mock_server, "/actions", json!({ "actions": [] })).await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let export = client.export_workspace(1, 10).await.unwrap();
+        let paths: Vec<&str> = export.documents.keys().map(String::as_str).collect();
+        assert_eq!(
+            paths,
+            vec!["datasets", "labels", "participants", "pipelines/rnaseq", "secrets", "workspace"]
+        );
+        assert_eq!(export.documents["secrets"], json!(["API_KEY", "TOKEN"]));
+        assert_eq!(export.documents["participants"][0]["userName"], "adam");
+        assert!(export.documents["datasets"][0].get("dateCreated").is_none());
+        assert!(export.documents["pipelines/rnaseq"]["launch"].get("dateCreated").is_none());
+        assert!(export.documents["pipelines/rnaseq"]["pipeline"].get("lastUpdated").is_none());
+
+        let files = export.render(ExportFormat::Yaml).unwrap();
+        assert_eq!(files[4], ("secrets.yaml".to_string(), "- API_KEY\n- TOKEN\n".to_string()));
+    }
+}
@@ -0,0 +1,372 @@
+use serde::Serialize;
+use crate::errors::SeqeraError;
+use crate::models::common::{Validate, WorkspaceId};
+use crate::models::label::{
+    AssociateComputeEnvLabelsRequest, AssociatePipelineLabelsRequest,
+    AssociateWorkflowLabelsRequest, CreateLabelRequest, Label, LabelType, ListLabelsResponse,
+    UpdateLabelRequest,
+};
+
+impl super::SeqeraClient {
+    pub async fn list_labels(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        label_type: Option<LabelType>,
+        max: Option<i32>,
+        offset: Option<i32>,
+        search: Option<&str>,
+    ) -> Result<ListLabelsResponse, SeqeraError> {
+        let url = self.base_url.join("labels")?;
+        let mut request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header())
+            .query(&[("workspaceId", workspace_id.into().0)]);
+
+        if let Some(label_type) = label_type {
+            request = request.query(&[("type", label_type.to_string())]);
+        }
+        if let Some(max) = max {
+            request = request.query(&[("max", max)]);
+        }
+        if let Some(offset) = offset {
+            request = request.query(&[("offset", offset)]);
+        }
+        if let Some(search) = search {
+            request = request.query(&[("search", search)]);
+        }
+
+        let request = request.build()?;
+        let response = self.handle_response(request).await?;
+        Ok(response.json().await?)
+    }
+
+    /// Lists every label of a workspace, fetching `page_size` labels per request
+    pub async fn list_all_labels(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        label_type: LabelType,
+        page_size: i32,
+    ) -> Result<Vec<Label>, SeqeraError> {
+        let workspace_id = workspace_id.into();
+        let mut labels = Vec::new();
+        loop {
+            let page = self
+                .list_labels(workspace_id, Some(label_type), Some(page_size), Some(labels.len() as i32), None)
+                .await?;
+            let fetched = page.labels.len();
+            labels.extend(page.labels);
+            if fetched == 0 || labels.len() as i64 >= page.total_size {
+                return Ok(labels);
+            }
+        }
+    }
+
+    pub async fn create_label(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        request: CreateLabelRequest,
+    ) -> Result<Label, SeqeraError> {
+        request.validate()?;
+        let mut url = self.base_url.join("labels")?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string());
+
+        let request = self.client
+            .post(url)
+            .header("Authorization", self.auth_header())
+            .json(&request)
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        Ok(response.json().await?)
+    }
+
+    pub async fn update_label(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        label_id: i64,
+        request: UpdateLabelRequest,
+    ) -> Result<Label, SeqeraError> {
+        request.validate()?;
+        let mut url = self.base_url.join(&format!("labels/{}", label_id))?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string());
+
+        let request = self.client
+            .put(url)
+            .header("Authorization", self.auth_header())
+            .json(&request)
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        Ok(response.json().await?)
+    }
+
+    pub async fn delete_label(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        label_id: i64,
+    ) -> Result<(), SeqeraError> {
+        let mut url = self.base_url.join(&format!("labels/{}", label_id))?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string());
+
+        let request = self.client
+            .delete(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let _ = self.handle_response(request).await?;
+        Ok(())
+    }
+
+    async fn post_label_association(
+        &self,
+        path: &str,
+        workspace_id: WorkspaceId,
+        body: &impl Serialize,
+    ) -> Result<(), SeqeraError> {
+        let mut url = self.base_url.join(path)?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.0.to_string());
+
+        let request = self.client
+            .post(url)
+            .header("Authorization", self.auth_header())
+            .json(body)
+            .build()?;
+
+        let _ = self.handle_response(request).await?;
+        Ok(())
+    }
+
+    pub async fn add_labels_to_workflows(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        workflow_ids: Vec<String>,
+        label_ids: Vec<i64>,
+    ) -> Result<(), SeqeraError> {
+        let body = AssociateWorkflowLabelsRequest { workflow_ids, label_ids };
+        self.post_label_association("workflow/labels/add", workspace_id.into(), &body).await
+    }
+
+    pub async fn remove_labels_from_workflows(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        workflow_ids: Vec<String>,
+        label_ids: Vec<i64>,
+    ) -> Result<(), SeqeraError> {
+        let body = AssociateWorkflowLabelsRequest { workflow_ids, label_ids };
+        self.post_label_association("workflow/labels/remove", workspace_id.into(), &body).await
+    }
+
+    pub async fn add_labels_to_pipelines(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        pipeline_ids: Vec<i64>,
+        label_ids: Vec<i64>,
+    ) -> Result<(), SeqeraError> {
+        let body = AssociatePipelineLabelsRequest { pipeline_ids, label_ids };
+        self.post_label_association("pipelines/labels/add", workspace_id.into(), &body).await
+    }
+
+    pub async fn remove_labels_from_pipelines(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        pipeline_ids: Vec<i64>,
+        label_ids: Vec<i64>,
+    ) -> Result<(), SeqeraError> {
+        let body = AssociatePipelineLabelsRequest { pipeline_ids, label_ids };
+        self.post_label_association("pipelines/labels/remove", workspace_id.into(), &body).await
+    }
+
+    pub async fn add_labels_to_compute_envs(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        compute_env_ids: Vec<String>,
+        label_ids: Vec<i64>,
+    ) -> Result<(), SeqeraError> {
+        let body = AssociateComputeEnvLabelsRequest { compute_env_ids, label_ids };
+        self.post_label_association("compute-envs/labels/add", workspace_id.into(), &body).await
+    }
+
+    pub async fn remove_labels_from_compute_envs(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        compute_env_ids: Vec<String>,
+        label_ids: Vec<i64>,
+    ) -> Result<(), SeqeraError> {
+        let body = AssociateComputeEnvLabelsRequest { compute_env_ids, label_ids };
+        self.post_label_association("compute-envs/labels/remove", workspace_id.into(), &body).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use wiremock::matchers::{body_json, header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_list_labels() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/labels"))
+            .and(query_param("workspaceId", "123"))
+            .and(query_param("type", "resource"))
+            .and(header("authorization", "Bearer test-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "labels": [{
+                    "id": 1,
+                    "name": "owner",
+                    "value": "genomics",
+                    "resource": true,
+                    "isDefault": true
+                }],
+                "totalSize": 1
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let response = client
+            .list_labels(123, Some(LabelType::Resource), None, None, None)
+            .await
+            .unwrap();
+        assert_eq!(response.total_size, 1);
+        let label = &response.labels[0];
+        assert_eq!(label.id, 1);
+        assert_eq!(label.name, "owner");
+        assert_eq!(label.value.as_deref(), Some("genomics"));
+        assert!(label.resource);
+        assert!(label.is_default);
+    }
+
+    #[tokio::test]
+    async fn test_create_label() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/labels"))
+            .and(query_param("workspaceId", "123"))
+            .and(body_json(json!({
+                "name": "owner",
+                "value": "genomics",
+                "resource": true,
+                "isDefault": true
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": 5,
+                "name": "owner",
+                "value": "genomics",
+                "resource": true,
+                "isDefault": true
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let request = CreateLabelRequest::resource("owner", "genomics").default_label(true);
+        let label = client.create_label(123, request).await.unwrap();
+        assert_eq!(label.id, 5);
+    }
+
+    #[tokio::test]
+    async fn test_update_and_delete_label() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("PUT"))
+            .and(path("/labels/5"))
+            .and(query_param("workspaceId", "123"))
+            .and(body_json(json!({ "name": "rnaseq" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": 5,
+                "name": "rnaseq",
+                "value": null,
+                "resource": false,
+                "isDefault": false
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/labels/5"))
+            .and(query_param("workspaceId", "123"))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let request = UpdateLabelRequest {
+            name: "rnaseq".to_string(),
+            value: None,
+            is_default: None,
+        };
+        let label = client.update_label(123, 5, request).await.unwrap();
+        assert_eq!(label.name, "rnaseq");
+        assert!(label.value.is_none());
+
+        assert!(client.delete_label(123, 5).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_label_associations() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/workflow/labels/add"))
+            .and(query_param("workspaceId", "123"))
+            .and(body_json(json!({ "workflowIds": ["wf-1"], "labelIds": [1, 2] })))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/pipelines/labels/remove"))
+            .and(query_param("workspaceId", "123"))
+            .and(body_json(json!({ "pipelineIds": [9], "labelIds": [1] })))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/compute-envs/labels/add"))
+            .and(query_param("workspaceId", "123"))
+            .and(body_json(json!({ "computeEnvIds": ["ce-1"], "labelIds": [3] })))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        assert!(client
+            .add_labels_to_workflows(123, vec!["wf-1".to_string()], vec![1, 2])
+            .await
+            .is_ok());
+        assert!(client
+            .remove_labels_from_pipelines(123, vec![9], vec![1])
+            .await
+            .is_ok());
+        assert!(client
+            .add_labels_to_compute_envs(123, vec!["ce-1".to_string()], vec![3])
+            .await
+            .is_ok());
+    }
+}
@@ -1,5 +1,7 @@
 use crate::errors::SeqeraError;
 use crate::models::member::*;
+use crate::models::team::CreateTeamMemberRequest;
+use crate::models::workspace::AddWorkspaceParticipantRequest;
 
 impl super::SeqeraClient {
     pub async fn list_organization_members(&self, org_id: i64) -> Result<MemberList, SeqeraError> {
@@ -23,4 +25,312 @@ impl super::SeqeraClient {
         let response = self.handle_response(request).await?;
         Ok(response.json().await?)
     }
-} 
\ No newline at end of file
+
+    /// Lists one page of organization members, optionally filtered by a
+    /// search on user name, email or full name
+    pub async fn search_organization_members(
+        &self,
+        org_id: i64,
+        search: Option<&str>,
+        max: Option<i32>,
+        offset: Option<i32>,
+    ) -> Result<MemberList, SeqeraError> {
+        let url = self.base_url.join(&format!("orgs/{}/members", org_id))?;
+        let mut request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header());
+
+        if let Some(search) = search {
+            request = request.query(&[("search", search)]);
+        }
+        if let Some(max) = max {
+            request = request.query(&[("max", max)]);
+        }
+        if let Some(offset) = offset {
+            request = request.query(&[("offset", offset)]);
+        }
+
+        let request = request.build()?;
+        let response = self.handle_response(request).await?;
+        Ok(response.json().await?)
+    }
+
+    /// Lists every organization member matching `search`, or all of them,
+    /// fetching `page_size` members per request
+    pub async fn list_all_organization_members(
+        &self,
+        org_id: i64,
+        page_size: i32,
+        search: Option<&str>,
+    ) -> Result<Vec<Member>, SeqeraError> {
+        let mut members = Vec::new();
+        loop {
+            let page = self
+                .search_organization_members(org_id, search, Some(page_size), Some(members.len() as i32))
+                .await?;
+            let fetched = page.members.len();
+            members.extend(page.members);
+            if fetched == 0 || members.len() as i64 >= page.total_size {
+                return Ok(members);
+            }
+        }
+    }
+
+    /// Finds a member by exact user name or email
+    pub async fn find_organization_member(
+        &self,
+        org_id: i64,
+        user_name_or_email: &str,
+    ) -> Result<Option<Member>, SeqeraError> {
+        let members = self
+            .list_all_organization_members(org_id, 100, Some(user_name_or_email))
+            .await?;
+        Ok(members
+            .into_iter()
+            .find(|m| m.user_name == user_name_or_email || m.email == user_name_or_email))
+    }
+
+    /// Removes a member from the organization, and so from all its teams and workspaces
+    pub async fn delete_organization_member(&self, org_id: i64, member_id: i64) -> Result<(), SeqeraError> {
+        let url = self.base_url.join(&format!("orgs/{}/members/{}", org_id, member_id))?;
+        let request = self.client
+            .delete(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let _ = self.handle_response(request).await?;
+        Ok(())
+    }
+
+    /// Adds a user to an organization, unless they are already a member, then
+    /// sets their role and adds them to teams and workspaces.
+    ///
+    /// Fails only if the user can't join the organization. Every later step is
+    /// attempted and its outcome recorded in the returned report.
+    pub async fn invite_and_assign(
+        &self,
+        org_id: i64,
+        request: InviteRequest,
+    ) -> Result<InviteReport, SeqeraError> {
+        let (member, existing_member) = match self.find_organization_member(org_id, &request.user).await? {
+            Some(member) => (member, true),
+            None => (self.add_organization_member(org_id, request.user.as_str()).await?, false),
+        };
+        let mut steps = Vec::new();
+
+        if let Some(role) = request.org_role {
+            if member.org_role().as_ref() != Some(&role) {
+                let result = self
+                    .update_member_role(org_id, member.member_id, role.clone())
+                    .await
+                    .map_err(|e| e.to_string());
+                steps.push(invite_step(format!("set organization role to {}", role), result));
+            }
+        }
+
+        if !request.teams.is_empty() {
+            let teams = self.list_organization_teams(org_id).await.map(|t| t.teams);
+            for name in &request.teams {
+                let description = format!("add to team {}", name);
+                let result = match &teams {
+                    Ok(teams) => match teams.iter().find(|t| &t.name == name) {
+                        Some(team) => {
+                            let add = CreateTeamMemberRequest { user_name_or_email: member.email.clone() };
+                            self.create_team_member(org_id, team.team_id, add)
+                                .await
+                                .map(|_| ())
+                                .map_err(|e| e.to_string())
+                        }
+                        None => Err(format!("team '{}' not found", name)),
+                    },
+                    Err(e) => Err(format!("failed to list teams: {}", e)),
+                };
+                steps.push(invite_step(description, result));
+            }
+        }
+
+        if !request.workspaces.is_empty() {
+            let workspaces = self.list_workspaces(org_id).await.map(|w| w.workspaces);
+            for assignment in &request.workspaces {
+                let description = format!("add to workspace {} as {}", assignment.workspace, assignment.role);
+                let result = match &workspaces {
+                    Ok(workspaces) => match workspaces.iter().find(|w| w.name == assignment.workspace) {
+                        Some(workspace) => {
+                            let add = AddWorkspaceParticipantRequest::with_member_id(member.member_id);
+                            self.add_workspace_participant_with_role(org_id, workspace.id, add, assignment.role.clone())
+                                .await
+                                .map(|_| ())
+                                .map_err(|e| e.to_string())
+                        }
+                        None => Err(format!("workspace '{}' not found", assignment.workspace)),
+                    },
+                    Err(e) => Err(format!("failed to list workspaces: {}", e)),
+                };
+                steps.push(invite_step(description, result));
+            }
+        }
+
+        Ok(InviteReport { member, existing_member, steps })
+    }
+}
+
+fn invite_step(description: String, result: Result<(), String>) -> InviteStep {
+    InviteStep { description, error: result.err() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::common::WspRole;
+    use crate::models::member::fixtures::member_json;
+    use serde_json::json;
+    use wiremock::matchers::{body_json, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_list_all_organization_members() {
+        let mock_server = MockServer::start().await;
+
+        for (offset, user_name) in [("0", "alice"), ("1", "alan")] {
+            Mock::given(method("GET"))
+                .and(path("/orgs/123/members"))
+                .and(query_param("max", "1"))
+                .and(query_param("offset", offset))
+                .and(query_param("search", "al"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "members": [member_json(1, user_name, "member")],
+                    "totalSize": 2
+                })))
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+        }
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let members = client.list_all_organization_members(123, 1, Some("al")).await.unwrap();
+        let names: Vec<_> = members.iter().map(|m| m.user_name.as_str()).collect();
+        assert_eq!(names, vec!["alice", "alan"]);
+    }
+
+    #[tokio::test]
+    async fn test_find_and_delete_organization_member() {
+        let mock_server = MockServer::start().await;
+
+        // The exact match is on the second page
+        for (offset, member) in [("0", member_json(6, "alice.b", "member")), ("1", member_json(7, "alice", "member"))] {
+            Mock::given(method("GET"))
+                .and(path("/orgs/123/members"))
+                .and(query_param("search", "alice@example.com"))
+                .and(query_param("offset", offset))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "members": [member],
+                    "totalSize": 2
+                })))
+                .mount(&mock_server)
+                .await;
+        }
+
+        Mock::given(method("DELETE"))
+            .and(path("/orgs/123/members/7"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let member = client.find_organization_member(123, "alice@example.com").await.unwrap().unwrap();
+        assert_eq!(member.user_name, "alice");
+        assert!(client.delete_organization_member(123, member.member_id).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_invite_and_assign_reports_partial_failures() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/orgs/123/members"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "members": [], "totalSize": 0 })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("PUT"))
+            .and(path("/orgs/123/members/add"))
+            .and(body_json(json!({ "user": "alice@example.com" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(member_json(7, "alice", "member")))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/orgs/123/teams"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "teams": [{ "teamId": 5, "name": "bioinfo", "membersCount": 1 }],
+                "totalSize": 1
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/orgs/123/teams/5/members"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "member": member_json(7, "alice", "member") })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/orgs/123/workspaces"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "workspaces": [{ "id": 9, "name": "production", "fullName": "Production", "visibility": "PRIVATE" }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("PUT"))
+            .and(path("/orgs/123/workspaces/9/participants/add"))
+            .and(body_json(json!({ "memberId": 7 })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "participant": {
+                    "participantId": 31,
+                    "memberId": 7,
+                    "userName": "alice",
+                    "wspRole": "launch",
+                    "type": "MEMBER"
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("PUT"))
+            .and(path("/orgs/123/workspaces/9/participants/31/role"))
+            .and(body_json(json!({ "role": "maintain" })))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let request = InviteRequest::new("alice@example.com")
+            .team("bioinfo")
+            .team("missing")
+            .workspace("production", WspRole::Maintain);
+        let report = client.invite_and_assign(123, request).await.unwrap();
+
+        assert!(!report.existing_member);
+        assert_eq!(report.steps.len(), 3);
+        assert!(!report.is_complete());
+        let failures: Vec<_> = report.failures().map(|s| s.description.as_str()).collect();
+        assert_eq!(failures, vec!["add to team missing"]);
+    }
+}
@@ -0,0 +1,598 @@
+use std::collections::HashMap;
+use crate::errors::SeqeraError;
+use crate::models::common::{OrgId, ParticipantType, WorkspaceId, WspRole};
+use crate::models::compute_env::CreateComputeEnvRequest;
+use crate::models::label::{CreateLabelRequest, LabelType};
+use crate::models::migration::{MigrationOptions, MigrationOutcome, MigrationReport, ResourceKind};
+use crate::models::pipeline::CreatePipelineRequest;
+use crate::models::pipeline_secret::SecretScope;
+use crate::models::workspace::AddWorkspaceParticipantRequest;
+
+impl super::SeqeraClient {
+    /// Copies labels, compute environments, pipelines, secrets and
+    /// participants from a workspace of this client to a workspace of
+    /// `target`, which may be a client for another server. Resources that
+    /// already exist in the target by name are skipped. Credentials and
+    /// compute environments are remapped through the options' mapping, and
+    /// secret values come from the options since the API never returns them.
+    pub async fn migrate_workspace(
+        &self,
+        source_org_id: impl Into<OrgId>,
+        source_workspace_id: impl Into<WorkspaceId>,
+        target: &super::SeqeraClient,
+        target_org_id: impl Into<OrgId>,
+        target_workspace_id: impl Into<WorkspaceId>,
+        options: &MigrationOptions,
+    ) -> Result<MigrationReport, SeqeraError> {
+        let (source_org, source_ws) = (source_org_id.into(), source_workspace_id.into());
+        let (target_org, target_ws) = (target_org_id.into(), target_workspace_id.into());
+        let mut report = MigrationReport::default();
+
+        // Target IDs by name; `None` for resources only planned in a dry run
+        let mut labels: HashMap<(String, Option<String>), Option<i64>> = target
+            .list_all_labels(target_ws, LabelType::All, 100)
+            .await?
+            .into_iter()
+            .map(|l| ((l.name, l.value), Some(l.id)))
+            .collect();
+        for label in self.list_all_labels(source_ws, LabelType::All, 100).await? {
+            let key = (label.name.clone(), label.value.clone());
+            let name = match &label.value {
+                Some(value) => format!("{}={}", label.name, value),
+                None => label.name.clone(),
+            };
+            if labels.contains_key(&key) {
+                report.push(ResourceKind::Label, name, skipped_existing());
+                continue;
+            }
+            let outcome = if options.dry_run {
+                labels.insert(key, None);
+                MigrationOutcome::Planned
+            } else {
+                let request = match &label.value {
+                    Some(value) => CreateLabelRequest::resource(&label.name, value).default_label(label.is_default),
+                    None => CreateLabelRequest::simple(&label.name),
+                };
+                match target.create_label(target_ws, request).await {
+                    Ok(created) => {
+                        labels.insert(key, Some(created.id));
+                        MigrationOutcome::Created
+                    }
+                    Err(e) => MigrationOutcome::Failed(e.to_string()),
+                }
+            };
+            report.push(ResourceKind::Label, name, outcome);
+        }
+
+        let source_credentials = self.list_credentials(source_ws, None).await?.credentials;
+        let target_credentials = target.list_credentials(target_ws, None).await?.credentials;
+        let mut compute_envs: HashMap<String, Option<String>> = target
+            .list_compute_envs(target_ws, None)
+            .await?
+            .compute_envs
+            .into_iter()
+            .map(|ce| (ce.name, Some(ce.id)))
+            .collect();
+        for summary in self.list_compute_envs(source_ws, None).await?.compute_envs {
+            if compute_envs.contains_key(&summary.name) {
+                report.push(ResourceKind::ComputeEnv, summary.name, skipped_existing());
+                continue;
+            }
+            if let Some(mapped) = options.mapping.mapped_compute_env(&summary.id, &summary.name) {
+                let outcome = if find_compute_env(&compute_envs, mapped).is_some() {
+                    MigrationOutcome::Skipped(format!("mapped to {}", mapped))
+                } else {
+                    MigrationOutcome::Failed(format!("mapped to {}, which is not in the target workspace", mapped))
+                };
+                report.push(ResourceKind::ComputeEnv, summary.name, outcome);
+                continue;
+            }
+            let credentials_name = source_credentials
+                .iter()
+                .find(|c| c.id == summary.credentials_id)
+                .map_or(summary.credentials_id.as_str(), |c| c.name.as_str());
+            let mapped = options.mapping.credentials_for(&summary.credentials_id, credentials_name);
+            let Some(credentials) = target_credentials.iter().find(|c| c.id == mapped || c.name == mapped) else {
+                let error = format!("no credentials {} in the target workspace", mapped);
+                report.push(ResourceKind::ComputeEnv, summary.name, MigrationOutcome::Failed(error));
+                continue;
+            };
+            let outcome = if options.dry_run {
+                compute_envs.insert(summary.name.clone(), None);
+                MigrationOutcome::Planned
+            } else {
+                let created = match self.get_compute_env(&summary.id, source_ws).await {
+                    Ok(details) => {
+                        let mut request = CreateComputeEnvRequest::new(&details.name, &credentials.id, details.config);
+                        if let Some(description) = &details.description {
+                            request = request.description(description);
+                        }
+                        target.create_compute_env(target_ws, request).await
+                    }
+                    Err(e) => Err(e),
+                };
+                match created {
+                    Ok(id) => {
+                        compute_envs.insert(summary.name.clone(), Some(id));
+                        MigrationOutcome::Created
+                    }
+                    Err(e) => MigrationOutcome::Failed(e.to_string()),
+                }
+            };
+            report.push(ResourceKind::ComputeEnv, summary.name, outcome);
+        }
+
+        let existing: Vec<String> = target
+            .list_all_pipelines(target_ws, 100)
+            .await?
+            .into_iter()
+            .map(|p| p.name)
+            .collect();
+        for pipeline in self.list_all_pipelines(source_ws, 100).await? {
+            if existing.contains(&pipeline.name) {
+                report.push(ResourceKind::Pipeline, pipeline.name, skipped_existing());
+                continue;
+            }
+            let launch = match self.get_pipeline_launch(source_ws, pipeline.pipeline_id).await {
+                Ok(launch) => launch,
+                Err(e) => {
+                    report.push(ResourceKind::Pipeline, pipeline.name, MigrationOutcome::Failed(e.to_string()));
+                    continue;
+                }
+            };
+            let compute_env_id = match &launch.compute_env {
+                Some(ce) => {
+                    let mapped = options.mapping.compute_env_for(&ce.id, &ce.name);
+                    match find_compute_env(&compute_envs, mapped) {
+                        Some(id) => id,
+                        None => {
+                            let error = format!("no compute environment {} in the target workspace", mapped);
+                            report.push(ResourceKind::Pipeline, pipeline.name, MigrationOutcome::Failed(error));
+                            continue;
+                        }
+                    }
+                }
+                None => None,
+            };
+            let outcome = if options.dry_run {
+                MigrationOutcome::Planned
+            } else {
+                let mut label_ids = Vec::new();
+                let mut missing_labels = Vec::new();
+                for label in &pipeline.labels {
+                    match labels.get(&(label.name.clone(), label.value.clone())).copied().flatten() {
+                        Some(id) => label_ids.push(id),
+                        None => missing_labels.push(match &label.value {
+                            Some(value) => format!("{}={}", label.name, value),
+                            None => label.name.clone(),
+                        }),
+                    }
+                }
+                let request = CreatePipelineRequest {
+                    name: pipeline.name.clone(),
+                    description: pipeline.description.clone(),
+                    icon: pipeline.icon.clone(),
+                    launch: launch.to_launch_request(compute_env_id, Vec::new()),
+                    label_ids,
+                };
+                match target.create_pipeline(target_ws, request).await {
+                    Ok(_) if missing_labels.is_empty() => MigrationOutcome::Created,
+                    Ok(_) => MigrationOutcome::Failed(format!(
+                        "created without labels {}, which failed to migrate",
+                        missing_labels.join(", ")
+                    )),
+                    Err(e) => MigrationOutcome::Failed(e.to_string()),
+                }
+            };
+            report.push(ResourceKind::Pipeline, pipeline.name, outcome);
+        }
+
+        let existing: Vec<String> = target
+            .list_pipeline_secrets(SecretScope::Workspace(target_ws))
+            .await?
+            .pipeline_secrets
+            .into_iter()
+            .map(|s| s.name)
+            .collect();
+        for secret in self.list_pipeline_secrets(SecretScope::Workspace(source_ws)).await?.pipeline_secrets {
+            let outcome = if existing.contains(&secret.name) {
+                skipped_existing()
+            } else if let Some(value) = options.secret(&secret.name) {
+                if options.dry_run {
+                    MigrationOutcome::Planned
+                } else {
+                    match target
+                        .create_pipeline_secret(SecretScope::Workspace(target_ws), &secret.name, value.clone())
+                        .await
+                    {
+                        Ok(_) => MigrationOutcome::Created,
+                        Err(e) => MigrationOutcome::Failed(e.to_string()),
+                    }
+                }
+            } else {
+                MigrationOutcome::Failed("no value supplied".to_string())
+            };
+            report.push(ResourceKind::Secret, secret.name, outcome);
+        }
+
+        let existing = target.list_all_workspace_participants(target_org, target_ws, 100).await?;
+        let teams = target.list_organization_teams(target_org.0).await?.teams;
+        for participant in self.list_all_workspace_participants(source_org, source_ws, 100).await? {
+            let name = participant.name().to_string();
+            let exists = existing.iter().any(|p| {
+                p.participant_type == participant.participant_type
+                    && match participant.participant_type {
+                        ParticipantType::Team => p.team_name == participant.team_name,
+                        _ => {
+                            (participant.email.is_some() && p.email == participant.email)
+                                || (participant.user_name.is_some() && p.user_name == participant.user_name)
+                        }
+                    }
+            });
+            if exists {
+                report.push(ResourceKind::Participant, name, skipped_existing());
+                continue;
+            }
+            if participant.wsp_role == WspRole::Owner {
+                let reason = "the owner role comes from the organization".to_string();
+                report.push(ResourceKind::Participant, name, MigrationOutcome::Skipped(reason));
+                continue;
+            }
+            let request = match participant.participant_type {
+                ParticipantType::Team => match teams.iter().find(|t| t.name == name) {
+                    Some(team) => AddWorkspaceParticipantRequest::with_team_id(team.team_id),
+                    None => {
+                        let error = format!("no team {} in the target organization", name);
+                        report.push(ResourceKind::Participant, name, MigrationOutcome::Failed(error));
+                        continue;
+                    }
+                },
+                _ => AddWorkspaceParticipantRequest::with_user_name_or_email(
+                    participant.email.clone().unwrap_or_else(|| name.clone()),
+                ),
+            };
+            let outcome = if options.dry_run {
+                MigrationOutcome::Planned
+            } else {
+                match target
+                    .add_workspace_participant_with_role(target_org, target_ws, request, participant.wsp_role.clone())
+                    .await
+                {
+                    Ok(_) => MigrationOutcome::Created,
+                    Err(e) => MigrationOutcome::Failed(e.to_string()),
+                }
+            };
+            report.push(ResourceKind::Participant, name, outcome);
+        }
+
+        Ok(report)
+    }
+}
+
+/// The target ID of a compute environment given by name or ID; `Some(None)`
+/// for one only planned in a dry run
+fn find_compute_env(compute_envs: &HashMap<String, Option<String>>, name_or_id: &str) -> Option<Option<String>> {
+    compute_envs
+        .get(name_or_id)
+        .cloned()
+        .or_else(|| compute_envs.values().find(|id| id.as_deref() == Some(name_or_id)).cloned())
+}
+
+fn skipped_existing() -> MigrationOutcome {
+    MigrationOutcome::Skipped("already exists".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::fixtures::mount_json;
+    use crate::models::common::Secret;
+    use crate::models::migration::{MigrationMapping, MigrationOptions, MigrationOutcome, ResourceKind};
+    use serde_json::json;
+    use wiremock::matchers::{body_json, body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn credentials(id: &str, name: &str) -> serde_json::Value {
+        json!({ "credentials": [{ "id": id, "name": name, "provider": "ssh" }] })
+    }
+
+    #[tokio::test]
+    async fn test_migrate_workspace() {
+        let source = MockServer::start().await;
+        let target = MockServer::start().await;
+
+        mount_json(&source, "/labels", json!({
+            "labels": [
+                { "id": 1, "name": "project", "value": "rna", "resource": true },
+                { "id": 2, "name": "team" }
+            ],
+            "totalSize": 2
+        })).await;
+        mount_json(&source, "/credentials", credentials("src-cred", "ssh-keys")).await;
+        mount_json(&source, "/compute-envs", json!({
+            "computeEnvs": [{
+                "id": "src-ce", "name": "hpc", "credentialsId": "src-cred", "platform": "slurm-platform",
+                "status": "AVAILABLE", "visibility": "PRIVATE", "workDir": "/scratch", "workspaceName": "genomics"
+            }]
+        })).await;
+        mount_json(&source, "/compute-envs/src-ce", json!({
+            "computeEnv": {
+                "id": "src-ce", "name": "hpc", "platform": "slurm-platform", "credentialsId": "src-cred",
+                "config": {
+                    "discriminator": "slurm-platform", "workDir": "/scratch", "environment": [],
+                    "userName": "nf", "hostName": "login.hpc.example.com", "port": 22,
+                    "maxQueueSize": 100
+                },
+                "dateCreated": "2024-01-01T00:00:00Z", "lastUpdated": "2024-01-01T00:00:00Z",
+                "status": "AVAILABLE", "orgId": 1, "workspaceId": 10
+            }
+        })).await;
+        mount_json(&source, "/pipelines", json!({
+            "pipelines": [{
+                "pipelineId": 5, "name": "rnaseq",
+                "labels": [{ "id": 1, "name": "project", "value": "rna", "resource": true }]
+            }],
+            "totalSize": 1
+        })).await;
+        mount_json(&source, "/pipelines/5/launch", json!({
+            "launch": {
+                "id": "src-launch",
+                "computeEnv": { "id": "src-ce", "name": "hpc" },
+                "pipeline": "https://github.com/nf-core/rnaseq",
+                "revision": "3.14.0",
+                "mainScript": "workflows/main.nf",
+                "headJobCpus": 2
+            }
+        })).await;
+        mount_json(&source, "/pipeline-secrets", json!({
+            "pipelineSecrets": [{ "id": 1, "name": "API_KEY" }, { "id": 2, "name": "TOKEN" }]
+        })).await;
+        mount_json(&source, "/orgs/1/workspaces/10/participants", json!({
+            "participants": [
+                { "participantId": 1, "userName": "adam", "email": "adam@example.com", "wspRole": "owner", "type": "MEMBER" },
+                { "participantId": 2, "teamName": "bioinfo", "teamId": 3, "wspRole": "maintain", "type": "TEAM" }
+            ],
+            "totalSize": 2
+        })).await;
+
+        mount_json(&target, "/labels", json!({
+            "labels": [{ "id": 30, "name": "team", "resource": false }],
+            "totalSize": 1
+        })).await;
+        mount_json(&target, "/credentials", credentials("tgt-cred", "cluster-keys")).await;
+        mount_json(&target, "/compute-envs", json!({ "computeEnvs": [] })).await;
+        mount_json(&target, "/pipelines", json!({ "pipelines": [], "totalSize": 0 })).await;
+        mount_json(&target, "/pipeline-secrets", json!({ "pipelineSecrets": [] })).await;
+        mount_json(&target, "/orgs/2/workspaces/20/participants", json!({ "participants": [], "totalSize": 0 })).await;
+        mount_json(&target, "/orgs/2/teams", json!({
+            "teams": [{ "teamId": 8, "name": "bioinfo", "membersCount": 1 }],
+            "totalSize": 1
+        })).await;
+
+        Mock::given(method("POST"))
+            .and(path("/labels"))
+            .and(body_partial_json(json!({ "name": "project", "value": "rna" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": 31, "name": "project", "value": "rna", "resource": true
+            })))
+            .expect(1)
+            .mount(&target)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/compute-envs"))
+            .and(body_partial_json(json!({ "computeEnv": { "name": "hpc", "credentialsId": "tgt-cred" } })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "computeEnvId": "tgt-ce" })))
+            .expect(1)
+            .mount(&target)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/pipelines"))
+            .and(body_partial_json(json!({
+                "name": "rnaseq",
+                "launch": {
+                    "computeEnvId": "tgt-ce",
+                    "pipeline": "https://github.com/nf-core/rnaseq",
+                    "mainScript": "workflows/main.nf",
+                    "headJobCpus": 2
+                },
+                "labelIds": [31]
+            })))
+            .and(|request: &wiremock::Request| {
+                request.body_json::<serde_json::Value>().is_ok_and(|body| body["launch"].get("id").is_none())
+            })
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "pipeline": { "pipelineId": 50, "name": "rnaseq" }
+            })))
+            .expect(1)
+            .mount(&target)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/pipeline-secrets"))
+            .and(body_json(json!({ "name": "API_KEY", "value": "s3cr3t" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "secretId": 9 })))
+            .expect(1)
+            .mount(&target)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/orgs/2/workspaces/20/participants/add"))
+            .and(body_json(json!({ "teamId": 8 })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "participant": { "participantId": 60, "teamId": 8, "teamName": "bioinfo", "wspRole": "maintain", "type": "TEAM" }
+            })))
+            .expect(1)
+            .mount(&target)
+            .await;
+
+        let source_client = super::super::SeqeraClient::with_base_url("source-token".to_string(), &source.uri()).unwrap();
+        let target_client = super::super::SeqeraClient::with_base_url("target-token".to_string(), &target.uri()).unwrap();
+        let options = MigrationOptions {
+            mapping: MigrationMapping {
+                credentials: [("ssh-keys".to_string(), "cluster-keys".to_string())].into(),
+                ..Default::default()
+            },
+            secrets: vec![("API_KEY".to_string(), Secret::new("s3cr3t"))],
+            dry_run: false,
+        };
+
+        let report = source_client
+            .migrate_workspace(1, 10, &target_client, 2, 20, &options)
+            .await
+            .unwrap();
+
+        let outcomes: Vec<(ResourceKind, &str, &MigrationOutcome)> =
+            report.resources.iter().map(|r| (r.kind, r.name.as_str(), &r.outcome)).collect();
+        assert_eq!(outcomes, vec![
+            (ResourceKind::Label, "project=rna", &MigrationOutcome::Created),
+            (ResourceKind::Label, "team", &MigrationOutcome::Skipped("already exists".to_string())),
+            (ResourceKind::ComputeEnv, "hpc", &MigrationOutcome::Created),
+            (ResourceKind::Pipeline, "rnaseq", &MigrationOutcome::Created),
+            (ResourceKind::Secret, "API_KEY", &MigrationOutcome::Created),
+            (ResourceKind::Secret, "TOKEN", &MigrationOutcome::Failed("no value supplied".to_string())),
+            (ResourceKind::Participant, "adam", &MigrationOutcome::Skipped("the owner role comes from the organization".to_string())),
+            (ResourceKind::Participant, "bioinfo", &MigrationOutcome::Created),
+        ]);
+        assert!(!report.is_complete());
+    }
+
+    #[tokio::test]
+    async fn test_migrate_pipelines_reports_missing_labels_and_unreadable_launches() {
+        let source = MockServer::start().await;
+        let target = MockServer::start().await;
+
+        mount_json(&source, "/labels", json!({
+            "labels": [{ "id": 1, "name": "project", "value": "rna", "resource": true }],
+            "totalSize": 1
+        })).await;
+        mount_json(&source, "/credentials", json!({ "credentials": [] })).await;
+        mount_json(&source, "/compute-envs", json!({ "computeEnvs": [] })).await;
+        mount_json(&source, "/pipelines", json!({
+            "pipelines": [
+                {
+                    "pipelineId": 5, "name": "rnaseq",
+                    "labels": [{ "id": 1, "name": "project", "value": "rna", "resource": true }]
+                },
+                { "pipelineId": 6, "name": "sarek" }
+            ],
+            "totalSize": 2
+        })).await;
+        mount_json(&source, "/pipelines/5/launch", json!({
+            "launch": { "pipeline": "https://github.com/nf-core/rnaseq", "revision": "3.14.0" }
+        })).await;
+        Mock::given(method("GET"))
+            .and(path("/pipelines/6/launch"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&source)
+            .await;
+        mount_json(&source, "/pipeline-secrets", json!({ "pipelineSecrets": [] })).await;
+        mount_json(&source, "/orgs/1/workspaces/10/participants", json!({ "participants": [], "totalSize": 0 })).await;
+
+        mount_json(&target, "/labels", json!({ "labels": [], "totalSize": 0 })).await;
+        mount_json(&target, "/credentials", json!({ "credentials": [] })).await;
+        mount_json(&target, "/compute-envs", json!({ "computeEnvs": [] })).await;
+        mount_json(&target, "/pipelines", json!({ "pipelines": [], "totalSize": 0 })).await;
+        mount_json(&target, "/pipeline-secrets", json!({ "pipelineSecrets": [] })).await;
+        mount_json(&target, "/orgs/2/workspaces/20/participants", json!({ "participants": [], "totalSize": 0 })).await;
+        mount_json(&target, "/orgs/2/teams", json!({ "teams": [], "totalSize": 0 })).await;
+
+        Mock::given(method("POST"))
+            .and(path("/labels"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&target)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/pipelines"))
+            .and(body_partial_json(json!({ "name": "rnaseq" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "pipeline": { "pipelineId": 50, "name": "rnaseq" }
+            })))
+            .expect(1)
+            .mount(&target)
+            .await;
+
+        let source_client = super::super::SeqeraClient::with_base_url("source-token".to_string(), &source.uri()).unwrap();
+        let target_client = super::super::SeqeraClient::with_base_url("target-token".to_string(), &target.uri()).unwrap();
+
+        let report = source_client
+            .migrate_workspace(1, 10, &target_client, 2, 20, &MigrationOptions::default())
+            .await
+            .unwrap();
+
+        let pipelines: Vec<_> = report.resources.iter().filter(|r| r.kind == ResourceKind::Pipeline).collect();
+        assert_eq!(pipelines.len(), 2);
+        assert_eq!(
+            pipelines[0].outcome,
+            MigrationOutcome::Failed("created without labels project=rna, which failed to migrate".to_string())
+        );
+        assert_eq!(pipelines[1].name, "sarek");
+        assert!(matches!(pipelines[1].outcome, MigrationOutcome::Failed(_)));
+    }
+
+    #[tokio::test]
+    async fn test_migrate_mapped_compute_env_is_not_created() {
+        let source = MockServer::start().await;
+        let target = MockServer::start().await;
+
+        for (server, org, ws) in [(&source, 1, 10), (&target, 2, 20)] {
+            mount_json(server, "/labels", json!({ "labels": [], "totalSize": 0 })).await;
+            mount_json(server, "/pipeline-secrets", json!({ "pipelineSecrets": [] })).await;
+            mount_json(server, &format!("/orgs/{}/workspaces/{}/participants", org, ws), json!({
+                "participants": [], "totalSize": 0
+            })).await;
+        }
+        mount_json(&source, "/credentials", credentials("src-cred", "aws-keys")).await;
+        mount_json(&source, "/compute-envs", json!({
+            "computeEnvs": [{
+                "id": "src-ce", "name": "aws-cloud", "credentialsId": "src-cred", "platform": "aws-batch",
+                "status": "AVAILABLE", "visibility": "PRIVATE", "workDir": "s3://a/work", "workspaceName": "genomics"
+            }]
+        })).await;
+        mount_json(&source, "/pipelines", json!({ "pipelines": [{ "pipelineId": 5, "name": "rnaseq" }], "totalSize": 1 })).await;
+        mount_json(&source, "/pipelines/5/launch", json!({
+            "launch": { "computeEnv": { "id": "src-ce", "name": "aws-cloud" }, "pipeline": "https://github.com/nf-core/rnaseq" }
+        })).await;
+
+        mount_json(&target, "/credentials", credentials("tgt-cred", "aws-keys")).await;
+        mount_json(&target, "/compute-envs", json!({
+            "computeEnvs": [{
+                "id": "tgt-ce", "name": "aws-prod", "credentialsId": "tgt-cred", "platform": "aws-batch",
+                "status": "AVAILABLE", "visibility": "PRIVATE", "workDir": "s3://b/work", "workspaceName": "prod"
+            }]
+        })).await;
+        mount_json(&target, "/pipelines", json!({ "pipelines": [], "totalSize": 0 })).await;
+        mount_json(&target, "/orgs/2/teams", json!({ "teams": [], "totalSize": 0 })).await;
+
+        Mock::given(method("POST"))
+            .and(path("/compute-envs"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "computeEnvId": "dup" })))
+            .expect(0)
+            .mount(&target)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/pipelines"))
+            .and(body_partial_json(json!({ "launch": { "computeEnvId": "tgt-ce" } })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "pipeline": { "pipelineId": 50, "name": "rnaseq" }
+            })))
+            .expect(1)
+            .mount(&target)
+            .await;
+
+        let source_client = super::super::SeqeraClient::with_base_url("source-token".to_string(), &source.uri()).unwrap();
+        let target_client = super::super::SeqeraClient::with_base_url("target-token".to_string(), &target.uri()).unwrap();
+        let options = MigrationOptions {
+            mapping: MigrationMapping {
+                compute_envs: [("src-ce".to_string(), "aws-prod".to_string())].into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let report = source_client
+            .migrate_workspace(1, 10, &target_client, 2, 20, &options)
+            .await
+            .unwrap();
+
+        assert_eq!(report.resources[0].kind, ResourceKind::ComputeEnv);
+        assert_eq!(report.resources[0].outcome, MigrationOutcome::Skipped("mapped to aws-prod".to_string()));
+        assert!(report.is_complete());
+    }
+}
@@ -1,6 +1,9 @@
 use reqwest::{Client, Request, StatusCode};
+use std::future::Future;
+use std::time::{Duration, Instant};
 use url::Url;
 use crate::errors::SeqeraError;
+use crate::models::service_info::ServiceInfo;
 use crate::utils::mask_auth_header;
 
 pub mod organization;
@@ -9,6 +12,22 @@ pub mod workspace;
 pub mod member;
 pub mod platform;
 pub mod compute_env;
+pub mod credentials;
+pub mod pipeline_secret;
+pub mod label;
+pub mod data_link;
+pub mod dataset;
+pub mod studio;
+pub mod action;
+pub mod token;
+pub mod user;
+pub mod service_info;
+pub mod workspace_settings;
+pub mod audit;
+pub mod org_spec;
+pub mod pipeline;
+pub mod export;
+pub mod migration;
 
 const DEFAULT_BASE_URL: &str = "https://api.cloud.seqera.io/";
 
@@ -17,6 +36,8 @@ pub struct SeqeraClient {
     base_url: Url,
     token: String,
     verbose: bool,
+    /// Set by `negotiate`; gates optional features when present
+    service_info: Option<ServiceInfo>,
 }
 
 impl SeqeraClient {
@@ -31,6 +52,7 @@ impl SeqeraClient {
             base_url,
             token,
             verbose: false,
+            service_info: None,
         })
     }
 
@@ -38,6 +60,17 @@ impl SeqeraClient {
         self.verbose = verbose;
     }
 
+    /// A client for the same server authenticated with a different token
+    pub fn with_token(&self, token: String) -> Self {
+        Self {
+            client: self.client.clone(),
+            base_url: self.base_url.clone(),
+            token,
+            verbose: self.verbose,
+            service_info: self.service_info.clone(),
+        }
+    }
+
     fn auth_header(&self) -> String {
         format!("Bearer {}", self.token)
     }
@@ -114,6 +147,55 @@ impl SeqeraClient {
     }
 }
 
+/// Calls `poll` until it returns a value, sleeping `poll_interval` in between.
+/// With a `timeout`, the last sleep is cut short so that the final poll
+/// happens at the deadline, and [`SeqeraError::Timeout`] is returned if that
+/// poll doesn't return a value either.
+async fn poll_until<T, F, Fut>(
+    poll_interval: Duration,
+    timeout: Option<Duration>,
+    what: impl FnOnce() -> String,
+    mut poll: F,
+) -> Result<T, SeqeraError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<Option<T>, SeqeraError>>,
+{
+    let started = Instant::now();
+    loop {
+        if let Some(value) = poll().await? {
+            return Ok(value);
+        }
+        let sleep = match timeout {
+            Some(timeout) => {
+                let elapsed = started.elapsed();
+                if elapsed >= timeout {
+                    return Err(SeqeraError::Timeout { what: what(), elapsed });
+                }
+                poll_interval.min(timeout - elapsed)
+            }
+            None => poll_interval,
+        };
+        tokio::time::sleep(sleep).await;
+    }
+}
+
+/// Helpers shared by the client tests
+#[cfg(test)]
+pub(crate) mod fixtures {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// Answers GET requests to `route` with `body`
+    pub async fn mount_json(mock_server: &MockServer, route: &str, body: serde_json::Value) {
+        Mock::given(method("GET"))
+            .and(path(route))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .mount(mock_server)
+            .await;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -149,5 +231,18 @@ mod tests {
         assert!(curl.contains("-H 'authorization: Bearer token'"));
         assert!(curl.contains("example.com"));
     }
+
+    #[test]
+    fn test_request_to_curl_omits_body() {
+        let client = Client::new();
+        let request = client
+            .post("https://example.com/pipeline-secrets")
+            .json(&serde_json::json!({"name": "TOKEN", "value": "s3cr3t"}))
+            .build()
+            .unwrap();
+
+        let curl = SeqeraClient::request_to_curl(&request);
+        assert!(!curl.contains("s3cr3t"));
+    }
 }
 
@@ -0,0 +1,363 @@
+use std::collections::HashMap;
+use crate::errors::SeqeraError;
+use crate::models::common::{OrgId, WorkspaceId};
+use crate::models::compute_env::CreateComputeEnvRequest;
+use crate::models::label::{CreateLabelRequest, LabelType, UpdateLabelRequest};
+use crate::models::org_spec::{ApplyReport, ApplyStep, Change, OrgPlan, OrgState, TeamState, WorkspaceState};
+use crate::models::team::{CreateTeamMemberRequest, CreateTeamRequestBuilder, UpdateTeamRequest};
+use crate::models::workspace::{AddWorkspaceParticipantRequest, CreateWorkspaceRequestBuilder, UpdateWorkspaceRequest};
+
+impl super::SeqeraClient {
+    /// Gathers the teams and workspaces of an organization, with everything
+    /// an [`OrgSpec`](crate::models::org_spec::OrgSpec) can describe
+    pub async fn get_org_state(&self, org_id: impl Into<OrgId>) -> Result<OrgState, SeqeraError> {
+        let org_id = org_id.into();
+
+        let mut teams = Vec::new();
+        for team in self.list_organization_teams(org_id.0).await?.teams {
+            let members = self.list_all_team_members(org_id.0, team.team_id, 100, None).await?;
+            teams.push(TeamState { team, members });
+        }
+
+        let mut workspaces = Vec::new();
+        for workspace in self.list_workspaces(org_id).await?.workspaces {
+            let participants = self.list_all_workspace_participants(org_id, workspace.id, 100).await?;
+            let labels = self.list_all_labels(workspace.id, LabelType::All, 100).await?;
+            let compute_envs = self.list_compute_envs(workspace.id, None).await?.compute_envs;
+            workspaces.push(WorkspaceState { workspace, participants, labels, compute_envs });
+        }
+
+        Ok(OrgState { teams, workspaces })
+    }
+
+    /// Applies a plan's changes in order, stopping at the first failure
+    pub async fn apply_org_plan(
+        &self,
+        org_id: impl Into<OrgId>,
+        plan: &OrgPlan<'_>,
+    ) -> Result<ApplyReport, SeqeraError> {
+        let org_id = org_id.into();
+        let mut ids = PlanIds {
+            teams: self
+                .list_organization_teams(org_id.0)
+                .await?
+                .teams
+                .into_iter()
+                .map(|t| (t.name, t.team_id))
+                .collect(),
+            workspaces: self
+                .list_workspaces(org_id)
+                .await?
+                .workspaces
+                .into_iter()
+                .map(|w| (w.name, w.id))
+                .collect(),
+        };
+
+        let mut steps = Vec::new();
+        for (i, change) in plan.changes.iter().enumerate() {
+            let result = self.apply_change(org_id, change, &mut ids).await;
+            let failed = result.is_err();
+            steps.push(ApplyStep { description: change.to_string(), error: result.err() });
+            if failed {
+                return Ok(ApplyReport { steps, skipped: plan.changes.len() - i - 1 });
+            }
+        }
+        Ok(ApplyReport { steps, skipped: 0 })
+    }
+
+    async fn apply_change(&self, org_id: OrgId, change: &Change<'_>, ids: &mut PlanIds) -> Result<(), String> {
+        match change {
+            Change::CreateTeam { spec } => {
+                let mut builder = CreateTeamRequestBuilder::new().name(&spec.name);
+                if let Some(description) = &spec.description {
+                    builder = builder.description(description);
+                }
+                let request = builder.build()?;
+                let team = self.create_team(org_id.0, request).await.map_err(|e| e.to_string())?;
+                ids.teams.insert(team.name, team.team_id);
+            }
+            Change::UpdateTeam { team_id, spec, .. } => {
+                let request = UpdateTeamRequest {
+                    name: spec.name.clone(),
+                    description: spec.description.clone(),
+                    avatar_id: None,
+                };
+                self.update_team(org_id.0, *team_id, request).await.map_err(|e| e.to_string())?;
+            }
+            Change::DeleteTeam { team_id, .. } => {
+                self.delete_team(org_id.0, *team_id).await.map_err(|e| e.to_string())?;
+            }
+            Change::AddTeamMember { team, user } => {
+                let request = CreateTeamMemberRequest { user_name_or_email: user.clone() };
+                self.create_team_member(org_id.0, ids.team(team)?, request)
+                    .await
+                    .map_err(|e| e.to_string())?;
+            }
+            Change::RemoveTeamMember { team_id, member_id, .. } => {
+                self.delete_team_member(org_id.0, *team_id, *member_id)
+                    .await
+                    .map_err(|e| e.to_string())?;
+            }
+            Change::CreateWorkspace { spec } => {
+                let mut builder = CreateWorkspaceRequestBuilder::new()
+                    .name(&spec.name)
+                    .full_name(spec.full_name())
+                    .visibility(spec.visibility);
+                if let Some(description) = &spec.description {
+                    builder = builder.description(description);
+                }
+                let request = builder.build()?;
+                let workspace = self.create_workspace(org_id, request).await.map_err(|e| e.to_string())?;
+                ids.workspaces.insert(workspace.name, workspace.id);
+            }
+            Change::UpdateWorkspace { workspace_id, spec, .. } => {
+                let request = UpdateWorkspaceRequest {
+                    name: spec.name.clone(),
+                    full_name: spec.full_name().to_string(),
+                    description: spec.description.clone(),
+                    visibility: spec.visibility,
+                };
+                self.update_workspace(org_id, *workspace_id, request)
+                    .await
+                    .map_err(|e| e.to_string())?;
+            }
+            Change::DeleteWorkspace { workspace_id, .. } => {
+                self.delete_workspace(org_id, *workspace_id).await.map_err(|e| e.to_string())?;
+            }
+            Change::CreateLabel { workspace, spec } => {
+                let request = match &spec.value {
+                    Some(value) => CreateLabelRequest::resource(&spec.name, value).default_label(spec.is_default),
+                    None => CreateLabelRequest::simple(&spec.name),
+                };
+                self.create_label(ids.workspace(workspace)?, request)
+                    .await
+                    .map_err(|e| e.to_string())?;
+            }
+            Change::UpdateLabel { workspace_id, label_id, spec, .. } => {
+                let request = UpdateLabelRequest {
+                    name: spec.name.clone(),
+                    value: spec.value.clone(),
+                    is_default: Some(spec.is_default),
+                };
+                self.update_label(*workspace_id, *label_id, request)
+                    .await
+                    .map_err(|e| e.to_string())?;
+            }
+            Change::DeleteLabel { workspace_id, label_id, .. } => {
+                self.delete_label(*workspace_id, *label_id).await.map_err(|e| e.to_string())?;
+            }
+            Change::CreateComputeEnv { workspace, spec, credentials, config } => {
+                let workspace_id = ids.workspace(workspace)?;
+                let credentials = self
+                    .list_credentials(workspace_id, None)
+                    .await
+                    .map_err(|e| e.to_string())?
+                    .credentials
+                    .into_iter()
+                    .find(|c| &c.name == credentials)
+                    .ok_or_else(|| format!("no credentials named {} in workspace {}", credentials, workspace))?;
+                let mut request = CreateComputeEnvRequest::new(&spec.name, credentials.id, (*config).clone());
+                if let Some(description) = &spec.description {
+                    request = request.description(description);
+                }
+                self.create_compute_env(workspace_id, request)
+                    .await
+                    .map_err(|e| e.to_string())?;
+            }
+            Change::SetPrimaryComputeEnv { workspace, name } => {
+                let workspace_id = ids.workspace(workspace)?;
+                let compute_env = self
+                    .list_compute_envs(workspace_id, None)
+                    .await
+                    .map_err(|e| e.to_string())?
+                    .compute_envs
+                    .into_iter()
+                    .find(|ce| &ce.name == name)
+                    .ok_or_else(|| format!("no compute environment named {} in workspace {}", name, workspace))?;
+                self.set_primary_compute_env(&compute_env.id, workspace_id)
+                    .await
+                    .map_err(|e| e.to_string())?;
+            }
+            Change::DeleteComputeEnv { workspace_id, compute_env_id, .. } => {
+                self.delete_compute_env(compute_env_id, *workspace_id)
+                    .await
+                    .map_err(|e| e.to_string())?;
+            }
+            Change::AddParticipant { workspace, spec } => {
+                let request = match (&spec.team, &spec.member) {
+                    (Some(team), _) => AddWorkspaceParticipantRequest::with_team_id(ids.team(team)?),
+                    (None, Some(member)) => AddWorkspaceParticipantRequest::with_user_name_or_email(member),
+                    (None, None) => return Err("participant has neither member nor team".to_string()),
+                };
+                self.add_workspace_participant_with_role(org_id, ids.workspace(workspace)?, request, spec.role.clone())
+                    .await
+                    .map_err(|e| e.to_string())?;
+            }
+            Change::UpdateParticipantRole { workspace_id, participant_id, to, .. } => {
+                self.update_workspace_participant_role(org_id, *workspace_id, *participant_id, to.clone())
+                    .await
+                    .map_err(|e| e.to_string())?;
+            }
+            Change::RemoveParticipant { workspace_id, participant_id, .. } => {
+                self.delete_workspace_participant(org_id, *workspace_id, *participant_id)
+                    .await
+                    .map_err(|e| e.to_string())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// IDs of teams and workspaces by name, including those created while applying
+struct PlanIds {
+    teams: HashMap<String, i64>,
+    workspaces: HashMap<String, WorkspaceId>,
+}
+
+impl PlanIds {
+    fn team(&self, name: &str) -> Result<i64, String> {
+        self.teams.get(name).copied().ok_or_else(|| format!("no team named {}", name))
+    }
+
+    fn workspace(&self, name: &str) -> Result<WorkspaceId, String> {
+        self.workspaces.get(name).copied().ok_or_else(|| format!("no workspace named {}", name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::member::fixtures::member_json;
+    use crate::models::org_spec::{OrgSpec, SpecFormat};
+    use serde_json::json;
+    use wiremock::matchers::{body_json, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const SPEC: &str = r#"
+organization: acme
+teams:
+  - name: bioinfo
+    members: [alice]
+workspaces:
+  - name: genomics
+    participants:
+      - team: bioinfo
+        role: maintain
+    labels:
+      - name: project
+"#;
+
+    async fn mount_empty_org(mock_server: &MockServer) {
+        Mock::given(method("GET"))
+            .and(path("/orgs/1/teams"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "teams": [], "totalSize": 0 })))
+            .up_to_n_times(2)
+            .mount(mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/orgs/1/workspaces"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "workspaces": [] })))
+            .mount(mock_server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_apply_creates_in_dependency_order() {
+        let mock_server = MockServer::start().await;
+        mount_empty_org(&mock_server).await;
+
+        Mock::given(method("POST"))
+            .and(path("/orgs/1/teams"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "team": { "teamId": 7, "name": "bioinfo", "membersCount": 0 }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/orgs/1/teams/7/members"))
+            .and(body_json(json!({ "userNameOrEmail": "alice" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "member": member_json(5, "alice", "member") })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/orgs/1/workspaces"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "workspace": { "id": 10, "name": "genomics", "fullName": "genomics", "visibility": "PRIVATE" }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/labels"))
+            .and(query_param("workspaceId", "10"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": 3, "name": "project" })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("PUT"))
+            .and(path("/orgs/1/workspaces/10/participants/add"))
+            .and(body_json(json!({ "teamId": 7 })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "participant": {
+                    "participantId": 20, "teamId": 7, "teamName": "bioinfo", "wspRole": "launch", "type": "TEAM"
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("PUT"))
+            .and(path("/orgs/1/workspaces/10/participants/20/role"))
+            .and(body_json(json!({ "role": "maintain" })))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let spec = OrgSpec::parse(SPEC, SpecFormat::Yaml).unwrap();
+        let state = client.get_org_state(1).await.unwrap();
+        let plan = spec.plan(&state, false).unwrap();
+        assert_eq!(plan.changes.len(), 5);
+
+        let report = client.apply_org_plan(1, &plan).await.unwrap();
+        assert!(report.is_complete(), "{:?}", report);
+        assert_eq!(report.steps[4].description, "+ workspace.genomics.participant[team:bioinfo]");
+    }
+
+    #[tokio::test]
+    async fn test_apply_stops_at_first_failure() {
+        let mock_server = MockServer::start().await;
+        mount_empty_org(&mock_server).await;
+
+        Mock::given(method("POST"))
+            .and(path("/orgs/1/teams"))
+            .respond_with(ResponseTemplate::new(409).set_body_string("Team already exists"))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let spec = OrgSpec::parse(SPEC, SpecFormat::Yaml).unwrap();
+        let state = client.get_org_state(1).await.unwrap();
+        let plan = spec.plan(&state, false).unwrap();
+        let report = client.apply_org_plan(1, &plan).await.unwrap();
+        assert!(!report.is_complete());
+        assert_eq!(report.failures().count(), 1);
+        assert_eq!(report.skipped, 4);
+    }
+}
@@ -0,0 +1,152 @@
+use crate::errors::SeqeraError;
+use crate::models::common::{Validate, WorkspaceId};
+use crate::models::pipeline::{
+    CreatePipelineRequest, CreatePipelineResponse, DescribePipelineLaunchResponse, ListPipelinesResponse,
+    Pipeline, PipelineLaunch,
+};
+
+impl super::SeqeraClient {
+    pub async fn list_pipelines(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        max: Option<i32>,
+        offset: Option<i32>,
+        search: Option<&str>,
+    ) -> Result<ListPipelinesResponse, SeqeraError> {
+        let url = self.base_url.join("pipelines")?;
+        let mut request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header())
+            .query(&[("workspaceId", workspace_id.into().0)])
+            .query(&[("attributes", "labels")]);
+
+        if let Some(max) = max {
+            request = request.query(&[("max", max)]);
+        }
+        if let Some(offset) = offset {
+            request = request.query(&[("offset", offset)]);
+        }
+        if let Some(search) = search {
+            request = request.query(&[("search", search)]);
+        }
+
+        let request = request.build()?;
+        let response = self.handle_response(request).await?;
+        Ok(response.json().await?)
+    }
+
+    /// Lists every pipeline of a workspace, fetching `page_size` pipelines per request
+    pub async fn list_all_pipelines(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        page_size: i32,
+    ) -> Result<Vec<Pipeline>, SeqeraError> {
+        let workspace_id = workspace_id.into();
+        let mut pipelines = Vec::new();
+        loop {
+            let page = self
+                .list_pipelines(workspace_id, Some(page_size), Some(pipelines.len() as i32), None)
+                .await?;
+            let fetched = page.pipelines.len();
+            pipelines.extend(page.pipelines);
+            if fetched == 0 || pipelines.len() as i64 >= page.total_size {
+                return Ok(pipelines);
+            }
+        }
+    }
+
+    pub async fn get_pipeline_launch(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        pipeline_id: i64,
+    ) -> Result<PipelineLaunch, SeqeraError> {
+        let mut url = self.base_url.join(&format!("pipelines/{}/launch", pipeline_id))?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string());
+
+        let request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        let wrapper: DescribePipelineLaunchResponse = response.json().await?;
+        Ok(wrapper.launch)
+    }
+
+    /// Adds a pipeline to the workspace's launchpad
+    pub async fn create_pipeline(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        request: CreatePipelineRequest,
+    ) -> Result<Pipeline, SeqeraError> {
+        request.validate()?;
+        let mut url = self.base_url.join("pipelines")?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string());
+
+        let request = self.client
+            .post(url)
+            .header("Authorization", self.auth_header())
+            .json(&request)
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        let wrapper: CreatePipelineResponse = response.json().await?;
+        Ok(wrapper.pipeline)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_list_all_pipelines_and_launch() {
+        let mock_server = MockServer::start().await;
+
+        for (offset, name) in [("0", "rnaseq"), ("1", "sarek")] {
+            Mock::given(method("GET"))
+                .and(path("/pipelines"))
+                .and(query_param("workspaceId", "123"))
+                .and(query_param("offset", offset))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "pipelines": [{ "pipelineId": offset.parse::<i64>().unwrap() + 1, "name": name }],
+                    "totalSize": 2
+                })))
+                .mount(&mock_server)
+                .await;
+        }
+
+        Mock::given(method("GET"))
+            .and(path("/pipelines/1/launch"))
+            .and(query_param("workspaceId", "123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "launch": {
+                    "id": "abc",
+                    "computeEnv": { "id": "ce1", "name": "aws-prod", "platform": "aws-batch" },
+                    "pipeline": "https://github.com/nf-core/rnaseq",
+                    "revision": "3.14.0",
+                    "configProfiles": ["docker"],
+                    "resume": false
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let pipelines = client.list_all_pipelines(123, 1).await.unwrap();
+        assert_eq!(pipelines.iter().map(|p| p.name.as_str()).collect::<Vec<_>>(), vec!["rnaseq", "sarek"]);
+
+        let launch = client.get_pipeline_launch(123, 1).await.unwrap();
+        assert_eq!(launch.compute_env.unwrap().name, "aws-prod");
+        assert_eq!(launch.config_profiles, vec!["docker"]);
+        assert_eq!(launch.other.get("resume"), Some(&json!(false)));
+    }
+}
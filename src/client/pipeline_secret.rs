@@ -0,0 +1,343 @@
+use std::collections::HashMap;
+use url::Url;
+use crate::errors::SeqeraError;
+use crate::models::common::{Secret, Validate};
+use crate::models::pipeline_secret::{
+    CreatePipelineSecretRequest, CreatePipelineSecretResponse, DescribePipelineSecretResponse,
+    ListPipelineSecretsResponse, PipelineSecret, SecretChange, SecretScope, SecretSyncPlan,
+    UpdatePipelineSecretRequest,
+};
+
+impl super::SeqeraClient {
+    /// Builds a pipeline secrets URL. User-scoped secrets are addressed by
+    /// omitting the workspace ID.
+    fn pipeline_secrets_url(&self, path: &str, scope: SecretScope) -> Result<Url, SeqeraError> {
+        let mut url = self.base_url.join(path)?;
+        if let SecretScope::Workspace(workspace_id) = scope {
+            url.query_pairs_mut()
+                .append_pair("workspaceId", &workspace_id.0.to_string());
+        }
+        Ok(url)
+    }
+
+    pub async fn list_pipeline_secrets(
+        &self,
+        scope: impl Into<SecretScope>,
+    ) -> Result<ListPipelineSecretsResponse, SeqeraError> {
+        let url = self.pipeline_secrets_url("pipeline-secrets", scope.into())?;
+        let request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        Ok(response.json().await?)
+    }
+
+    pub async fn get_pipeline_secret(
+        &self,
+        scope: impl Into<SecretScope>,
+        secret_id: i64,
+    ) -> Result<PipelineSecret, SeqeraError> {
+        let url = self.pipeline_secrets_url(&format!("pipeline-secrets/{}", secret_id), scope.into())?;
+        let request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        let wrapper: DescribePipelineSecretResponse = response.json().await?;
+        Ok(wrapper.pipeline_secret)
+    }
+
+    pub async fn find_pipeline_secret_by_name(
+        &self,
+        scope: impl Into<SecretScope>,
+        name: &str,
+    ) -> Result<Option<PipelineSecret>, SeqeraError> {
+        let secrets = self.list_pipeline_secrets(scope).await?;
+        Ok(secrets.pipeline_secrets.into_iter().find(|s| s.name == name))
+    }
+
+    pub async fn validate_pipeline_secret_name(
+        &self,
+        scope: impl Into<SecretScope>,
+        name: &str,
+    ) -> Result<(), SeqeraError> {
+        let mut url = self.pipeline_secrets_url("pipeline-secrets/validate", scope.into())?;
+        url.query_pairs_mut().append_pair("name", name);
+
+        let request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let _ = self.handle_response(request).await?;
+        Ok(())
+    }
+
+    /// Creates a pipeline secret and returns its ID
+    pub async fn create_pipeline_secret(
+        &self,
+        scope: impl Into<SecretScope>,
+        name: impl Into<String>,
+        value: impl Into<Secret>,
+    ) -> Result<i64, SeqeraError> {
+        let request = CreatePipelineSecretRequest {
+            name: name.into(),
+            value: value.into(),
+        };
+        request.validate()?;
+
+        let url = self.pipeline_secrets_url("pipeline-secrets", scope.into())?;
+        let request = self.client
+            .post(url)
+            .header("Authorization", self.auth_header())
+            .json(&request)
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        let wrapper: CreatePipelineSecretResponse = response.json().await?;
+        Ok(wrapper.secret_id)
+    }
+
+    pub async fn update_pipeline_secret(
+        &self,
+        scope: impl Into<SecretScope>,
+        secret_id: i64,
+        value: impl Into<Secret>,
+    ) -> Result<(), SeqeraError> {
+        let request = UpdatePipelineSecretRequest { value: value.into() };
+        let url = self.pipeline_secrets_url(&format!("pipeline-secrets/{}", secret_id), scope.into())?;
+        let request = self.client
+            .put(url)
+            .header("Authorization", self.auth_header())
+            .json(&request)
+            .build()?;
+
+        let _ = self.handle_response(request).await?;
+        Ok(())
+    }
+
+    pub async fn delete_pipeline_secret(
+        &self,
+        scope: impl Into<SecretScope>,
+        secret_id: i64,
+    ) -> Result<(), SeqeraError> {
+        let url = self.pipeline_secrets_url(&format!("pipeline-secrets/{}", secret_id), scope.into())?;
+        let request = self.client
+            .delete(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let _ = self.handle_response(request).await?;
+        Ok(())
+    }
+
+    /// Computes the changes needed to make the secrets in `scope` match `entries`.
+    ///
+    /// Secrets that exist remotely but not in `entries` are only deleted when
+    /// `prune` is set.
+    pub async fn plan_pipeline_secret_sync(
+        &self,
+        scope: impl Into<SecretScope>,
+        entries: Vec<(String, Secret)>,
+        prune: bool,
+    ) -> Result<SecretSyncPlan, SeqeraError> {
+        let existing: HashMap<String, i64> = self
+            .list_pipeline_secrets(scope)
+            .await?
+            .pipeline_secrets
+            .into_iter()
+            .map(|s| (s.name, s.id))
+            .collect();
+
+        let mut plan = SecretSyncPlan::default();
+        let mut local_names = Vec::with_capacity(entries.len());
+        for (name, value) in entries {
+            local_names.push(name.clone());
+            match existing.get(&name) {
+                Some(&id) => plan.changes.push(SecretChange::Update { id, name, value }),
+                None => plan.changes.push(SecretChange::Add { name, value }),
+            }
+        }
+
+        if prune {
+            let mut stale: Vec<(&String, &i64)> = existing
+                .iter()
+                .filter(|(name, _)| !local_names.contains(name))
+                .collect();
+            stale.sort();
+            for (name, &id) in stale {
+                plan.changes.push(SecretChange::Delete { id, name: name.clone() });
+            }
+        }
+
+        Ok(plan)
+    }
+
+    /// Applies a plan produced by [`plan_pipeline_secret_sync`](Self::plan_pipeline_secret_sync)
+    pub async fn apply_pipeline_secret_sync(
+        &self,
+        scope: impl Into<SecretScope>,
+        plan: SecretSyncPlan,
+    ) -> Result<(), SeqeraError> {
+        let scope = scope.into();
+        for change in plan.changes {
+            match change {
+                SecretChange::Add { name, value } => {
+                    self.create_pipeline_secret(scope, name, value).await?;
+                }
+                SecretChange::Update { id, value, .. } => {
+                    self.update_pipeline_secret(scope, id, value).await?;
+                }
+                SecretChange::Delete { id, .. } => {
+                    self.delete_pipeline_secret(scope, id).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use wiremock::matchers::{body_json, header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_list_pipeline_secrets() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/pipeline-secrets"))
+            .and(query_param("workspaceId", "123"))
+            .and(header("authorization", "Bearer test-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "pipelineSecrets": [{
+                    "id": 1,
+                    "name": "API_TOKEN",
+                    "lastUsed": "2023-01-01T00:00:00Z",
+                    "dateCreated": "2022-12-01T00:00:00Z",
+                    "lastUpdated": "2022-12-02T00:00:00Z"
+                }],
+                "totalSize": 1
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let response = client.list_pipeline_secrets(123).await.unwrap();
+        assert_eq!(response.pipeline_secrets.len(), 1);
+        assert_eq!(response.pipeline_secrets[0].id, 1);
+        assert_eq!(response.pipeline_secrets[0].name, "API_TOKEN");
+    }
+
+    #[tokio::test]
+    async fn test_create_user_pipeline_secret() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/pipeline-secrets"))
+            .and(header("authorization", "Bearer test-token"))
+            .and(body_json(json!({
+                "name": "API_TOKEN",
+                "value": "s3cr3t"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "secretId": 42
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let id = client
+            .create_pipeline_secret(SecretScope::User, "API_TOKEN", "s3cr3t")
+            .await
+            .unwrap();
+        assert_eq!(id, 42);
+
+        let requests = mock_server.received_requests().await.unwrap();
+        assert!(requests[0].url.query().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_create_pipeline_secret_invalid_name() {
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            "http://localhost",
+        ).unwrap();
+
+        let result = client.create_pipeline_secret(123, "not-valid", "x").await;
+        assert!(matches!(result, Err(SeqeraError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_update_and_delete_pipeline_secret() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("PUT"))
+            .and(path("/pipeline-secrets/7"))
+            .and(query_param("workspaceId", "123"))
+            .and(body_json(json!({ "value": "new-value" })))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/pipeline-secrets/7"))
+            .and(query_param("workspaceId", "123"))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        assert!(client.update_pipeline_secret(123, 7, "new-value").await.is_ok());
+        assert!(client.delete_pipeline_secret(123, 7).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_plan_pipeline_secret_sync() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/pipeline-secrets"))
+            .and(query_param("workspaceId", "123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "pipelineSecrets": [
+                    { "id": 1, "name": "KEEP" },
+                    { "id": 2, "name": "STALE" }
+                ]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let entries = vec![
+            ("KEEP".to_string(), Secret::new("a")),
+            ("NEW".to_string(), Secret::new("b")),
+        ];
+
+        let plan = client.plan_pipeline_secret_sync(123, entries, true).await.unwrap();
+        let summary: Vec<String> = plan.changes.iter().map(|c| c.to_string()).collect();
+        assert_eq!(summary, vec!["~ KEEP", "+ NEW", "- STALE"]);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::errors::SeqeraError;
-use crate::models::platform::{ListPlatformsResponse, PlatformDetailsResponse, ListPlatformRegionsResponse};
+use crate::models::platform::{ListPlatformsResponse, PlatformDetailsResponse, PlatformId, PlatformMetaInfo, ListPlatformRegionsResponse};
 use crate::models::common::{OrgId, WorkspaceId};
 
 impl super::SeqeraClient {
@@ -31,12 +31,13 @@ impl super::SeqeraClient {
 
     pub async fn get_platform_details(
         &self,
-        platform_id: impl AsRef<str>,
+        platform_id: impl Into<PlatformId>,
         workspace_id: impl Into<WorkspaceId>,
         region_id: Option<&str>,
         credentials_id: Option<&str>,
     ) -> Result<PlatformDetailsResponse, SeqeraError> {
-        let mut url = self.base_url.join(&format!("platforms/{}", platform_id.as_ref()))?;
+        let platform_id = platform_id.into();
+        let mut url = self.base_url.join(&format!("platforms/{}", platform_id))?;
         let mut query_pairs = url.query_pairs_mut();
         
         query_pairs.append_pair("workspaceId", &workspace_id.into().0.to_string());
@@ -57,15 +58,17 @@ impl super::SeqeraClient {
             .build()?;
 
         let response = self.handle_response(request).await?;
-        Ok(response.json().await?)
+        let mut body: serde_json::Value = response.json().await?;
+        let metainfo = PlatformMetaInfo::from_value(&platform_id, body["metainfo"].take())?;
+        Ok(PlatformDetailsResponse { metainfo })
     }
 
     pub async fn list_platform_regions(
         &self,
-        platform_id: impl AsRef<str>,
+        platform_id: impl Into<PlatformId>,
         workspace_id: impl Into<WorkspaceId>,
     ) -> Result<ListPlatformRegionsResponse, SeqeraError> {
-        let mut url = self.base_url.join(&format!("platforms/{}/regions", platform_id.as_ref()))?;
+        let mut url = self.base_url.join(&format!("platforms/{}/regions", platform_id.into()))?;
         url.query_pairs_mut()
             .append_pair("workspaceId", &workspace_id.into().0.to_string());
 
@@ -87,7 +90,7 @@ mod tests {
     use serde_json::json;
 
     #[tokio::test]
-    async fn test_get_platform_details_type1() {
+    async fn test_get_platform_details_aws() {
         let mock_server = MockServer::start().await;
 
         Mock::given(method("GET"))
@@ -147,43 +150,30 @@ mod tests {
         let response = client.get_platform_details("aws-batch", 123, None, None).await.unwrap();
         
         match response.metainfo {
-            crate::models::platform::PlatformMetaInfo::Type1 { 
-                warnings,
-                job_queues,
-                buckets,
-                file_systems,
-                efs_file_systems,
-                key_pairs,
-                vpcs,
-                images,
-                security_groups,
-                subnets,
-                instance_families,
-                alloc_strategy,
-            } => {
-                assert_eq!(warnings, vec!["Warning 1"]);
-                assert_eq!(job_queues[0].name, "queue1");
-                assert_eq!(buckets[0].path, "s3://bucket1");
-                assert_eq!(file_systems[0].id, "fs-1");
-                assert_eq!(efs_file_systems[0].id, "efs-1");
-                assert_eq!(key_pairs, vec!["key1"]);
-                assert_eq!(vpcs[0].id, "vpc-1");
-                assert_eq!(images[0].id, "ami-1");
-                assert_eq!(security_groups[0].id, "sg-1");
-                assert_eq!(subnets[0].id, "subnet-1");
-                assert_eq!(instance_families, vec!["t2", "t3"]);
-                assert_eq!(alloc_strategy, vec!["BEST_FIT"]);
+            crate::models::platform::PlatformMetaInfo::Aws(info) => {
+                assert_eq!(info.warnings, vec!["Warning 1"]);
+                assert_eq!(info.job_queues[0].name, "queue1");
+                assert_eq!(info.buckets[0].path, "s3://bucket1");
+                assert_eq!(info.file_systems[0].id, "fs-1");
+                assert_eq!(info.efs_file_systems[0].id, "efs-1");
+                assert_eq!(info.key_pairs, vec!["key1"]);
+                assert_eq!(info.vpcs[0].id, "vpc-1");
+                assert_eq!(info.images[0].id, "ami-1");
+                assert_eq!(info.security_groups[0].id, "sg-1");
+                assert_eq!(info.subnets[0].id, "subnet-1");
+                assert_eq!(info.instance_families, vec!["t2", "t3"]);
+                assert_eq!(info.alloc_strategy, vec!["BEST_FIT"]);
             },
-            _ => panic!("Expected Type1 response"),
+            _ => panic!("Expected AWS metainfo"),
         }
     }
 
     #[tokio::test]
-    async fn test_get_platform_details_type2() {
+    async fn test_get_platform_details_google() {
         let mock_server = MockServer::start().await;
 
         Mock::given(method("GET"))
-            .and(path("/platforms/google-cloud"))
+            .and(path("/platforms/google-batch"))
             .and(query_param("workspaceId", "123"))
             .and(header("authorization", "Bearer test-token"))
             .respond_with(ResponseTemplate::new(200).set_body_json(json!({
@@ -209,23 +199,17 @@ mod tests {
             &mock_server.uri(),
         ).unwrap();
 
-        let response = client.get_platform_details("google-cloud", 123, None, None).await.unwrap();
+        let response = client.get_platform_details("google-batch", 123, None, None).await.unwrap();
         
         match response.metainfo {
-            crate::models::platform::PlatformMetaInfo::Type2 { 
-                locations,
-                warnings,
-                zones,
-                buckets,
-                filestores,
-            } => {
-                assert_eq!(locations, vec!["us-east1"]);
-                assert_eq!(warnings, vec!["Warning 1"]);
-                assert_eq!(zones, vec!["us-east1-b"]);
-                assert_eq!(buckets[0].path, "gs://bucket1");
-                assert_eq!(filestores[0].target, "target1");
+            crate::models::platform::PlatformMetaInfo::Google(info) => {
+                assert_eq!(info.locations, vec!["us-east1"]);
+                assert_eq!(info.warnings, vec!["Warning 1"]);
+                assert_eq!(info.zones, vec!["us-east1-b"]);
+                assert_eq!(info.buckets[0].path, "gs://bucket1");
+                assert_eq!(info.filestores[0].target, "target1");
             },
-            _ => panic!("Expected Type2 response"),
+            _ => panic!("Expected Google metainfo"),
         }
     }
 
@@ -265,4 +249,98 @@ mod tests {
         assert_eq!(region.id, "us-west-2");
         assert_eq!(region.name, "US West (Oregon)");
     }
-} 
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_get_platform_details_azure() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/platforms/azure-batch"))
+            .and(query_param("workspaceId", "123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "metainfo": {
+                    "warnings": [],
+                    "pools": ["pool1"],
+                    "containers": ["az://work"],
+                    "vmTypes": ["Standard_D4_v3"]
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let response = client.get_platform_details("azure-batch", 123, None, None).await.unwrap();
+        match response.metainfo {
+            crate::models::platform::PlatformMetaInfo::Azure(info) => {
+                assert_eq!(info.pools, vec!["pool1"]);
+                assert_eq!(info.containers, vec!["az://work"]);
+                assert_eq!(info.vm_types, vec!["Standard_D4_v3"]);
+            },
+            _ => panic!("Expected Azure metainfo"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_platform_details_grid_and_unknown() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/platforms/slurm-platform"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "metainfo": {}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/platforms/local-platform"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "metainfo": { "something": [1, 2] }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let response = client.get_platform_details("slurm-platform", 123, None, None).await.unwrap();
+        assert!(matches!(response.metainfo, crate::models::platform::PlatformMetaInfo::Grid(_)));
+        assert!(response.metainfo.warnings().is_empty());
+
+        let response = client.get_platform_details("local-platform", 123, None, None).await.unwrap();
+        match response.metainfo {
+            crate::models::platform::PlatformMetaInfo::Raw(value) => {
+                assert_eq!(value["something"], json!([1, 2]));
+            },
+            _ => panic!("Expected raw metainfo"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_platform_details_without_metainfo() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/platforms/aws-batch"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let response = client.get_platform_details("aws-batch", 123, None, None).await.unwrap();
+        match response.metainfo {
+            crate::models::platform::PlatformMetaInfo::Aws(info) => assert!(info.job_queues.is_empty()),
+            _ => panic!("Expected AWS metainfo"),
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use reqwest::StatusCode;
+use crate::errors::SeqeraError;
+use crate::models::service_info::{Feature, ServiceInfo, ServiceInfoResponse};
+
+impl super::SeqeraClient {
+    /// Fetches the server's version, API version, feature flags and
+    /// supported authentication types
+    pub async fn get_service_info(&self) -> Result<ServiceInfo, SeqeraError> {
+        let url = self.base_url.join("service-info")?;
+        let request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        let wrapper: ServiceInfoResponse = response.json().await?;
+        Ok(wrapper.service_info)
+    }
+
+    /// Fetches the service info once and keeps it, so that calls to features
+    /// the server lacks fail early with `UnsupportedByServer`
+    pub async fn negotiate(&mut self) -> Result<&ServiceInfo, SeqeraError> {
+        let info = self.get_service_info().await?;
+        Ok(self.service_info.insert(info))
+    }
+
+    /// The service info from `negotiate`, if it has been called
+    pub fn service_info(&self) -> Option<&ServiceInfo> {
+        self.service_info.as_ref()
+    }
+
+    /// Whether `feature` can be used. Without negotiation every feature is
+    /// assumed to be available.
+    pub fn supports(&self, feature: Feature) -> bool {
+        self.service_info.as_ref().is_none_or(|info| info.supports(feature))
+    }
+
+    pub(crate) fn require_feature(&self, feature: Feature) -> Result<(), SeqeraError> {
+        if self.supports(feature) {
+            Ok(())
+        } else {
+            Err(self.unsupported(feature))
+        }
+    }
+
+    /// Maps a 404 from a feature's collection endpoint to `UnsupportedByServer`:
+    /// the route itself is missing on servers that predate the feature
+    pub(crate) fn unsupported_if_missing<T>(
+        &self,
+        feature: Feature,
+        result: Result<T, SeqeraError>,
+    ) -> Result<T, SeqeraError> {
+        match result {
+            Err(SeqeraError::Api { status: StatusCode::NOT_FOUND, .. }) => Err(self.unsupported(feature)),
+            other => other,
+        }
+    }
+
+    fn unsupported(&self, feature: Feature) -> SeqeraError {
+        SeqeraError::UnsupportedByServer {
+            feature: feature.to_string(),
+            api_version: self.service_info
+                .as_ref()
+                .map(|info| info.api_version.clone())
+                .unwrap_or_else(|| "unknown".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::SeqeraError;
+    use crate::models::service_info::Feature;
+    use serde_json::json;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn mount_service_info(mock_server: &MockServer, api_version: &str) {
+        Mock::given(method("GET"))
+            .and(path("/service-info"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "serviceInfo": {
+                    "version": "v23.1.3",
+                    "apiVersion": api_version,
+                    "commitId": "abc123",
+                    "authTypes": ["github", "oidc"],
+                    "waveEnabled": true,
+                    "heartbeatInterval": 60
+                }
+            })))
+            .mount(mock_server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_negotiate_gates_features() {
+        let mock_server = MockServer::start().await;
+        mount_service_info(&mock_server, "1.15.0").await;
+
+        let mut client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        assert!(client.supports(Feature::DataStudios));
+
+        let info = client.negotiate().await.unwrap();
+        assert_eq!(info.version, "v23.1.3");
+        assert_eq!(info.auth_types, vec!["github", "oidc"]);
+
+        assert!(client.supports(Feature::DataLinks));
+        assert!(!client.supports(Feature::DataStudios));
+
+        // No studios route is mounted: the gate must stop the request first
+        match client.list_studios(123, None, None, None).await {
+            Err(SeqeraError::UnsupportedByServer { feature, api_version }) => {
+                assert_eq!(feature, "Data Studios");
+                assert_eq!(api_version, "1.15.0");
+            }
+            other => panic!("expected UnsupportedByServer, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_missing_route_is_unsupported() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/data-links"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let result = client.list_data_links(123, None, None, None, None).await;
+        assert!(matches!(
+            result,
+            Err(SeqeraError::UnsupportedByServer { ref api_version, .. }) if api_version == "unknown"
+        ));
+    }
+}
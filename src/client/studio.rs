@@ -0,0 +1,471 @@
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+use crate::errors::SeqeraError;
+use crate::models::common::{OrgId, Validate, WorkspaceId};
+use crate::models::service_info::Feature;
+use crate::models::studio::{
+    CreateStudioRequest, CreateStudioResponse, ListStudioTemplatesResponse, ListStudiosResponse,
+    Studio, StudioActionResponse, StudioStatus,
+};
+
+impl super::SeqeraClient {
+    pub async fn list_studios(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        search: Option<&str>,
+        max: Option<i32>,
+        offset: Option<i32>,
+    ) -> Result<ListStudiosResponse, SeqeraError> {
+        self.require_feature(Feature::DataStudios)?;
+        let url = self.base_url.join("studios")?;
+        let mut request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header())
+            .query(&[("workspaceId", workspace_id.into().0)]);
+
+        if let Some(search) = search {
+            request = request.query(&[("search", search)]);
+        }
+        if let Some(max) = max {
+            request = request.query(&[("max", max)]);
+        }
+        if let Some(offset) = offset {
+            request = request.query(&[("offset", offset)]);
+        }
+
+        let request = request.build()?;
+        let response = self.unsupported_if_missing(
+            Feature::DataStudios,
+            self.handle_response(request).await,
+        )?;
+        Ok(response.json().await?)
+    }
+
+    /// Lists every studio of a workspace, fetching `page_size` studios per request
+    pub async fn list_all_studios(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        page_size: i32,
+    ) -> Result<Vec<Studio>, SeqeraError> {
+        let workspace_id = workspace_id.into();
+        let mut studios = Vec::new();
+        loop {
+            let page = self
+                .list_studios(workspace_id, None, Some(page_size), Some(studios.len() as i32))
+                .await?;
+            let fetched = page.studios.len();
+            studios.extend(page.studios);
+            let complete = match page.total_size {
+                Some(total_size) => studios.len() as i64 >= total_size,
+                None => fetched < page_size as usize,
+            };
+            if fetched == 0 || complete {
+                return Ok(studios);
+            }
+        }
+    }
+
+    pub async fn list_studio_templates(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+    ) -> Result<ListStudioTemplatesResponse, SeqeraError> {
+        self.require_feature(Feature::DataStudios)?;
+        let mut url = self.base_url.join("studios/templates")?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string());
+
+        let request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let response = self.unsupported_if_missing(
+            Feature::DataStudios,
+            self.handle_response(request).await,
+        )?;
+        Ok(response.json().await?)
+    }
+
+    /// Fetches a studio, including its status and connection URL
+    pub async fn get_studio(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        session_id: &str,
+    ) -> Result<Studio, SeqeraError> {
+        self.require_feature(Feature::DataStudios)?;
+        let mut url = self.base_url.join(&format!("studios/{}", session_id))?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string());
+
+        let request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        Ok(response.json().await?)
+    }
+
+    pub async fn find_studio_by_name(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        name: &str,
+    ) -> Result<Option<Studio>, SeqeraError> {
+        let studios = self.list_studios(workspace_id, Some(name), None, None).await?;
+        Ok(studios.studios.into_iter().find(|s| s.name == name))
+    }
+
+    /// Creates a studio, starting it straight away when `auto_start` is set
+    pub async fn create_studio(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        request: CreateStudioRequest,
+        auto_start: bool,
+    ) -> Result<Studio, SeqeraError> {
+        request.validate()?;
+        self.require_feature(Feature::DataStudios)?;
+        let mut url = self.base_url.join("studios")?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string())
+            .append_pair("autoStart", &auto_start.to_string());
+
+        let request = self.client
+            .post(url)
+            .header("Authorization", self.auth_header())
+            .json(&request)
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        let wrapper: CreateStudioResponse = response.json().await?;
+        Ok(wrapper.studio)
+    }
+
+    pub async fn start_studio(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        session_id: &str,
+    ) -> Result<StudioActionResponse, SeqeraError> {
+        self.put_studio_action(workspace_id.into(), session_id, "start").await
+    }
+
+    pub async fn stop_studio(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        session_id: &str,
+    ) -> Result<StudioActionResponse, SeqeraError> {
+        self.put_studio_action(workspace_id.into(), session_id, "stop").await
+    }
+
+    async fn put_studio_action(
+        &self,
+        workspace_id: WorkspaceId,
+        session_id: &str,
+        action: &str,
+    ) -> Result<StudioActionResponse, SeqeraError> {
+        self.require_feature(Feature::DataStudios)?;
+        let mut url = self.base_url.join(&format!("studios/{}/{}", session_id, action))?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.0.to_string());
+
+        let request = self.client
+            .put(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        Ok(response.json().await?)
+    }
+
+    pub async fn delete_studio(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        session_id: &str,
+    ) -> Result<(), SeqeraError> {
+        self.require_feature(Feature::DataStudios)?;
+        let mut url = self.base_url.join(&format!("studios/{}", session_id))?;
+        url.query_pairs_mut()
+            .append_pair("workspaceId", &workspace_id.into().0.to_string());
+
+        let request = self.client
+            .delete(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let _ = self.handle_response(request).await?;
+        Ok(())
+    }
+
+    /// Polls a studio until it reaches `target` (usually `Running` or `Stopped`).
+    ///
+    /// Returns early with [`SeqeraError::StudioFailed`] if the studio ends up
+    /// `errored` or `buildFailed`, and with [`SeqeraError::Timeout`] if
+    /// `timeout` elapses first.
+    pub async fn wait_for_studio(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        session_id: &str,
+        target: StudioStatus,
+        poll_interval: Duration,
+        timeout: Option<Duration>,
+    ) -> Result<Studio, SeqeraError> {
+        let workspace_id = workspace_id.into();
+
+        super::poll_until(
+            poll_interval,
+            timeout,
+            || format!("studio {} to become {}", session_id, target),
+            move || async move {
+                let studio = self.get_studio(workspace_id, session_id).await?;
+                match studio.status() {
+                    status if status == target => Ok(Some(studio)),
+                    StudioStatus::Errored | StudioStatus::BuildFailed => Err(SeqeraError::StudioFailed {
+                        id: studio.session_id,
+                        status: studio.status_info.status.to_string(),
+                        message: studio.status_info.message.unwrap_or_else(|| "no message".to_string()),
+                    }),
+                    _ => Ok(None),
+                }
+            },
+        )
+        .await
+    }
+
+    /// Counts the studios starting or running across every workspace of an organization
+    pub async fn count_active_studios(&self, org_id: impl Into<OrgId>) -> Result<i64, SeqeraError> {
+        let workspaces = self.list_workspaces(org_id).await?.workspaces;
+        let mut active = 0;
+        for workspace in workspaces {
+            let studios = self.list_all_studios(workspace.id, 100).await?;
+            active += studios.iter().filter(|s| s.status().is_active()).count() as i64;
+        }
+        Ok(active)
+    }
+
+    /// Starts a studio unless the organization is already at its
+    /// `max_data_studios_running` quota
+    pub async fn start_studio_within_quota(
+        &self,
+        org_id: impl Into<OrgId>,
+        workspace_id: impl Into<WorkspaceId>,
+        session_id: &str,
+    ) -> Result<StudioActionResponse, SeqeraError> {
+        let org_id = org_id.into();
+        let quotas = self.get_organization_quotas(org_id.0, None).await?;
+        let limit = quotas.max_data_studios_running as i64;
+        if limit > 0 && self.count_active_studios(org_id).await? >= limit {
+            return Err(SeqeraError::QuotaExceeded {
+                what: "running data studios".to_string(),
+                limit,
+            });
+        }
+        self.start_studio(workspace_id, session_id).await
+    }
+
+    /// Running studios in a workspace with no activity since `cutoff`
+    pub async fn list_idle_studios(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        cutoff: DateTime<Utc>,
+    ) -> Result<Vec<Studio>, SeqeraError> {
+        let studios = self.list_all_studios(workspace_id, 100).await?;
+        Ok(studios
+            .into_iter()
+            .filter(|s| s.is_idle_since(cutoff))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use wiremock::matchers::{body_json, header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn studio_body(status: &str) -> serde_json::Value {
+        json!({
+            "sessionId": "s1",
+            "workspaceId": 123,
+            "name": "notebook",
+            "studioUrl": "https://s1.studios.example.com",
+            "computeEnv": { "id": "ce-1", "name": "aws", "platform": "aws-batch", "region": "eu-west-1" },
+            "template": { "repository": "cr.example.com/jupyter:1", "tool": "jupyter" },
+            "configuration": { "cpu": 2, "memory": 8192, "mountData": ["dl-1"] },
+            "statusInfo": { "status": status, "message": "boom" }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_create_studio() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/studios"))
+            .and(query_param("workspaceId", "123"))
+            .and(query_param("autoStart", "true"))
+            .and(header("authorization", "Bearer test-token"))
+            .and(body_json(json!({
+                "name": "notebook",
+                "dataStudioToolUrl": "cr.example.com/jupyter:1",
+                "computeEnvId": "ce-1",
+                "configuration": { "mountData": ["dl-1"] }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "studio": studio_body("starting") })))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let request = CreateStudioRequest::new("notebook", "cr.example.com/jupyter:1", "ce-1")
+            .mount_data_link("dl-1");
+        let studio = client.create_studio(123, request, true).await.unwrap();
+        assert_eq!(studio.session_id, "s1");
+        assert_eq!(studio.status(), StudioStatus::Starting);
+        assert_eq!(studio.configuration.mount_data, vec!["dl-1"]);
+    }
+
+    #[tokio::test]
+    async fn test_start_stop_and_delete_studio() {
+        let mock_server = MockServer::start().await;
+
+        for action in ["start", "stop"] {
+            Mock::given(method("PUT"))
+                .and(path(format!("/studios/s1/{}", action)))
+                .and(query_param("workspaceId", "123"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "jobSubmitted": true,
+                    "sessionId": "s1",
+                    "statusInfo": { "status": if action == "start" { "starting" } else { "stopping" } }
+                })))
+                .mount(&mock_server)
+                .await;
+        }
+
+        Mock::given(method("DELETE"))
+            .and(path("/studios/s1"))
+            .and(query_param("workspaceId", "123"))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let started = client.start_studio(123, "s1").await.unwrap();
+        assert_eq!(started.status_info.status, StudioStatus::Starting);
+        let stopped = client.stop_studio(123, "s1").await.unwrap();
+        assert_eq!(stopped.status_info.status, StudioStatus::Stopping);
+        assert!(client.delete_studio(123, "s1").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_studio() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/studios/s1"))
+            .and(query_param("workspaceId", "123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(studio_body("starting")))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/studios/s1"))
+            .and(query_param("workspaceId", "123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(studio_body("running")))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let studio = client
+            .wait_for_studio(123, "s1", StudioStatus::Running, Duration::from_millis(10), None)
+            .await
+            .unwrap();
+        assert_eq!(studio.studio_url.as_deref(), Some("https://s1.studios.example.com"));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_studio_failed() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/studios/s1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(studio_body("errored")))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let result = client
+            .wait_for_studio(123, "s1", StudioStatus::Running, Duration::from_millis(10), None)
+            .await;
+        assert!(matches!(result, Err(SeqeraError::StudioFailed { ref message, .. }) if message == "boom"));
+    }
+
+    #[tokio::test]
+    async fn test_start_studio_within_quota() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/orgs/1/quotas"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "quotas": {
+                    "maxWorkspaces": 10,
+                    "maxMembers": 10,
+                    "maxTeams": 10,
+                    "maxPipelinesPerWorkspace": 10,
+                    "maxParticipantsPerWorkspace": 10,
+                    "maxDatasetsPerWorkspace": 10,
+                    "maxVersionsPerDataset": 10,
+                    "maxRuns": 10,
+                    "maxLabelsPerWorkspace": 10,
+                    "maxDataStudiosRunning": 1
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/orgs/1/workspaces"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "workspaces": [{ "id": 123, "name": "ws", "fullName": "Workspace", "visibility": "PRIVATE" }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        // The running studio is on the second page
+        for (offset, status) in [("0", "stopped"), ("1", "running")] {
+            Mock::given(method("GET"))
+                .and(path("/studios"))
+                .and(query_param("workspaceId", "123"))
+                .and(query_param("offset", offset))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "studios": [studio_body(status)],
+                    "totalSize": 2
+                })))
+                .mount(&mock_server)
+                .await;
+        }
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let result = client.start_studio_within_quota(1, 123, "s2").await;
+        assert!(matches!(result, Err(SeqeraError::QuotaExceeded { limit: 1, .. })));
+    }
+}
@@ -1,8 +1,8 @@
 use crate::errors::SeqeraError;
 use crate::models::team::*;
-use crate::models::member::Member;
-use crate::models::common::Validate;
-use crate::models::workspace::WorkspaceList;
+use crate::models::member::{Member, MemberList, Participant};
+use crate::models::common::{ParticipantType, Validate, WorkspaceId};
+use crate::models::workspace::{Workspace, WorkspaceList};
 
 impl super::SeqeraClient {
     pub async fn list_organization_teams(&self, org_id: i64) -> Result<TeamList, SeqeraError> {
@@ -142,14 +142,166 @@ impl super::SeqeraClient {
         let _ = self.handle_response(request).await?;
         Ok(())
     }
+
+    pub async fn find_team_by_name(&self, org_id: i64, name: &str) -> Result<Option<Team>, SeqeraError> {
+        let teams = self.list_organization_teams(org_id).await?;
+        Ok(teams.teams.into_iter().find(|t| t.name == name))
+    }
+
+    pub async fn list_team_members(
+        &self,
+        org_id: i64,
+        team_id: i64,
+        max: Option<i32>,
+        offset: Option<i32>,
+        search: Option<&str>,
+    ) -> Result<MemberList, SeqeraError> {
+        let url = self.base_url.join(&format!("orgs/{}/teams/{}/members", org_id, team_id))?;
+        let mut request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header());
+
+        if let Some(max) = max {
+            request = request.query(&[("max", max)]);
+        }
+        if let Some(offset) = offset {
+            request = request.query(&[("offset", offset)]);
+        }
+        if let Some(search) = search {
+            request = request.query(&[("search", search)]);
+        }
+
+        let request = request.build()?;
+        let response = self.handle_response(request).await?;
+        Ok(response.json().await?)
+    }
+
+    /// Lists every member of a team, or every one matching `search`,
+    /// fetching `page_size` members per request
+    pub async fn list_all_team_members(
+        &self,
+        org_id: i64,
+        team_id: i64,
+        page_size: i32,
+        search: Option<&str>,
+    ) -> Result<Vec<Member>, SeqeraError> {
+        let mut members = Vec::new();
+        loop {
+            let page = self
+                .list_team_members(org_id, team_id, Some(page_size), Some(members.len() as i32), search)
+                .await?;
+            let fetched = page.members.len();
+            members.extend(page.members);
+            if fetched == 0 || members.len() as i64 >= page.total_size {
+                return Ok(members);
+            }
+        }
+    }
+
+    /// Deletes a team. Its members stay in the organization but lose the
+    /// workspace access they had through the team.
+    pub async fn delete_team(&self, org_id: i64, team_id: i64) -> Result<(), SeqeraError> {
+        let url = self.base_url.join(&format!("orgs/{}/teams/{}", org_id, team_id))?;
+        let request = self.client
+            .delete(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let _ = self.handle_response(request).await?;
+        Ok(())
+    }
+
+    /// Lists every workspace a team participates in, fetching `page_size`
+    /// workspaces per request
+    pub async fn list_all_team_workspaces(
+        &self,
+        org_id: i64,
+        team_id: i64,
+        page_size: i32,
+    ) -> Result<Vec<Workspace>, SeqeraError> {
+        let mut workspaces = Vec::new();
+        loop {
+            let page = self
+                .list_team_workspaces(org_id, team_id, Some(page_size), Some(workspaces.len() as i32), None)
+                .await?;
+            let fetched = page.workspaces.len();
+            workspaces.extend(page.workspaces);
+            if fetched < page_size as usize {
+                return Ok(workspaces);
+            }
+        }
+    }
+
+    /// Lists the workspaces a team participates in, with the team's role in each
+    pub async fn list_team_participations(
+        &self,
+        org_id: i64,
+        team_id: i64,
+    ) -> Result<Vec<TeamParticipation>, SeqeraError> {
+        let team = self.get_team(org_id, team_id).await?;
+        let mut participations = Vec::new();
+        for workspace in self.list_all_team_workspaces(org_id, team_id, 100).await? {
+            if let Some(participant) = self.find_team_participant(org_id, workspace.id, &team).await? {
+                participations.push(TeamParticipation {
+                    workspace,
+                    participant_id: participant.participant_id,
+                    wsp_role: participant.wsp_role,
+                });
+            }
+        }
+        Ok(participations)
+    }
+
+    /// Removes a team from a workspace. Returns false if it wasn't a participant.
+    pub async fn remove_team_from_workspace(
+        &self,
+        org_id: i64,
+        workspace_id: i64,
+        team_id: i64,
+    ) -> Result<bool, SeqeraError> {
+        let team = self.get_team(org_id, team_id).await?;
+        match self.find_team_participant(org_id, WorkspaceId(workspace_id), &team).await? {
+            Some(participant) => {
+                self.delete_workspace_participant(org_id, workspace_id, participant.participant_id)
+                    .await?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// The participant standing for a team in a workspace, searching the
+    /// participants by the team's name page by page
+    async fn find_team_participant(
+        &self,
+        org_id: i64,
+        workspace_id: WorkspaceId,
+        team: &Team,
+    ) -> Result<Option<Participant>, SeqeraError> {
+        let mut offset = 0;
+        loop {
+            let page = self
+                .list_workspace_participants(org_id, workspace_id, Some(100), Some(offset), Some(&team.name))
+                .await?;
+            let fetched = page.participants.len();
+            offset += fetched as i32;
+            let participant = page.participants.into_iter().find(|p| {
+                p.participant_type == ParticipantType::Team && p.team_id == Some(team.team_id)
+            });
+            if participant.is_some() || fetched == 0 || offset as i64 >= page.total_size {
+                return Ok(participant);
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::common::WorkspaceId;
+    use crate::models::common::WspRole;
+    use crate::models::member::fixtures::member_json;
     use wiremock::{MockServer, Mock, ResponseTemplate};
-    use wiremock::matchers::{method, path, header};
+    use wiremock::matchers::{method, path, header, query_param};
     use serde_json::json;
 
     #[tokio::test]
@@ -264,4 +416,103 @@ mod tests {
         let result = client.delete_team_member(123, 456, 789).await;
         assert!(result.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_list_all_team_members() {
+        let mock_server = MockServer::start().await;
+
+        for (offset, user_name) in [("0", "alice"), ("1", "alan")] {
+            Mock::given(method("GET"))
+                .and(path("/orgs/123/teams/456/members"))
+                .and(query_param("max", "1"))
+                .and(query_param("offset", offset))
+                .and(query_param("search", "al"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "members": [member_json(1, user_name, "member")],
+                    "totalSize": 2
+                })))
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+        }
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let members = client.list_all_team_members(123, 456, 1, Some("al")).await.unwrap();
+        let names: Vec<_> = members.iter().map(|m| m.user_name.as_str()).collect();
+        assert_eq!(names, vec!["alice", "alan"]);
+    }
+
+    #[tokio::test]
+    async fn test_team_participations_and_removal() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/orgs/123/teams/456"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "team": { "teamId": 456, "name": "bioinfo", "membersCount": 3 }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/orgs/123/teams/456/workspaces"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "workspaces": [{
+                    "id": 789,
+                    "name": "production",
+                    "fullName": "Production",
+                    "visibility": "PRIVATE"
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        // The team comes after a member whose name also matches the search
+        let pages = [
+            ("0", json!({ "participantId": 10, "memberId": 3, "userName": "bioinfo-bot", "wspRole": "launch", "type": "MEMBER" })),
+            ("1", json!({ "participantId": 11, "teamId": 456, "teamName": "bioinfo", "wspRole": "maintain", "type": "TEAM" })),
+        ];
+        for (offset, participant) in pages {
+            Mock::given(method("GET"))
+                .and(path("/orgs/123/workspaces/789/participants"))
+                .and(query_param("search", "bioinfo"))
+                .and(query_param("offset", offset))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "participants": [participant],
+                    "totalSize": 2
+                })))
+                .mount(&mock_server)
+                .await;
+        }
+
+        Mock::given(method("GET"))
+            .and(path("/orgs/123/workspaces/999/participants"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "participants": [], "totalSize": 0 })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/orgs/123/workspaces/789/participants/11"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let participations = client.list_team_participations(123, 456).await.unwrap();
+        assert_eq!(participations.len(), 1);
+        assert_eq!(participations[0].workspace.name, "production");
+        assert_eq!(participations[0].wsp_role, WspRole::Maintain);
+
+        assert!(client.remove_team_from_workspace(123, 789, 456).await.unwrap());
+        assert!(!client.remove_team_from_workspace(123, 999, 456).await.unwrap());
+    }
 } 
\ No newline at end of file
@@ -0,0 +1,137 @@
+use crate::errors::SeqeraError;
+use crate::models::common::Validate;
+use crate::models::token::{
+    CreateAccessTokenRequest, CreateAccessTokenResponse, ListAccessTokensResponse,
+};
+
+impl super::SeqeraClient {
+    pub async fn list_access_tokens(&self) -> Result<ListAccessTokensResponse, SeqeraError> {
+        let url = self.base_url.join("tokens")?;
+        let request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        Ok(response.json().await?)
+    }
+
+    /// Creates a personal access token. The returned `access_key` is the only
+    /// time the token value is available.
+    pub async fn create_access_token(
+        &self,
+        name: impl Into<String>,
+    ) -> Result<CreateAccessTokenResponse, SeqeraError> {
+        let request = CreateAccessTokenRequest { name: name.into() };
+        request.validate()?;
+
+        let url = self.base_url.join("tokens")?;
+        let request = self.client
+            .post(url)
+            .header("Authorization", self.auth_header())
+            .json(&request)
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        Ok(response.json().await?)
+    }
+
+    pub async fn delete_access_token(&self, token_id: i64) -> Result<(), SeqeraError> {
+        let url = self.base_url.join(&format!("tokens/{}", token_id))?;
+        let request = self.client
+            .delete(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let _ = self.handle_response(request).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use wiremock::matchers::{body_json, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_list_access_tokens() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/tokens"))
+            .and(header("authorization", "Bearer test-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "tokens": [{
+                    "id": 1,
+                    "name": "ci",
+                    "lastUsed": "2024-01-01T00:00:00Z",
+                    "dateCreated": "2023-01-01T00:00:00Z"
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let tokens = client.list_access_tokens().await.unwrap().tokens;
+        assert_eq!(tokens[0].name, "ci");
+        assert!(tokens[0].last_used.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_create_and_delete_access_token() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/tokens"))
+            .and(body_json(json!({ "name": "ci-20240501123000" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "accessKey": "new-secret",
+                "token": { "id": 2, "name": "ci-20240501123000" }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/tokens/1"))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let created = client.create_access_token("ci-20240501123000").await.unwrap();
+        assert_eq!(created.token.id, 2);
+        assert_eq!(created.access_key.expose(), "new-secret");
+
+        assert!(client.delete_access_token(1).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_with_token_uses_new_credentials() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/tokens"))
+            .and(header("authorization", "Bearer rotated"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "tokens": [] })))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let rotated = client.with_token("rotated".to_string());
+        assert!(rotated.list_access_tokens().await.is_ok());
+        assert!(client.list_access_tokens().await.is_err());
+    }
+}
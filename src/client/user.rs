@@ -0,0 +1,228 @@
+use crate::errors::SeqeraError;
+use crate::models::common::{require_role, Action, OrgId, WorkspaceId, WspRole};
+use crate::models::user::{
+    ListUserWorkspacesAndOrgsResponse, OrgAndWorkspace, UserInfo, UserInfoResponse,
+};
+
+impl super::SeqeraClient {
+    /// Returns the user the client's token belongs to
+    pub async fn get_user_info(&self) -> Result<UserInfo, SeqeraError> {
+        let url = self.base_url.join("user-info")?;
+        let request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        let wrapper: UserInfoResponse = response.json().await?;
+        Ok(wrapper.user)
+    }
+
+    /// Lists every organization and workspace a user belongs to, with their role in each
+    pub async fn list_user_workspaces_and_orgs(
+        &self,
+        user_id: i64,
+    ) -> Result<Vec<OrgAndWorkspace>, SeqeraError> {
+        let url = self.base_url.join(&format!("user/{}/workspaces", user_id))?;
+        let request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        let wrapper: ListUserWorkspacesAndOrgsResponse = response.json().await?;
+        Ok(wrapper.orgs_and_workspaces)
+    }
+
+    /// The current user's effective role in a workspace, counting organization
+    /// ownership. `None` if they have no access to it.
+    pub async fn get_my_workspace_role(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+    ) -> Result<Option<WspRole>, SeqeraError> {
+        let workspace_id = workspace_id.into();
+        let user = self.get_user_info().await?;
+        let rows = self.list_user_workspaces_and_orgs(user.id).await?;
+
+        let Some(workspace) = rows.iter().find(|r| r.workspace_id == Some(workspace_id)) else {
+            return Ok(None);
+        };
+        let org_role = rows
+            .iter()
+            .find(|r| !r.is_workspace() && r.org_id == workspace.org_id)
+            .and_then(|r| r.org_role());
+        Ok(match org_role {
+            Some(org_role) => org_role.effective_workspace_role(workspace.workspace_role()),
+            None => workspace.workspace_role(),
+        })
+    }
+
+    /// The organization of a workspace the current user belongs to; `None`
+    /// if they have no access to it
+    pub async fn get_workspace_org(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+    ) -> Result<Option<OrgId>, SeqeraError> {
+        let workspace_id = Some(workspace_id.into());
+        let user = self.get_user_info().await?;
+        let rows = self.list_user_workspaces_and_orgs(user.id).await?;
+        Ok(rows.into_iter().find(|r| r.workspace_id == workspace_id).map(|r| r.org_id))
+    }
+
+    /// Checks up front that the current user may perform `action` in a
+    /// workspace, failing with [`SeqeraError::PermissionDenied`] otherwise.
+    /// Returns their effective role.
+    pub async fn require_workspace_permission(
+        &self,
+        workspace_id: impl Into<WorkspaceId>,
+        action: Action,
+    ) -> Result<WspRole, SeqeraError> {
+        let role = self.get_my_workspace_role(workspace_id).await?;
+        Ok(require_role(role, action)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::SeqeraError;
+    use crate::models::common::{Action, OrgId, WorkspaceId, WspRole};
+    use serde_json::json;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_get_user_info() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/user-info"))
+            .and(header("authorization", "Bearer test-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "user": {
+                    "id": 42,
+                    "userName": "alice",
+                    "email": "alice@example.com",
+                    "avatar": "https://example.com/alice.png"
+                },
+                "needConsent": false,
+                "defaultWorkspaceId": 10
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let user = client.get_user_info().await.unwrap();
+        assert_eq!(user.id, 42);
+        assert_eq!(user.user_name, "alice");
+        assert_eq!(user.email, "alice@example.com");
+        assert_eq!(user.avatar.as_deref(), Some("https://example.com/alice.png"));
+    }
+
+    #[tokio::test]
+    async fn test_list_user_workspaces_and_orgs() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/user/42/workspaces"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "orgsAndWorkspaces": [
+                    { "orgId": 1, "orgName": "acme", "workspaceId": null, "roles": ["member"] },
+                    {
+                        "orgId": 1,
+                        "orgName": "acme",
+                        "workspaceId": 10,
+                        "workspaceName": "genomics",
+                        "workspaceFullName": "Genomics",
+                        "visibility": "PRIVATE",
+                        "roles": ["launch"]
+                    }
+                ]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let rows = client.list_user_workspaces_and_orgs(42).await.unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1].workspace_id, Some(WorkspaceId(10)));
+        assert_eq!(rows[1].workspace_role(), Some(WspRole::Launch));
+    }
+
+    async fn mount_user_rows(mock_server: &MockServer, org_role: &str) {
+        Mock::given(method("GET"))
+            .and(path("/user-info"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "user": { "id": 42, "userName": "alice", "email": "alice@example.com" }
+            })))
+            .mount(mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/user/42/workspaces"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "orgsAndWorkspaces": [
+                    { "orgId": 1, "orgName": "acme", "workspaceId": null, "roles": [org_role] },
+                    { "orgId": 1, "orgName": "acme", "workspaceId": 10, "workspaceName": "genomics", "roles": ["launch"] }
+                ]
+            })))
+            .mount(mock_server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_require_workspace_permission() {
+        let mock_server = MockServer::start().await;
+        mount_user_rows(&mock_server, "member").await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        assert_eq!(client.require_workspace_permission(10, Action::Launch).await.unwrap(), WspRole::Launch);
+        match client.require_workspace_permission(10, Action::ManageComputeEnvs).await {
+            Err(SeqeraError::PermissionDenied(denied)) => {
+                assert_eq!(denied.role, Some(WspRole::Launch));
+                assert_eq!(denied.required, WspRole::Admin);
+            }
+            other => panic!("expected PermissionDenied, got {:?}", other),
+        }
+        assert_eq!(client.get_my_workspace_role(99).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_get_workspace_org() {
+        let mock_server = MockServer::start().await;
+        mount_user_rows(&mock_server, "member").await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        assert_eq!(client.get_workspace_org(10).await.unwrap(), Some(OrgId(1)));
+        assert_eq!(client.get_workspace_org(99).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_org_owner_has_full_workspace_access() {
+        let mock_server = MockServer::start().await;
+        mount_user_rows(&mock_server, "owner").await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let role = client.require_workspace_permission(10, Action::ManageParticipants).await.unwrap();
+        assert_eq!(role, WspRole::Owner);
+    }
+}
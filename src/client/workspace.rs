@@ -1,8 +1,8 @@
 use crate::errors::SeqeraError;
 use crate::models::workspace::*;
 use crate::models::member::ParticipantList;
-use crate::models::common::{Validate, OrgId, WorkspaceId};
-use crate::models::member::{Participant, ParticipantResponse};
+use crate::models::common::{Validate, OrgId, ParticipantType, WorkspaceId, WspRole};
+use crate::models::member::{Participant, ParticipantResponse, UpdateParticipantRoleRequest};
 
 impl super::SeqeraClient {
     pub async fn get_workspace(&self, org_id: impl Into<OrgId>, workspace_id: impl Into<WorkspaceId>) -> Result<Workspace, SeqeraError> {
@@ -97,6 +97,41 @@ impl super::SeqeraClient {
         Ok(response.json().await?)
     }
 
+    /// Lists every participant of a workspace, fetching `page_size` per request
+    pub async fn list_all_workspace_participants(
+        &self,
+        org_id: impl Into<OrgId>,
+        workspace_id: impl Into<WorkspaceId>,
+        page_size: i32,
+    ) -> Result<Vec<Participant>, SeqeraError> {
+        let org_id = org_id.into();
+        let workspace_id = workspace_id.into();
+        let mut participants = Vec::new();
+        loop {
+            let page = self
+                .list_workspace_participants(org_id, workspace_id, Some(page_size), Some(participants.len() as i32), None)
+                .await?;
+            let fetched = page.participants.len();
+            participants.extend(page.participants);
+            if fetched == 0 || participants.len() as i64 >= page.total_size {
+                return Ok(participants);
+            }
+        }
+    }
+
+    /// Finds a participant by participant ID, email, user name or team name.
+    /// `team:<name>` only matches a team; input matching more than one
+    /// participant, e.g. a user and a team of the same name, is an error.
+    pub async fn find_workspace_participant(
+        &self,
+        org_id: impl Into<OrgId>,
+        workspace_id: impl Into<WorkspaceId>,
+        who: &str,
+    ) -> Result<Option<Participant>, SeqeraError> {
+        let participants = self.list_all_workspace_participants(org_id, workspace_id, 100).await?;
+        find_participant(participants, who)
+    }
+
     pub async fn update_workspace(
         &self,
         org_id: impl Into<OrgId>,
@@ -141,6 +176,49 @@ impl super::SeqeraClient {
         Ok(wrapper.participant)
     }
 
+    /// Adds a participant and gives it `role` rather than the default launch role
+    pub async fn add_workspace_participant_with_role(
+        &self,
+        org_id: impl Into<OrgId>,
+        workspace_id: impl Into<WorkspaceId>,
+        request: AddWorkspaceParticipantRequest,
+        role: WspRole,
+    ) -> Result<Participant, SeqeraError> {
+        let org_id = org_id.into();
+        let workspace_id = workspace_id.into();
+        let mut participant = self.add_workspace_participant(org_id, workspace_id, request).await?;
+        if participant.wsp_role != role {
+            self.update_workspace_participant_role(org_id, workspace_id, participant.participant_id, role.clone())
+                .await?;
+            participant.wsp_role = role;
+        }
+        Ok(participant)
+    }
+
+    pub async fn update_workspace_participant_role(
+        &self,
+        org_id: impl Into<OrgId>,
+        workspace_id: impl Into<WorkspaceId>,
+        participant_id: i64,
+        role: WspRole,
+    ) -> Result<(), SeqeraError> {
+        let org_id = org_id.into();
+        let workspace_id = workspace_id.into();
+        let request = UpdateParticipantRoleRequest { role };
+        let url = self.base_url.join(&format!(
+            "orgs/{}/workspaces/{}/participants/{}/role",
+            org_id.0, workspace_id.0, participant_id
+        ))?;
+        let request = self.client
+            .put(url)
+            .header("Authorization", self.auth_header())
+            .json(&request)
+            .build()?;
+
+        let _ = self.handle_response(request).await?;
+        Ok(())
+    }
+
     pub async fn delete_workspace(
         &self,
         org_id: impl Into<OrgId>,
@@ -200,11 +278,40 @@ impl super::SeqeraClient {
     }
 }
 
+fn find_participant(participants: Vec<Participant>, who: &str) -> Result<Option<Participant>, SeqeraError> {
+    if let Some(team) = who.strip_prefix("team:") {
+        return Ok(participants
+            .into_iter()
+            .find(|p| p.participant_type == ParticipantType::Team && p.team_name.as_deref() == Some(team)));
+    }
+    let mut matches: Vec<Participant> = participants
+        .into_iter()
+        .filter(|p| {
+            p.participant_id.to_string() == who
+                || p.email.as_deref() == Some(who)
+                || match p.participant_type {
+                    ParticipantType::Team => p.team_name.as_deref() == Some(who),
+                    _ => p.user_name.as_deref() == Some(who),
+                }
+        })
+        .collect();
+    if matches.len() > 1 {
+        return Err(SeqeraError::AmbiguousParticipant {
+            who: who.to_string(),
+            matches: matches
+                .iter()
+                .map(|p| format!("{} {} (participant {})", p.participant_type, p.name(), p.participant_id))
+                .collect(),
+        });
+    }
+    Ok(matches.pop())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use wiremock::{MockServer, Mock, ResponseTemplate};
-    use wiremock::matchers::{method, path, header, query_param};
+    use wiremock::matchers::{body_json, method, path, header, query_param};
     use crate::models::common::{OrgRole, WspRole, ParticipantType};
     use serde_json::json;
 
@@ -295,18 +402,18 @@ mod tests {
 
         let participant = &result.participants[0];
         assert_eq!(participant.participant_id, 789);
-        assert_eq!(participant.member_id, 101);
-        assert_eq!(participant.user_name, "test-user");
-        assert_eq!(participant.first_name, "Test");
-        assert_eq!(participant.last_name, "User");
-        assert_eq!(participant.email, "test@example.com");
-        assert_eq!(participant.org_role, OrgRole::Member);
-        assert_eq!(participant.team_id, 201);
-        assert_eq!(participant.team_name, "Test Team");
+        assert_eq!(participant.member_id, Some(101));
+        assert_eq!(participant.user_name.as_deref(), Some("test-user"));
+        assert_eq!(participant.first_name.as_deref(), Some("Test"));
+        assert_eq!(participant.last_name.as_deref(), Some("User"));
+        assert_eq!(participant.email.as_deref(), Some("test@example.com"));
+        assert_eq!(participant.org_role, Some(OrgRole::Member));
+        assert_eq!(participant.team_id, Some(201));
+        assert_eq!(participant.team_name.as_deref(), Some("Test Team"));
         assert_eq!(participant.wsp_role, WspRole::Admin);
         assert_eq!(participant.participant_type, ParticipantType::Member);
-        assert_eq!(participant.team_avatar_url, "https://example.com/team-avatar.png");
-        assert_eq!(participant.user_avatar_url, "https://example.com/user-avatar.png");
+        assert_eq!(participant.team_avatar_url.as_deref(), Some("https://example.com/team-avatar.png"));
+        assert_eq!(participant.user_avatar_url.as_deref(), Some("https://example.com/user-avatar.png"));
     }
 
     #[tokio::test]
@@ -388,18 +495,18 @@ mod tests {
         let participant = client.add_workspace_participant(123, 456, request).await.unwrap();
 
         assert_eq!(participant.participant_id, 789);
-        assert_eq!(participant.member_id, 101);
-        assert_eq!(participant.user_name, "test-user");
-        assert_eq!(participant.first_name, "Test");
-        assert_eq!(participant.last_name, "User");
-        assert_eq!(participant.email, "test@example.com");
-        assert_eq!(participant.org_role, OrgRole::Member);
-        assert_eq!(participant.team_id, 201);
-        assert_eq!(participant.team_name, "Test Team");
+        assert_eq!(participant.member_id, Some(101));
+        assert_eq!(participant.user_name.as_deref(), Some("test-user"));
+        assert_eq!(participant.first_name.as_deref(), Some("Test"));
+        assert_eq!(participant.last_name.as_deref(), Some("User"));
+        assert_eq!(participant.email.as_deref(), Some("test@example.com"));
+        assert_eq!(participant.org_role, Some(OrgRole::Member));
+        assert_eq!(participant.team_id, Some(201));
+        assert_eq!(participant.team_name.as_deref(), Some("Test Team"));
         assert_eq!(participant.wsp_role, WspRole::Admin);
         assert_eq!(participant.participant_type, ParticipantType::Member);
-        assert_eq!(participant.team_avatar_url, "https://example.com/team-avatar.png");
-        assert_eq!(participant.user_avatar_url, "https://example.com/user-avatar.png");
+        assert_eq!(participant.team_avatar_url.as_deref(), Some("https://example.com/team-avatar.png"));
+        assert_eq!(participant.user_avatar_url.as_deref(), Some("https://example.com/user-avatar.png"));
     }
 
     #[tokio::test]
@@ -461,4 +568,78 @@ mod tests {
         let result = client.delete_workspace_participant(123, 456, 789).await;
         assert!(result.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_update_workspace_participant_role() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("PUT"))
+            .and(path("/orgs/123/workspaces/456/participants/789/role"))
+            .and(body_json(json!({ "role": "maintain" })))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let result = client.update_workspace_participant_role(123, 456, 789, WspRole::Maintain).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_find_workspace_participant() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/orgs/123/workspaces/456/participants"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "participants": [
+                    {
+                        "participantId": 1,
+                        "memberId": 10,
+                        "userName": "bioinfo",
+                        "email": "bioinfo@example.com",
+                        "wspRole": "launch",
+                        "type": "MEMBER"
+                    },
+                    {
+                        "participantId": 2,
+                        "teamId": 20,
+                        "teamName": "bioinfo",
+                        "wspRole": "maintain",
+                        "type": "TEAM"
+                    }
+                ],
+                "totalSize": 2
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let by_email = client.find_workspace_participant(123, 456, "bioinfo@example.com").await.unwrap();
+        assert_eq!(by_email.unwrap().participant_id, 1);
+
+        // A user and a team of the same name need telling apart
+        let ambiguous = client.find_workspace_participant(123, 456, "bioinfo").await;
+        assert!(matches!(
+            ambiguous,
+            Err(SeqeraError::AmbiguousParticipant { ref matches, .. }) if matches.len() == 2
+        ));
+        let team = client.find_workspace_participant(123, 456, "team:bioinfo").await.unwrap().unwrap();
+        assert_eq!(team.participant_type, ParticipantType::Team);
+
+        let by_id = client.find_workspace_participant(123, 456, "2").await.unwrap().unwrap();
+        assert_eq!(by_id.name(), "bioinfo");
+        assert_eq!(by_id.wsp_role, WspRole::Maintain);
+
+        assert!(client.find_workspace_participant(123, 456, "nobody").await.unwrap().is_none());
+    }
 } 
\ No newline at end of file
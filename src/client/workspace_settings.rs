@@ -0,0 +1,346 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use crate::errors::SeqeraError;
+use crate::models::common::{OrgId, ValidationError, WorkspaceId};
+use crate::models::service_info::Feature;
+use crate::models::workspace_settings::{
+    DataLinkSettings, DesiredWorkspaceSettings, SettingChange, StudiosSettings, ToggleSettings,
+    WorkspaceSettings, WorkspaceSettingsResponse,
+};
+
+impl super::SeqeraClient {
+    async fn get_workspace_settings_section<T: DeserializeOwned>(
+        &self,
+        org_id: OrgId,
+        workspace_id: WorkspaceId,
+        section: &str,
+    ) -> Result<T, SeqeraError> {
+        let url = self.base_url.join(&format!(
+            "orgs/{}/workspaces/{}/settings/{}",
+            org_id.0, workspace_id.0, section
+        ))?;
+        let request = self.client
+            .get(url)
+            .header("Authorization", self.auth_header())
+            .build()?;
+
+        let response = self.handle_response(request).await?;
+        let wrapper: WorkspaceSettingsResponse<T> = response.json().await?;
+        Ok(wrapper.settings)
+    }
+
+    async fn put_workspace_settings_section<T: Serialize>(
+        &self,
+        org_id: OrgId,
+        workspace_id: WorkspaceId,
+        section: &str,
+        settings: &T,
+    ) -> Result<(), SeqeraError> {
+        let url = self.base_url.join(&format!(
+            "orgs/{}/workspaces/{}/settings/{}",
+            org_id.0, workspace_id.0, section
+        ))?;
+        let request = self.client
+            .put(url)
+            .header("Authorization", self.auth_header())
+            .json(settings)
+            .build()?;
+
+        let _ = self.handle_response(request).await?;
+        Ok(())
+    }
+
+    pub async fn get_workspace_studios_settings(
+        &self,
+        org_id: impl Into<OrgId>,
+        workspace_id: impl Into<WorkspaceId>,
+    ) -> Result<StudiosSettings, SeqeraError> {
+        self.require_feature(Feature::DataStudios)?;
+        let result = self
+            .get_workspace_settings_section(org_id.into(), workspace_id.into(), "studios")
+            .await;
+        self.unsupported_if_missing(Feature::DataStudios, result)
+    }
+
+    pub async fn update_workspace_studios_settings(
+        &self,
+        org_id: impl Into<OrgId>,
+        workspace_id: impl Into<WorkspaceId>,
+        settings: &StudiosSettings,
+    ) -> Result<(), SeqeraError> {
+        self.require_feature(Feature::DataStudios)?;
+        let result = self
+            .put_workspace_settings_section(org_id.into(), workspace_id.into(), "studios", settings)
+            .await;
+        self.unsupported_if_missing(Feature::DataStudios, result)
+    }
+
+    pub async fn get_workspace_wave_settings(
+        &self,
+        org_id: impl Into<OrgId>,
+        workspace_id: impl Into<WorkspaceId>,
+    ) -> Result<ToggleSettings, SeqeraError> {
+        self.get_workspace_settings_section(org_id.into(), workspace_id.into(), "wave")
+            .await
+    }
+
+    pub async fn update_workspace_wave_settings(
+        &self,
+        org_id: impl Into<OrgId>,
+        workspace_id: impl Into<WorkspaceId>,
+        settings: &ToggleSettings,
+    ) -> Result<(), SeqeraError> {
+        if settings.enabled {
+            self.require_feature(Feature::Wave)?;
+        }
+        self.put_workspace_settings_section(org_id.into(), workspace_id.into(), "wave", settings)
+            .await
+    }
+
+    pub async fn get_workspace_fusion_settings(
+        &self,
+        org_id: impl Into<OrgId>,
+        workspace_id: impl Into<WorkspaceId>,
+    ) -> Result<ToggleSettings, SeqeraError> {
+        self.get_workspace_settings_section(org_id.into(), workspace_id.into(), "fusion")
+            .await
+    }
+
+    pub async fn update_workspace_fusion_settings(
+        &self,
+        org_id: impl Into<OrgId>,
+        workspace_id: impl Into<WorkspaceId>,
+        settings: &ToggleSettings,
+    ) -> Result<(), SeqeraError> {
+        if settings.enabled {
+            self.require_feature(Feature::Fusion)?;
+        }
+        self.put_workspace_settings_section(org_id.into(), workspace_id.into(), "fusion", settings)
+            .await
+    }
+
+    pub async fn get_workspace_data_link_settings(
+        &self,
+        org_id: impl Into<OrgId>,
+        workspace_id: impl Into<WorkspaceId>,
+    ) -> Result<DataLinkSettings, SeqeraError> {
+        self.require_feature(Feature::DataLinks)?;
+        let result = self
+            .get_workspace_settings_section(org_id.into(), workspace_id.into(), "data-links")
+            .await;
+        self.unsupported_if_missing(Feature::DataLinks, result)
+    }
+
+    pub async fn update_workspace_data_link_settings(
+        &self,
+        org_id: impl Into<OrgId>,
+        workspace_id: impl Into<WorkspaceId>,
+        settings: &DataLinkSettings,
+    ) -> Result<(), SeqeraError> {
+        self.require_feature(Feature::DataLinks)?;
+        let result = self
+            .put_workspace_settings_section(org_id.into(), workspace_id.into(), "data-links", settings)
+            .await;
+        self.unsupported_if_missing(Feature::DataLinks, result)
+    }
+
+    /// Gathers all settings of a workspace. Studios and data link settings
+    /// are `None` on servers without those features.
+    pub async fn get_workspace_settings(
+        &self,
+        org_id: impl Into<OrgId>,
+        workspace_id: impl Into<WorkspaceId>,
+    ) -> Result<WorkspaceSettings, SeqeraError> {
+        let org_id = org_id.into();
+        let workspace_id = workspace_id.into();
+
+        let default_compute_env = self.get_primary_compute_env(workspace_id).await?.map(|ce| ce.name);
+        let wave = self.get_workspace_wave_settings(org_id, workspace_id).await?;
+        let fusion = self.get_workspace_fusion_settings(org_id, workspace_id).await?;
+        let studios = optional_setting(self.get_workspace_studios_settings(org_id, workspace_id).await)?;
+        let data_links = optional_setting(self.get_workspace_data_link_settings(org_id, workspace_id).await)?;
+
+        Ok(WorkspaceSettings {
+            default_compute_env,
+            wave_enabled: wave.enabled,
+            fusion_enabled: fusion.enabled,
+            studios,
+            data_links,
+        })
+    }
+
+    /// Brings a workspace's settings in line with `desired` and returns what
+    /// changed. With `dry_run` nothing is changed.
+    pub async fn apply_workspace_settings(
+        &self,
+        org_id: impl Into<OrgId>,
+        workspace_id: impl Into<WorkspaceId>,
+        desired: &DesiredWorkspaceSettings,
+        dry_run: bool,
+    ) -> Result<Vec<SettingChange>, SeqeraError> {
+        let org_id = org_id.into();
+        let workspace_id = workspace_id.into();
+        let current = self.get_workspace_settings(org_id, workspace_id).await?;
+        let changes = desired.diff(&current);
+        if dry_run || changes.is_empty() {
+            return Ok(changes);
+        }
+
+        if let Some(name) = &desired.default_compute_env {
+            if current.default_compute_env.as_ref() != Some(name) {
+                let compute_envs = self.list_compute_envs(workspace_id, None).await?;
+                let compute_env = compute_envs
+                    .compute_envs
+                    .into_iter()
+                    .find(|ce| &ce.name == name)
+                    .ok_or(ValidationError::Invalid {
+                        field: "defaultComputeEnv",
+                        reason: "no compute environment with that name in the workspace",
+                    })?;
+                self.set_primary_compute_env(&compute_env.id, workspace_id).await?;
+            }
+        }
+        if let Some(enabled) = desired.wave_enabled.filter(|e| *e != current.wave_enabled) {
+            self.update_workspace_wave_settings(org_id, workspace_id, &ToggleSettings { enabled })
+                .await?;
+        }
+        if let Some(enabled) = desired.fusion_enabled.filter(|e| *e != current.fusion_enabled) {
+            self.update_workspace_fusion_settings(org_id, workspace_id, &ToggleSettings { enabled })
+                .await?;
+        }
+        if let Some(studios) = desired.studios.as_ref().filter(|s| current.studios.as_ref() != Some(s)) {
+            self.update_workspace_studios_settings(org_id, workspace_id, studios).await?;
+        }
+        if let Some(data_links) = desired.data_links.as_ref().filter(|d| current.data_links.as_ref() != Some(d)) {
+            self.update_workspace_data_link_settings(org_id, workspace_id, data_links).await?;
+        }
+        Ok(changes)
+    }
+}
+
+/// Turns `UnsupportedByServer` into `None`
+fn optional_setting<T>(result: Result<T, SeqeraError>) -> Result<Option<T>, SeqeraError> {
+    match result {
+        Ok(settings) => Ok(Some(settings)),
+        Err(SeqeraError::UnsupportedByServer { .. }) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::SeqeraError;
+    use crate::models::workspace_settings::{
+        DataLinkSettings, DataLinkVisibility, DesiredWorkspaceSettings, StudiosSettings,
+    };
+    use serde_json::json;
+    use wiremock::matchers::{body_json, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn mount_settings(mock_server: &MockServer) {
+        Mock::given(method("GET"))
+            .and(path("/compute-envs/primary"))
+            .and(query_param("workspaceId", "456"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "computeEnv": null })))
+            .mount(mock_server)
+            .await;
+
+        for (section, body) in [
+            ("wave", json!({ "enabled": true })),
+            ("fusion", json!({ "enabled": false })),
+            ("data-links", json!({ "visibility": "ALL_PARTICIPANTS" })),
+        ] {
+            Mock::given(method("GET"))
+                .and(path(format!("/orgs/123/workspaces/456/settings/{}", section)))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "settings": body })))
+                .mount(mock_server)
+                .await;
+        }
+
+        // An older server without Data Studios
+        Mock::given(method("GET"))
+            .and(path("/orgs/123/workspaces/456/settings/studios"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(mock_server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_get_workspace_settings() {
+        let mock_server = MockServer::start().await;
+        mount_settings(&mock_server).await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let settings = client.get_workspace_settings(123, 456).await.unwrap();
+        assert_eq!(settings.default_compute_env, None);
+        assert!(settings.wave_enabled);
+        assert!(!settings.fusion_enabled);
+        assert!(settings.studios.is_none());
+        assert_eq!(settings.data_links, Some(DataLinkSettings::default()));
+    }
+
+    #[tokio::test]
+    async fn test_apply_workspace_settings() {
+        let mock_server = MockServer::start().await;
+        mount_settings(&mock_server).await;
+
+        Mock::given(method("PUT"))
+            .and(path("/orgs/123/workspaces/456/settings/fusion"))
+            .and(body_json(json!({ "enabled": true })))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("PUT"))
+            .and(path("/orgs/123/workspaces/456/settings/data-links"))
+            .and(body_json(json!({ "visibility": "MAINTAINERS" })))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let desired = DesiredWorkspaceSettings {
+            wave_enabled: Some(true),
+            fusion_enabled: Some(true),
+            data_links: Some(DataLinkSettings { visibility: DataLinkVisibility::Maintainers }),
+            ..Default::default()
+        };
+
+        let planned = client.apply_workspace_settings(123, 456, &desired, true).await.unwrap();
+        assert_eq!(planned.len(), 2);
+
+        let applied = client.apply_workspace_settings(123, 456, &desired, false).await.unwrap();
+        assert_eq!(applied, planned);
+    }
+
+    #[tokio::test]
+    async fn test_update_on_older_server_is_unsupported() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("PUT"))
+            .and(path("/orgs/123/workspaces/456/settings/studios"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let client = super::super::SeqeraClient::with_base_url(
+            "test-token".to_string(),
+            &mock_server.uri(),
+        ).unwrap();
+
+        let result = client
+            .update_workspace_studios_settings(123, 456, &StudiosSettings::default())
+            .await;
+        assert!(matches!(result, Err(SeqeraError::UnsupportedByServer { .. })));
+    }
+}
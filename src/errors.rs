@@ -1,7 +1,8 @@
 use reqwest::StatusCode;
+use std::time::Duration;
 use thiserror::Error;
 use url::ParseError;
-use crate::models::common::ValidationError;
+use crate::models::common::{PermissionDenied, ValidationError};
 
 #[derive(Error, Debug)]
 pub enum SeqeraError {
@@ -30,4 +31,48 @@ pub enum SeqeraError {
 
     #[error("JSON error: {0}")]
     Json(#[from] serde_json::Error),
-}
\ No newline at end of file
+
+    #[error("Compute environment {id} is {status}: {message}")]
+    ComputeEnvFailed {
+        id: String,
+        status: String,
+        message: String,
+    },
+
+    #[error("Studio {id} is {status}: {message}")]
+    StudioFailed {
+        id: String,
+        status: String,
+        message: String,
+    },
+
+    #[error("Quota exceeded: {what} (limit {limit})")]
+    QuotaExceeded {
+        what: String,
+        limit: i64,
+    },
+
+    #[error("Timed out after {elapsed:?} waiting for {what}")]
+    Timeout {
+        what: String,
+        elapsed: Duration,
+    },
+
+    #[error("{0}")]
+    PermissionDenied(#[from] PermissionDenied),
+
+    #[error(
+        "'{who}' matches more than one participant: {}; use an email, participant ID or team:<name>",
+        .matches.join(", ")
+    )]
+    AmbiguousParticipant {
+        who: String,
+        matches: Vec<String>,
+    },
+
+    #[error("{feature} is not supported by this server (API version {api_version})")]
+    UnsupportedByServer {
+        feature: String,
+        api_version: String,
+    },
+}
@@ -1,9 +1,31 @@
-use clap::{Parser, Subcommand};
-use env_logger;
-use log::error;
+use clap::{Args, Parser, Subcommand};
+use log::{error, warn};
 use std::env;
+use std::fs;
+use std::io::{self, BufRead, IsTerminal, Read, Write};
+use std::path::{Component, Path, PathBuf};
 use std::process;
+use std::time::Duration;
 use pform::SeqeraClient;
+use pform::errors::SeqeraError;
+use pform::models::action::{Action, ActionLaunch, ActionSource, CreateActionRequest, UpdateActionRequest};
+use pform::models::common::{OrgId, OrgRole, WspRole};
+use pform::models::compute_env::CreateComputeEnvRequest;
+use pform::models::data_link::{DataLink, DataLinkItemType, DataLinkPath};
+use pform::models::dataset::{CreateDatasetRequest, Dataset, DatasetFile, DatasetFormat, UpdateDatasetRequest};
+use pform::models::member::{InviteRequest, Member, Participant, WorkspaceAssignment};
+use pform::models::organization::Organization;
+use pform::models::export::ExportFormat;
+use pform::models::migration::{MigrationMapping, MigrationOptions};
+use pform::models::org_spec::{ChangeKind, OrgPlan, OrgSpec, SpecFormat};
+use pform::models::platform::{PlatformId, PlatformMetaInfo};
+use pform::models::pipeline_secret::{parse_env_file, SecretScope};
+use pform::models::service_info::Feature;
+use pform::models::studio::{CreateStudioRequest, StudioStatus};
+use pform::models::team::{CreateTeamMemberRequest, CreateTeamRequestBuilder, Team};
+use pform::models::token::rotated_token_name;
+use pform::models::workspace::{AddWorkspaceParticipantRequest, Workspace};
+use pform::models::workspace_settings::{DataLinkSettings, DataLinkVisibility, DesiredWorkspaceSettings, StudiosSettings};
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
@@ -12,6 +34,11 @@ struct Cli {
     #[arg(short, long)]
     verbose: bool,
 
+    /// Read the API token from this file instead of TOWER_ACCESS_TOKEN.
+    /// Also read from PFORM_TOKEN_FILE
+    #[arg(long, global = true)]
+    token_file: Option<PathBuf>,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -24,9 +51,109 @@ enum Commands {
     /// Workspace commands
     #[command(subcommand)]
     Workspaces(WorkspaceCommands),
+    /// Team commands
+    #[command(subcommand)]
+    Teams(TeamCommands),
+    /// Workspace participant commands
+    #[command(subcommand)]
+    Participants(ParticipantCommands),
     /// Compute environment commands
     #[command(subcommand)]
     ComputeEnv(ComputeEnvCommands),
+    /// Pipeline secret commands
+    #[command(subcommand)]
+    Secrets(SecretCommands),
+    /// Compute platform commands
+    #[command(subcommand)]
+    Platforms(PlatformCommands),
+    /// Data Explorer commands for browsing and transferring files in data links
+    #[command(subcommand)]
+    Data(DataCommands),
+    /// Dataset commands
+    #[command(subcommand)]
+    Datasets(DatasetCommands),
+    /// Data Studio commands
+    #[command(subcommand)]
+    Studios(StudioCommands),
+    /// Pipeline action commands
+    #[command(subcommand)]
+    Actions(ActionCommands),
+    /// Personal access token commands
+    #[command(subcommand)]
+    Tokens(TokenCommands),
+    /// Show the user the API token belongs to
+    Whoami,
+    /// Show the server version, API version and supported features
+    Info,
+    /// Organization audit reports
+    #[command(subcommand)]
+    Audit(AuditCommands),
+    /// Show the changes needed to bring an organization in line with a spec
+    Plan {
+        /// Organization spec (.yaml, .yml, .toml or .json)
+        #[arg(long)]
+        file: PathBuf,
+        /// Also delete teams, workspaces, members, participants, labels and
+        /// compute environments the spec doesn't list
+        #[arg(long)]
+        prune: bool,
+    },
+    /// Bring an organization in line with a spec
+    Apply {
+        /// Organization spec (.yaml, .yml, .toml or .json)
+        #[arg(long)]
+        file: PathBuf,
+        /// Also delete teams, workspaces, members, participants, labels and
+        /// compute environments the spec doesn't list
+        #[arg(long)]
+        prune: bool,
+        /// Apply deletions without asking for confirmation
+        #[arg(long)]
+        auto_approve: bool,
+    },
+    /// Write a workspace's configuration to files for backup and review
+    Export {
+        /// Workspace as `org/workspace`
+        #[arg(long)]
+        workspace: String,
+        /// Directory to write to; created if missing. It must be empty or
+        /// hold an earlier export, whose files are replaced
+        #[arg(long)]
+        out: PathBuf,
+        /// File format: json or yaml
+        #[arg(long, default_value = "json")]
+        format: ExportFormat,
+    },
+    /// Copy labels, compute environments, pipelines, secrets and participants
+    /// to another workspace, possibly on another server. Resources that
+    /// already exist in the target by name are skipped
+    Migrate {
+        /// Source workspace as `org/workspace`
+        #[arg(long)]
+        source: String,
+        /// Target workspace as `org/workspace`
+        #[arg(long)]
+        target: String,
+        /// API URL of the source server, if not Seqera Cloud
+        #[arg(long)]
+        source_url: Option<String>,
+        /// API URL of the target server, if not the source server
+        #[arg(long)]
+        target_url: Option<String>,
+        /// Token file for the target server; defaults to the source token
+        #[arg(long)]
+        target_token_file: Option<PathBuf>,
+        /// YAML, TOML or JSON file mapping source credentials and compute
+        /// environments, by ID or name, to target ones
+        #[arg(long)]
+        mapping: Option<PathBuf>,
+        /// Env file with the values of the source workspace's secrets
+        #[arg(long)]
+        secrets_file: Option<PathBuf>,
+        /// Report what would be copied without copying anything
+        #[arg(long)]
+        dry_run: bool,
+    },
 }
 
 #[derive(Debug, Subcommand)]
@@ -44,6 +171,57 @@ enum OrgCommands {
         /// Organization name to validate
         name: String,
     },
+    /// List organization members
+    Members {
+        /// Organization name
+        #[arg(long)]
+        org: String,
+        /// Only show members whose name or email matches
+        #[arg(long)]
+        search: Option<String>,
+        /// Number of members to show; all members are listed when omitted
+        #[arg(long)]
+        max: Option<i32>,
+        /// Number of members to skip
+        #[arg(long, requires = "max")]
+        offset: Option<i32>,
+    },
+    /// Change a member's organization role
+    SetRole {
+        /// User name or email
+        user: String,
+        /// New role: owner, member or collaborator
+        role: OrgRole,
+        /// Organization name
+        #[arg(long)]
+        org: String,
+    },
+    /// Remove a member from the organization and all its teams and workspaces
+    RemoveMember {
+        /// User name or email
+        user: String,
+        /// Organization name
+        #[arg(long)]
+        org: String,
+    },
+    /// Add a user to the organization, its teams and workspaces in one go
+    Invite {
+        /// User name or email
+        user: String,
+        /// Organization name
+        #[arg(long)]
+        org: String,
+        /// Organization role
+        #[arg(long)]
+        role: Option<OrgRole>,
+        /// Team to add the user to (repeatable)
+        #[arg(long = "team")]
+        teams: Vec<String>,
+        /// Workspace to add the user to as `name:role`; the role defaults to
+        /// launch (repeatable)
+        #[arg(long = "workspace")]
+        workspaces: Vec<WorkspaceAssignment>,
+    },
 }
 
 #[derive(Debug, Subcommand)]
@@ -56,6 +234,9 @@ enum WorkspaceCommands {
         /// Organization name
         #[arg(long, conflicts_with = "org_id")]
         org_name: Option<String>,
+        /// List every workspace you can access, across all organizations
+        #[arg(long, conflicts_with_all = ["org_id", "org_name"])]
+        all: bool,
     },
     /// View workspace details
     View {
@@ -66,6 +247,176 @@ enum WorkspaceCommands {
         #[arg(long)]
         id: i64,
     },
+    /// Show a workspace's settings
+    Settings {
+        /// Workspace as `org/workspace`
+        #[arg(long)]
+        workspace: String,
+    },
+    /// Enforce settings on one or more workspaces, showing what changes
+    Configure {
+        /// Workspace as `org/workspace` (repeatable)
+        #[arg(long = "workspace", required_unless_present = "org", conflicts_with = "org")]
+        workspaces: Vec<String>,
+        /// Configure every workspace in this organization
+        #[arg(long)]
+        org: Option<String>,
+        /// JSON file with the settings to enforce; the flags below override it
+        #[arg(long)]
+        file: Option<PathBuf>,
+        /// Name of the compute environment to make primary
+        #[arg(long)]
+        default_compute_env: Option<String>,
+        #[arg(long)]
+        wave: Option<bool>,
+        #[arg(long)]
+        fusion: Option<bool>,
+        /// Hours a studio may run before it is stopped
+        #[arg(long)]
+        studio_lifespan_hours: Option<i32>,
+        /// Who can see data links: all or maintainers
+        #[arg(long)]
+        data_link_visibility: Option<DataLinkVisibility>,
+        /// Show the changes without making them
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum TeamCommands {
+    /// List the teams in an organization
+    List {
+        /// Organization name
+        #[arg(long)]
+        org: String,
+    },
+
+    /// List the members of a team
+    Members {
+        /// Team name
+        team: String,
+        /// Organization name
+        #[arg(long)]
+        org: String,
+        /// Only show members whose name or email matches
+        #[arg(long)]
+        search: Option<String>,
+        /// Number of members to show; all members are listed when omitted
+        #[arg(long)]
+        max: Option<i32>,
+        /// Number of members to skip
+        #[arg(long, requires = "max")]
+        offset: Option<i32>,
+    },
+
+    /// Create a team
+    Create {
+        /// Team name
+        name: String,
+        /// Organization name
+        #[arg(long)]
+        org: String,
+        #[arg(long)]
+        description: Option<String>,
+    },
+
+    /// Delete a team
+    Delete {
+        /// Team name
+        team: String,
+        /// Organization name
+        #[arg(long)]
+        org: String,
+    },
+
+    /// Add an organization member to a team
+    AddMember {
+        /// Team name
+        team: String,
+        /// User name or email
+        user: String,
+        /// Organization name
+        #[arg(long)]
+        org: String,
+    },
+
+    /// Remove a member from a team
+    RemoveMember {
+        /// Team name
+        team: String,
+        /// User name or email
+        user: String,
+        /// Organization name
+        #[arg(long)]
+        org: String,
+    },
+
+    /// List the workspaces a team participates in, with its role
+    Workspaces {
+        /// Team name
+        team: String,
+        /// Organization name
+        #[arg(long)]
+        org: String,
+    },
+
+    /// Remove a team from a workspace
+    RemoveFromWorkspace {
+        /// Team name
+        team: String,
+        /// Organization name
+        #[arg(long)]
+        org: String,
+        /// Workspace name
+        #[arg(long)]
+        workspace: String,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum ParticipantCommands {
+    /// List the participants of a workspace and their roles
+    List {
+        /// Workspace as `org/workspace`
+        #[arg(long)]
+        workspace: String,
+    },
+
+    /// Add a user or team to a workspace
+    Add {
+        /// User name or email, or team name with --team
+        name: String,
+        /// Workspace as `org/workspace`
+        #[arg(long)]
+        workspace: String,
+        /// Workspace role
+        #[arg(long, default_value = "launch")]
+        role: WspRole,
+        /// Add a team rather than a user
+        #[arg(long)]
+        team: bool,
+    },
+
+    /// Change a participant's workspace role
+    UpdateRole {
+        /// User name, email, team name (team:<name> if a user has the same name) or participant ID
+        participant: String,
+        /// New role: owner, admin, maintain, launch, connect or view
+        role: WspRole,
+        /// Workspace as `org/workspace`
+        #[arg(long)]
+        workspace: String,
+    },
+
+    /// Remove a participant from a workspace
+    Remove {
+        /// User name, email, team name (team:<name> if a user has the same name) or participant ID
+        participant: String,
+        /// Workspace as `org/workspace`
+        #[arg(long)]
+        workspace: String,
+    },
 }
 
 #[derive(Debug, Subcommand)]
@@ -100,204 +451,2872 @@ pub enum ComputeEnvCommands {
         /// Name to validate
         name: String,
     },
+
+    /// Create a compute environment from a JSON definition file
+    Create {
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+
+        /// JSON file with the compute environment definition
+        #[arg(long)]
+        file: PathBuf,
+    },
+
+    /// Delete a compute environment
+    Delete {
+        /// Compute environment ID
+        compute_env_id: String,
+
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+    },
+
+    /// Wait until a compute environment is AVAILABLE
+    Wait {
+        /// Compute environment ID
+        compute_env_id: String,
+
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+
+        /// Give up after this many seconds
+        #[arg(long)]
+        timeout: Option<u64>,
+
+        /// Seconds between status checks
+        #[arg(long, default_value_t = 10)]
+        interval: u64,
+    },
+
+    /// Show the workspace's primary compute environment, or change it with --set
+    Primary {
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+
+        /// Compute environment ID to make primary
+        #[arg(long)]
+        set: Option<String>,
+    },
 }
 
-#[tokio::main]
-async fn main() {
-    env_logger::init();
+#[derive(Debug, Subcommand)]
+enum PlatformCommands {
+    /// List available compute platforms
+    List {
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: Option<i64>,
+    },
 
-    let cli = Cli::parse();
+    /// List the regions of a compute platform
+    Regions {
+        /// Platform ID (e.g. aws-batch)
+        platform_id: PlatformId,
 
-    let token = match env::var("TOWER_ACCESS_TOKEN") {
-        Ok(token) => token,
-        Err(_) => {
-            error!("TOWER_ACCESS_TOKEN environment variable not set");
-            process::exit(1);
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+    },
+
+    /// Show queues, networks, buckets and other resources available on a platform
+    Describe {
+        /// Platform ID (e.g. aws-batch)
+        platform_id: PlatformId,
+
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+
+        /// Region ID
+        #[arg(long)]
+        region: Option<String>,
+
+        /// Credentials ID used to discover resources
+        #[arg(long)]
+        credentials_id: Option<String>,
+    },
+}
+
+fn print_platform_metainfo(metainfo: &PlatformMetaInfo) {
+    for warning in metainfo.warnings() {
+        println!("Warning: {}", warning);
+    }
+    match metainfo {
+        PlatformMetaInfo::Aws(info) => {
+            println!("Job queues:");
+            for queue in &info.job_queues {
+                println!("  {:<40} {}", queue.name, queue.state);
+            }
+            println!("VPCs:");
+            for vpc in &info.vpcs {
+                println!("  {}{}", vpc.id, if vpc.is_default { " (default)" } else { "" });
+            }
+            println!("Subnets:");
+            for subnet in &info.subnets {
+                println!("  {:<28} {:<16} {}", subnet.id, subnet.zone, subnet.vpc_id);
+            }
+            println!("Security groups:");
+            for group in &info.security_groups {
+                println!("  {:<28} {:<24} {}", group.id, group.name, group.vpc_id);
+            }
+            println!("Buckets:");
+            for bucket in &info.buckets {
+                println!("  {}", bucket.path);
+            }
+            println!("EFS file systems:");
+            for fs in &info.efs_file_systems {
+                println!("  {}", fs.id);
+            }
+            println!("Instance families: {}", info.instance_families.join(", "));
         }
-    };
+        PlatformMetaInfo::Google(info) => {
+            println!("Locations: {}", info.locations.join(", "));
+            println!("Zones:     {}", info.zones.join(", "));
+            println!("Buckets:");
+            for bucket in &info.buckets {
+                println!("  {}", bucket.path);
+            }
+            println!("Filestores:");
+            for filestore in &info.filestores {
+                println!("  {:<24} {:<24} {}", filestore.name, filestore.target, filestore.location);
+            }
+        }
+        PlatformMetaInfo::Azure(info) => {
+            println!("Pools:      {}", info.pools.join(", "));
+            println!("Containers: {}", info.containers.join(", "));
+            println!("VM types:   {}", info.vm_types.join(", "));
+        }
+        PlatformMetaInfo::Kubernetes(info) => {
+            println!("Clusters:");
+            for cluster in &info.clusters {
+                println!("  {}", cluster.name.as_deref().unwrap_or(&cluster.id));
+            }
+        }
+        PlatformMetaInfo::Grid(_) => println!("No discoverable resources for this platform"),
+        PlatformMetaInfo::Raw(value) => {
+            println!("{}", serde_json::to_string_pretty(value).unwrap_or_default());
+        }
+    }
+}
 
-    let mut client = match SeqeraClient::new(token) {
-        Ok(client) => client,
-        Err(e) => {
-            error!("Failed to create client: {}", e);
-            process::exit(1);
+#[derive(Debug, Args)]
+struct SecretScopeArgs {
+    /// Workspace ID
+    #[arg(long, conflicts_with = "user", required_unless_present = "user")]
+    workspace_id: Option<i64>,
+
+    /// Use secrets from your user context instead of a workspace
+    #[arg(long)]
+    user: bool,
+}
+
+impl SecretScopeArgs {
+    fn scope(&self) -> SecretScope {
+        match self.workspace_id {
+            Some(id) => SecretScope::Workspace(id.into()),
+            None => SecretScope::User,
         }
-    };
+    }
+}
 
-    client.set_verbose(cli.verbose);
+#[derive(Debug, Subcommand)]
+enum SecretCommands {
+    /// List pipeline secrets
+    List {
+        #[command(flatten)]
+        scope: SecretScopeArgs,
+    },
 
-    match cli.command {
-        Commands::Orgs(cmd) => match cmd {
-            OrgCommands::List => match client.list_organizations().await {
-                Ok(response) => {
-                    println!("Found {} organizations:", response.organizations.len());
-                    for org in response.organizations {
-                        println!("{:<8} {}", org.id.0, org.name);
-                        if let Some(desc) = org.description {
-                            println!("  {}", desc);
-                        }
-                        println!();
-                    }
-                }
-                Err(e) => {
-                    error!("Failed to list organizations: {}", e);
-                    process::exit(1);
-                }
-            },
-            OrgCommands::Get { id } => match client.get_organization(id).await {
-                Ok(org) => {
-                    println!("ID:          {}", org.id.0);
-                    println!("Name:        {}", org.name);
-                    if let Some(desc) = org.description {
-                        println!("Description: {}", desc);
-                    }
-                }
-                Err(e) => {
-                    error!("Failed to get organization: {}", e);
-                    process::exit(1);
-                }
-            },
-            OrgCommands::ValidateName { name } => match client.validate_organization_name(&name).await {
-                Ok(_) => println!("Organization name '{}' is valid", name),
-                Err(e) => {
-                    error!("Organization name validation failed: {}", e);
-                    process::exit(1);
-                }
-            },
-        },
-        Commands::Workspaces(cmd) => match cmd {
-            WorkspaceCommands::List { org_id, org_name } => {
-                let org_id = match (org_id, org_name) {
-                    (Some(id), _) => id,
-                    (None, Some(name)) => {
-                        match client.find_organization_by_name(&name).await {
-                            Ok(Some(org)) => org.id.0,
-                            Ok(None) => {
-                                error!("Organization '{}' not found", name);
-                                process::exit(1);
-                            }
-                            Err(e) => {
-                                error!("Failed to find organization: {}", e);
-                                process::exit(1);
+    /// Add a pipeline secret. The value is read from --value-file or stdin
+    Add {
+        #[command(flatten)]
+        scope: SecretScopeArgs,
+
+        /// Secret name
+        name: String,
+
+        /// Read the secret value from this file instead of stdin
+        #[arg(long)]
+        value_file: Option<PathBuf>,
+    },
+
+    /// Update a pipeline secret value. The value is read from --value-file or stdin
+    Update {
+        #[command(flatten)]
+        scope: SecretScopeArgs,
+
+        /// Secret name
+        name: String,
+
+        /// Read the secret value from this file instead of stdin
+        #[arg(long)]
+        value_file: Option<PathBuf>,
+    },
+
+    /// Delete a pipeline secret
+    Delete {
+        #[command(flatten)]
+        scope: SecretScopeArgs,
+
+        /// Secret name
+        name: String,
+    },
+
+    /// Sync pipeline secrets from a .env-style file
+    Sync {
+        #[command(flatten)]
+        scope: SecretScopeArgs,
+
+        /// File with NAME=VALUE lines
+        #[arg(long)]
+        file: PathBuf,
+
+        /// Delete secrets that are not present in the file
+        #[arg(long)]
+        prune: bool,
+
+        /// Only report what would change
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum DataCommands {
+    /// List data links, or the contents of a dl://<data-link>/<path> location
+    Ls {
+        /// Location to list. Lists the workspace data links when omitted
+        path: Option<DataLinkPath>,
+
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+
+        /// Re-discover data links from the workspace credentials first
+        #[arg(long)]
+        refresh: bool,
+    },
+
+    /// Copy a file to or from a data link.
+    ///
+    /// Exactly one side must be a dl://<data-link>/<path> location. When
+    /// uploading, the data link location is the destination folder.
+    Cp {
+        /// Source file or dl:// location
+        source: String,
+
+        /// Destination file, folder or dl:// location
+        destination: String,
+
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum DatasetCommands {
+    /// List datasets
+    List {
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+    },
+
+    /// Create a dataset, optionally uploading its first version
+    Create {
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+
+        /// Dataset name
+        name: String,
+
+        /// Dataset description
+        #[arg(long)]
+        description: Option<String>,
+
+        /// CSV or TSV file to upload as the first version
+        #[arg(long)]
+        file: Option<PathBuf>,
+    },
+
+    /// Rename a dataset or change its description
+    Update {
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+
+        /// Dataset name
+        name: String,
+
+        /// New dataset name
+        #[arg(long)]
+        new_name: Option<String>,
+
+        /// New dataset description
+        #[arg(long)]
+        description: Option<String>,
+    },
+
+    /// Delete a dataset
+    Delete {
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+
+        /// Dataset name
+        name: String,
+    },
+
+    /// Upload a CSV or TSV file as a new dataset version
+    Upload {
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+
+        /// Dataset name
+        name: String,
+
+        /// CSV or TSV file
+        file: PathBuf,
+
+        /// Treat the first row as a header (detected when omitted)
+        #[arg(long, conflicts_with = "no_header")]
+        header: bool,
+
+        /// Treat the first row as data (detected when omitted)
+        #[arg(long)]
+        no_header: bool,
+    },
+
+    /// List dataset versions and the URLs pipelines read them from
+    Versions {
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+
+        /// Dataset name
+        name: String,
+    },
+
+    /// Download a dataset version (the latest when --version is omitted)
+    Download {
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+
+        /// Dataset name
+        name: String,
+
+        /// Version number
+        #[arg(long)]
+        version: Option<i64>,
+
+        /// Output file. Defaults to the uploaded file name
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum StudioCommands {
+    /// List studios
+    List {
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+    },
+
+    /// List the container templates studios can be created from
+    Templates {
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+    },
+
+    /// Create a studio from a template
+    Create {
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+
+        /// Studio name
+        name: String,
+
+        /// Template container image
+        #[arg(long)]
+        template: String,
+
+        /// Compute environment ID
+        #[arg(long)]
+        compute_env_id: String,
+
+        /// Data link to mount, by name. May be repeated
+        #[arg(long = "mount")]
+        mounts: Vec<String>,
+
+        /// Studio description
+        #[arg(long)]
+        description: Option<String>,
+
+        /// CPUs
+        #[arg(long)]
+        cpu: Option<i32>,
+
+        /// Memory in MB
+        #[arg(long)]
+        memory: Option<i32>,
+
+        /// GPUs
+        #[arg(long)]
+        gpu: Option<i32>,
+
+        /// Start the studio once created, within the organization's running
+        /// studios quota
+        #[arg(long)]
+        start: bool,
+
+        /// Organization of the workspace, for the quota; looked up when omitted
+        #[arg(long, requires = "start")]
+        org_id: Option<i64>,
+    },
+
+    /// Start a studio
+    Start {
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+
+        /// Studio session ID
+        session_id: String,
+
+        /// Organization of the workspace, whose running studios quota is
+        /// respected; looked up when omitted
+        #[arg(long)]
+        org_id: Option<i64>,
+
+        /// Wait until the studio is running
+        #[arg(long)]
+        wait: bool,
+    },
+
+    /// Stop a studio
+    Stop {
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+
+        /// Studio session ID
+        session_id: String,
+    },
+
+    /// Delete a studio
+    Delete {
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+
+        /// Studio session ID
+        session_id: String,
+    },
+
+    /// Show a studio's status and connection URL
+    Status {
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+
+        /// Studio session ID
+        session_id: String,
+    },
+
+    /// Wait for a studio to be running
+    Wait {
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+
+        /// Studio session ID
+        session_id: String,
+
+        /// Give up after this many seconds
+        #[arg(long)]
+        timeout: Option<u64>,
+
+        /// Seconds between status checks
+        #[arg(long, default_value_t = 10)]
+        interval: u64,
+    },
+
+    /// Stop running studios with no activity in the last --idle-hours
+    Cleanup {
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+
+        /// Hours without activity before a studio is stopped
+        #[arg(long, default_value_t = 12)]
+        idle_hours: i64,
+
+        /// Only report which studios would be stopped
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum ActionCommands {
+    /// List actions
+    List {
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+    },
+
+    /// Show an action, including its webhook URL
+    Get {
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+
+        /// Action name
+        name: String,
+    },
+
+    /// Create an action bound to a launch configuration
+    Create {
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+
+        /// Action name
+        name: String,
+
+        /// Trigger: github (repository pushes) or tower (webhook calls)
+        #[arg(long)]
+        source: ActionSource,
+
+        /// Pipeline repository URL
+        #[arg(long)]
+        pipeline: String,
+
+        /// Compute environment ID. Defaults to the workspace primary
+        #[arg(long)]
+        compute_env_id: Option<String>,
+
+        /// Work directory
+        #[arg(long)]
+        work_dir: Option<String>,
+
+        /// Pipeline revision
+        #[arg(long)]
+        revision: Option<String>,
+
+        /// JSON or YAML file with pipeline parameters
+        #[arg(long)]
+        params_file: Option<PathBuf>,
+
+        /// Nextflow config profile. May be repeated
+        #[arg(long = "profile")]
+        profiles: Vec<String>,
+    },
+
+    /// Change an action's name or launch configuration
+    Update {
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+
+        /// Action name
+        name: String,
+
+        /// New action name
+        #[arg(long)]
+        new_name: Option<String>,
+
+        /// Compute environment ID
+        #[arg(long)]
+        compute_env_id: Option<String>,
+
+        /// Work directory
+        #[arg(long)]
+        work_dir: Option<String>,
+
+        /// Pipeline revision
+        #[arg(long)]
+        revision: Option<String>,
+
+        /// JSON or YAML file with pipeline parameters
+        #[arg(long)]
+        params_file: Option<PathBuf>,
+    },
+
+    /// Pause an action
+    Pause {
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+
+        /// Action name
+        name: String,
+    },
+
+    /// Resume a paused action
+    Resume {
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+
+        /// Action name
+        name: String,
+    },
+
+    /// Delete an action
+    Delete {
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+
+        /// Action name
+        name: String,
+    },
+
+    /// Trigger a webhook action
+    Trigger {
+        /// Workspace ID
+        #[arg(long)]
+        workspace_id: i64,
+
+        /// Action name
+        name: String,
+
+        /// JSON file with parameters to override
+        #[arg(long)]
+        params_file: Option<PathBuf>,
+
+        /// Parameter to override as KEY=VALUE. May be repeated
+        #[arg(long = "param")]
+        params: Vec<String>,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum TokenCommands {
+    /// List personal access tokens
+    List,
+
+    /// Create a personal access token and print its value, which is shown only once
+    Create {
+        /// Token name
+        name: String,
+    },
+
+    /// Delete a personal access token
+    Delete {
+        /// Token name
+        name: String,
+    },
+
+    /// Replace a token with a new one: create it, check that it works, save
+    /// it to the token source, then delete the old token
+    Rotate {
+        /// Name of the token currently in use
+        name: String,
+
+        /// Name for the new token. Defaults to the old name with a timestamp suffix
+        #[arg(long)]
+        new_name: Option<String>,
+
+        /// File to write the new token to. Defaults to --token-file; required
+        /// when the token comes from TOWER_ACCESS_TOKEN
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum AuditCommands {
+    /// Show every user's effective role in every workspace of an organization,
+    /// taking the highest of their direct and team roles
+    Access {
+        /// Organization name
+        #[arg(long)]
+        org: String,
+        /// Output format: table, csv or json
+        #[arg(long, default_value = "table")]
+        format: OutputFormat,
+    },
+}
+
+/// Output format of report commands
+#[derive(Debug, Clone, Copy)]
+enum OutputFormat {
+    Table,
+    Csv,
+    Json,
+}
+
+impl std::str::FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(OutputFormat::Table),
+            "csv" => Ok(OutputFormat::Csv),
+            "json" => Ok(OutputFormat::Json),
+            other => Err(format!("unknown format '{}', expected table, csv or json", other)),
+        }
+    }
+}
+
+/// Where the API token was read from
+#[derive(Debug)]
+enum TokenSource {
+    Env,
+    File(PathBuf),
+}
+
+/// Reads the API token from --token-file, PFORM_TOKEN_FILE or TOWER_ACCESS_TOKEN
+fn read_token(token_file: Option<PathBuf>) -> (String, TokenSource) {
+    let token_file = token_file.or_else(|| env::var_os("PFORM_TOKEN_FILE").map(PathBuf::from));
+    match token_file {
+        Some(path) => {
+            let token = read_text_file(&path).trim().to_string();
+            if token.is_empty() {
+                error!("Token file {} is empty", path.display());
+                process::exit(1);
+            }
+            (token, TokenSource::File(path))
+        }
+        None => match env::var("TOWER_ACCESS_TOKEN") {
+            Ok(token) => (token, TokenSource::Env),
+            Err(_) => {
+                error!("TOWER_ACCESS_TOKEN environment variable not set");
+                process::exit(1);
+            }
+        },
+    }
+}
+
+/// Deletes a token created by a rotation that can't complete, and says
+/// what is left for the error message
+async fn discard_new_token(client: &SeqeraClient, token_id: i64) -> String {
+    match client.delete_access_token(token_id).await {
+        Ok(_) => "The new token was deleted and the old token kept".to_string(),
+        Err(e) => format!("Deleting the new token failed too ({}); delete it by hand. The old token was kept", e),
+    }
+}
+
+/// Replaces the contents of a token file, readable only by the current user
+fn write_token_file(path: &Path, token: &str) -> io::Result<()> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    let tmp = PathBuf::from(tmp);
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let mut file = options.open(&tmp)?;
+    io::Write::write_all(&mut file, token.as_bytes())?;
+    io::Write::write_all(&mut file, b"\n")?;
+    file.sync_all()?;
+    fs::rename(&tmp, path)
+}
+
+/// Looks up an organization by name, exiting if it cannot be found
+async fn resolve_org(client: &SeqeraClient, name: &str) -> Organization {
+    match client.find_organization_by_name(name).await {
+        Ok(Some(org)) => org,
+        Ok(None) => {
+            error!("Organization '{}' not found", name);
+            process::exit(1);
+        }
+        Err(e) => {
+            error!("Failed to find organization: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Looks up an organization member by user name or email, exiting if they cannot be found
+async fn resolve_member(client: &SeqeraClient, org_id: i64, user: &str) -> Member {
+    match client.find_organization_member(org_id, user).await {
+        Ok(Some(member)) => member,
+        Ok(None) => {
+            error!("'{}' is not a member of organization {}", user, org_id);
+            process::exit(1);
+        }
+        Err(e) => {
+            error!("Failed to find member: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Looks up a workspace by name, exiting if it cannot be found
+async fn resolve_workspace(client: &SeqeraClient, org_id: i64, name: &str) -> Workspace {
+    match client.list_workspaces(org_id).await {
+        Ok(response) => match response.workspaces.into_iter().find(|w| w.name == name) {
+            Some(workspace) => workspace,
+            None => {
+                error!("Workspace '{}' not found in organization {}", name, org_id);
+                process::exit(1);
+            }
+        },
+        Err(e) => {
+            error!("Failed to list workspaces: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Resolves an `org/workspace` path, exiting if either cannot be found
+async fn resolve_workspace_path(client: &SeqeraClient, path: &str) -> (Organization, Workspace) {
+    let Some((org_name, workspace_name)) = path.split_once('/') else {
+        error!("Expected a workspace as org/workspace, got '{}'", path);
+        process::exit(1);
+    };
+    let org = resolve_org(client, org_name).await;
+    let workspace = resolve_workspace(client, org.id.0, workspace_name).await;
+    (org, workspace)
+}
+
+/// Looks up a workspace participant by user name, email, team name or
+/// participant ID, exiting if it cannot be found or is ambiguous
+async fn resolve_participant(
+    client: &SeqeraClient,
+    org_id: i64,
+    workspace_id: i64,
+    who: &str,
+) -> Participant {
+    match client.find_workspace_participant(org_id, workspace_id, who).await {
+        Ok(Some(participant)) => participant,
+        Ok(None) => {
+            error!("'{}' is not a participant of workspace {}", who, workspace_id);
+            process::exit(1);
+        }
+        Err(e) => {
+            error!("Failed to find participant: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Looks up a team by name, exiting if it cannot be found
+async fn resolve_team(client: &SeqeraClient, org_id: i64, name: &str) -> Team {
+    match client.find_team_by_name(org_id, name).await {
+        Ok(Some(team)) => team,
+        Ok(None) => {
+            error!("Team '{}' not found in organization {}", name, org_id);
+            process::exit(1);
+        }
+        Err(e) => {
+            error!("Failed to find team: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Looks up an action by name, exiting if it cannot be found
+async fn resolve_action(client: &SeqeraClient, workspace_id: i64, name: &str) -> Action {
+    match client.find_action_by_name(workspace_id, name).await {
+        Ok(Some(action)) => action,
+        Ok(None) => {
+            error!("Action '{}' not found in workspace {}", name, workspace_id);
+            process::exit(1);
+        }
+        Err(e) => {
+            error!("Failed to find action: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Reads a file into a string, exiting on failure
+fn read_text_file(path: &Path) -> String {
+    match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) => {
+            error!("Failed to read {}: {}", path.display(), e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Reads and checks an organization spec, exiting on failure
+fn read_org_spec(path: &Path) -> OrgSpec {
+    let Some(format) = SpecFormat::from_path(path) else {
+        error!("Unknown spec format for {}, expected .yaml, .yml, .toml or .json", path.display());
+        process::exit(1);
+    };
+    match OrgSpec::parse(&read_text_file(path), format) {
+        Ok(spec) => spec,
+        Err(e) => {
+            error!("Invalid spec {}: {}", path.display(), e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Builds options for `migrate` from the mapping and secrets files, exiting on failure
+fn read_migration_options(mapping: Option<&Path>, secrets_file: Option<&Path>, dry_run: bool) -> MigrationOptions {
+    let mapping = match mapping {
+        Some(path) => {
+            let Some(format) = SpecFormat::from_path(path) else {
+                error!("Unknown mapping format for {}, expected .yaml, .yml, .toml or .json", path.display());
+                process::exit(1);
+            };
+            match MigrationMapping::parse(&read_text_file(path), format) {
+                Ok(mapping) => mapping,
+                Err(e) => {
+                    error!("Invalid mapping {}: {}", path.display(), e);
+                    process::exit(1);
+                }
+            }
+        }
+        None => MigrationMapping::default(),
+    };
+    let secrets = match secrets_file {
+        Some(path) => match parse_env_file(&read_text_file(path)) {
+            Ok(secrets) => secrets,
+            Err(e) => {
+                error!("Failed to parse {}: {}", path.display(), e);
+                process::exit(1);
+            }
+        },
+        None => Vec::new(),
+    };
+    MigrationOptions { mapping, secrets, dry_run }
+}
+
+/// A client for the server at `url`, exiting if the URL is invalid
+fn client_for_url(url: &str, token: String, verbose: bool) -> SeqeraClient {
+    // Without the trailing slash, joining endpoint paths would drop the last segment
+    let url = if url.ends_with('/') { url.to_string() } else { format!("{}/", url) };
+    match SeqeraClient::with_base_url(token, &url) {
+        Ok(mut client) => {
+            client.set_verbose(verbose);
+            client
+        }
+        Err(e) => {
+            error!("Invalid API URL {}: {}", url, e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Compares a spec against the live organization, exiting on failure
+async fn plan_org_spec<'a>(client: &SeqeraClient, spec: &'a OrgSpec, prune: bool) -> (Organization, OrgPlan<'a>) {
+    let org = resolve_org(client, &spec.organization).await;
+    let state = match client.get_org_state(org.id).await {
+        Ok(state) => state,
+        Err(e) => {
+            error!("Failed to read organization {}: {}", org.name, e);
+            process::exit(1);
+        }
+    };
+    match spec.plan(&state, prune) {
+        Ok(plan) => (org, plan),
+        Err(e) => {
+            error!("Failed to plan: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Asks on the terminal whether to go ahead with `count` deletions. Without
+/// a terminal to ask on, nothing is deleted.
+fn confirm_deletions(count: usize) -> bool {
+    if !io::stdin().is_terminal() {
+        return false;
+    }
+    print!("Apply {} deletions? Only 'yes' will be accepted: ", count);
+    if io::stdout().flush().is_err() {
+        return false;
+    }
+    let mut answer = String::new();
+    match io::stdin().lock().read_line(&mut answer) {
+        Ok(_) => answer.trim() == "yes",
+        Err(_) => false,
+    }
+}
+
+/// Lists the files an export wrote, so that the next export into the same
+/// directory removes only those
+const EXPORT_MANIFEST: &str = ".pform-export.json";
+
+/// Writes exported files under `out`, first removing the files of the
+/// previous export listed in its manifest so that deleted resources don't
+/// linger. A non-empty directory without a manifest is left untouched.
+fn write_export(out: &Path, files: &[(String, String)]) -> io::Result<()> {
+    let manifest = out.join(EXPORT_MANIFEST);
+    let previous: Vec<String> = match fs::read_to_string(&manifest) {
+        Ok(text) => serde_json::from_str(&text).map_err(|e| io::Error::other(format!("{}: {}", manifest.display(), e)))?,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            if fs::read_dir(out).is_ok_and(|mut entries| entries.next().is_some()) {
+                return Err(io::Error::other("the directory is not empty and holds no earlier export"));
+            }
+            Vec::new()
+        }
+        Err(e) => return Err(e),
+    };
+    for relative in &previous {
+        if !Path::new(relative).components().all(|c| matches!(c, Component::Normal(_))) {
+            return Err(io::Error::other(format!("{} lists {}, outside the export", manifest.display(), relative)));
+        }
+        match fs::remove_file(out.join(relative)) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+            _ => {}
+        }
+    }
+    for (relative, content) in files {
+        let path = out.join(relative);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, content)?;
+    }
+    let written: Vec<&str> = files.iter().map(|(relative, _)| relative.as_str()).collect();
+    fs::write(manifest, serde_json::to_string_pretty(&written)? + "\n")
+}
+
+/// Reads and checks a CSV/TSV file for upload, exiting on failure
+fn read_dataset_file(path: &Path, has_header: Option<bool>) -> DatasetFile {
+    let Some(format) = DatasetFormat::from_path(path) else {
+        error!("{} is not a .csv or .tsv file", path.display());
+        process::exit(1);
+    };
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) => {
+            error!("Failed to read {}: {}", path.display(), e);
+            process::exit(1);
+        }
+    };
+    let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("dataset");
+    match DatasetFile::new(file_name, format, content, has_header) {
+        Ok(file) => file,
+        Err(e) => {
+            error!("Invalid dataset file {}: {}", path.display(), e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Looks up a dataset by name, exiting if it cannot be found
+async fn resolve_dataset(client: &SeqeraClient, workspace_id: i64, name: &str) -> Dataset {
+    match client.find_dataset_by_name(workspace_id, name).await {
+        Ok(Some(dataset)) => dataset,
+        Ok(None) => {
+            error!("Dataset '{}' not found in workspace {}", name, workspace_id);
+            process::exit(1);
+        }
+        Err(e) => {
+            error!("Failed to find dataset: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Looks up a data link by name, exiting if it cannot be found
+async fn resolve_data_link(client: &SeqeraClient, workspace_id: i64, name: &str) -> DataLink {
+    match client.find_data_link_by_name(workspace_id, name).await {
+        Ok(Some(link)) => link,
+        Ok(None) => {
+            error!("Data link '{}' not found in workspace {}", name, workspace_id);
+            process::exit(1);
+        }
+        Err(e) => {
+            error!("Failed to find data link: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Starts a studio within the running studios quota of the workspace's
+/// organization, which is looked up unless given. Without access to the
+/// organization, the studio is started unchecked.
+async fn start_studio(
+    client: &SeqeraClient,
+    org_id: Option<i64>,
+    workspace_id: i64,
+    session_id: &str,
+) -> Result<(), SeqeraError> {
+    let org_id = match org_id {
+        Some(org_id) => Some(OrgId(org_id)),
+        None => client.get_workspace_org(workspace_id).await?,
+    };
+    match org_id {
+        Some(org_id) => client.start_studio_within_quota(org_id, workspace_id, session_id).await?,
+        None => {
+            warn!("Organization of workspace {} not found; not checking the studios quota", workspace_id);
+            client.start_studio(workspace_id, session_id).await?
+        }
+    };
+    Ok(())
+}
+
+/// Reads a secret value from a file, or from stdin when no file is given
+fn read_secret_value(path: Option<&Path>) -> io::Result<String> {
+    let mut value = match path {
+        Some(path) => fs::read_to_string(path)?,
+        None => {
+            let mut buffer = String::new();
+            io::stdin().read_to_string(&mut buffer)?;
+            buffer
+        }
+    };
+    let trimmed_len = value.trim_end_matches(['\n', '\r']).len();
+    value.truncate(trimmed_len);
+    Ok(value)
+}
+
+#[tokio::main]
+async fn main() {
+    env_logger::init();
+
+    let cli = Cli::parse();
+
+    let (token, token_source) = read_token(cli.token_file.clone());
+
+    let mut client = match SeqeraClient::new(token) {
+        Ok(client) => client,
+        Err(e) => {
+            error!("Failed to create client: {}", e);
+            process::exit(1);
+        }
+    };
+
+    client.set_verbose(cli.verbose);
+
+    // Commands using optional server features check for them up front
+    if matches!(
+        cli.command,
+        Commands::ComputeEnv(_)
+            | Commands::Data(_)
+            | Commands::Studios(_)
+            | Commands::Workspaces(WorkspaceCommands::Settings { .. } | WorkspaceCommands::Configure { .. })
+    ) {
+        if let Err(e) = client.negotiate().await {
+            warn!("Could not fetch service info, skipping feature checks: {}", e);
+        }
+    }
+
+    match cli.command {
+        Commands::Orgs(cmd) => match cmd {
+            OrgCommands::List => match client.list_organizations().await {
+                Ok(response) => {
+                    println!("Found {} organizations:", response.organizations.len());
+                    for org in response.organizations {
+                        println!("{:<8} {}", org.id.0, org.name);
+                        if let Some(desc) = org.description {
+                            println!("  {}", desc);
+                        }
+                        println!();
+                    }
+                }
+                Err(e) => {
+                    error!("Failed to list organizations: {}", e);
+                    process::exit(1);
+                }
+            },
+            OrgCommands::Get { id } => match client.get_organization(id).await {
+                Ok(org) => {
+                    println!("ID:          {}", org.id.0);
+                    println!("Name:        {}", org.name);
+                    if let Some(desc) = org.description {
+                        println!("Description: {}", desc);
+                    }
+                }
+                Err(e) => {
+                    error!("Failed to get organization: {}", e);
+                    process::exit(1);
+                }
+            },
+            OrgCommands::ValidateName { name } => match client.validate_organization_name(&name).await {
+                Ok(_) => println!("Organization name '{}' is valid", name),
+                Err(e) => {
+                    error!("Organization name validation failed: {}", e);
+                    process::exit(1);
+                }
+            },
+            OrgCommands::Members { org, search, max, offset } => {
+                let org = resolve_org(&client, &org).await;
+                let members = match max {
+                    None => client.list_all_organization_members(org.id.0, 100, search.as_deref()).await,
+                    max => client
+                        .search_organization_members(org.id.0, search.as_deref(), max, offset)
+                        .await
+                        .map(|page| page.members),
+                };
+                match members {
+                    Ok(members) => {
+                        for member in members {
+                            println!(
+                                "{:<8} {:<32} {:<40} {}",
+                                member.member_id, member.user_name, member.email, member.role
+                            );
+                        }
+                    }
+                    Err(e) => {
+                        error!("Failed to list members: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            OrgCommands::SetRole { user, role, org } => {
+                let org = resolve_org(&client, &org).await;
+                let member = resolve_member(&client, org.id.0, &user).await;
+                match client.update_member_role(org.id.0, member.member_id, role.clone()).await {
+                    Ok(()) => println!("{} is now {} of {}", member.user_name, role, org.name),
+                    Err(e) => {
+                        error!("Failed to update role: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            OrgCommands::RemoveMember { user, org } => {
+                let org = resolve_org(&client, &org).await;
+                let member = resolve_member(&client, org.id.0, &user).await;
+                match client.delete_organization_member(org.id.0, member.member_id).await {
+                    Ok(()) => println!("Removed {} from {}", member.user_name, org.name),
+                    Err(e) => {
+                        error!("Failed to remove member: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            OrgCommands::Invite { user, org, role, teams, workspaces } => {
+                let org = resolve_org(&client, &org).await;
+                let mut request = InviteRequest::new(user);
+                if let Some(role) = role {
+                    request = request.org_role(role);
+                }
+                request.teams = teams;
+                request.workspaces = workspaces;
+                match client.invite_and_assign(org.id.0, request).await {
+                    Ok(report) => {
+                        if report.existing_member {
+                            println!("{} is already a member of {}", report.member.user_name, org.name);
+                        } else {
+                            println!("Added {} to {}", report.member.user_name, org.name);
+                        }
+                        for step in &report.steps {
+                            match &step.error {
+                                None => println!("  ok      {}", step.description),
+                                Some(e) => println!("  failed  {}: {}", step.description, e),
+                            }
+                        }
+                        if !report.is_complete() {
+                            error!("{} of {} steps failed", report.failures().count(), report.steps.len());
+                            process::exit(1);
+                        }
+                    }
+                    Err(e) => {
+                        error!("Failed to add {} to the organization: {}", org.name, e);
+                        process::exit(1);
+                    }
+                }
+            },
+        },
+        Commands::Workspaces(cmd) => match cmd {
+            WorkspaceCommands::List { all: true, .. } => {
+                let rows = match client.get_user_info().await {
+                    Ok(user) => client.list_user_workspaces_and_orgs(user.id).await,
+                    Err(e) => Err(e),
+                };
+                match rows {
+                    Ok(rows) => {
+                        let workspaces: Vec<_> = rows.into_iter().filter(|r| r.is_workspace()).collect();
+                        println!("Found {} workspaces:", workspaces.len());
+                        for workspace in workspaces {
+                            let role = workspace.workspace_role().map(|r| r.to_string()).unwrap_or_default();
+                            println!(
+                                "{:<8} {:<40} {}",
+                                workspace.workspace_id.map(|id| id.to_string()).unwrap_or_default(),
+                                workspace.full_path(),
+                                role
+                            );
+                        }
+                    }
+                    Err(e) => {
+                        error!("Failed to list workspaces: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            WorkspaceCommands::List { org_id, org_name, .. } => {
+                let org_id = match (org_id, org_name) {
+                    (Some(id), _) => id,
+                    (None, Some(name)) => {
+                        match client.find_organization_by_name(&name).await {
+                            Ok(Some(org)) => org.id.0,
+                            Ok(None) => {
+                                error!("Organization '{}' not found", name);
+                                process::exit(1);
+                            }
+                            Err(e) => {
+                                error!("Failed to find organization: {}", e);
+                                process::exit(1);
+                            }
+                        }
+                    }
+                    (None, None) => {
+                        error!("Either --org-id or --org-name must be specified");
+                        process::exit(1);
+                    }
+                };
+
+                match client.list_workspaces(org_id).await {
+                    Ok(workspaces) => {
+                        println!("Found {} workspaces:", workspaces.workspaces.len());
+                        for workspace in workspaces.workspaces {
+                            println!("{} ({})", workspace.name, workspace.id);
+                            if let Some(desc) = workspace.description {
+                                println!("  {}", desc);
+                            }
+                            println!("  Visibility: {}", workspace.visibility);
+                            println!();
+                        }
+                    }
+                    Err(e) => {
+                        error!("Failed to list workspaces: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            WorkspaceCommands::View { org_id, id } => {
+                match client.get_workspace(org_id, id).await {
+                    Ok(workspace) => {
+                        println!("ID:          {}", workspace.id);
+                        println!("Name:        {}", workspace.name);
+                        println!("Full Name:   {}", workspace.full_name);
+                        if let Some(desc) = workspace.description {
+                            println!("Description: {}", desc);
+                        }
+                        println!("Visibility:  {}", workspace.visibility);
+                    }
+                    Err(e) => {
+                        error!("Failed to get workspace: {}", e);
+                        process::exit(1);
+                    }
+                }
+            }
+            WorkspaceCommands::Settings { workspace } => {
+                let (org, workspace) = resolve_workspace_path(&client, &workspace).await;
+                match client.get_workspace_settings(org.id, workspace.id).await {
+                    Ok(settings) => {
+                        let unsupported = || "unsupported".to_string();
+                        println!("Default compute env:   {}", settings.default_compute_env.as_deref().unwrap_or("none"));
+                        println!("Wave:                  {}", settings.wave_enabled);
+                        println!("Fusion:                {}", settings.fusion_enabled);
+                        println!(
+                            "Studio lifespan hours: {}",
+                            settings.studios.map_or_else(unsupported, |s| s
+                                .lifespan_hours
+                                .map_or_else(|| "unlimited".to_string(), |h| h.to_string()))
+                        );
+                        println!(
+                            "Data link visibility:  {}",
+                            settings.data_links.map_or_else(unsupported, |d| d.visibility.to_string())
+                        );
+                    }
+                    Err(e) => {
+                        error!("Failed to get workspace settings: {}", e);
+                        process::exit(1);
+                    }
+                }
+            }
+            WorkspaceCommands::Configure {
+                workspaces,
+                org,
+                file,
+                default_compute_env,
+                wave,
+                fusion,
+                studio_lifespan_hours,
+                data_link_visibility,
+                dry_run,
+            } => {
+                let mut desired: DesiredWorkspaceSettings = match file {
+                    Some(path) => match serde_json::from_str(&read_text_file(&path)) {
+                        Ok(desired) => desired,
+                        Err(e) => {
+                            error!("Invalid settings file {}: {}", path.display(), e);
+                            process::exit(1);
+                        }
+                    },
+                    None => DesiredWorkspaceSettings::default(),
+                };
+                desired.default_compute_env = default_compute_env.or(desired.default_compute_env);
+                desired.wave_enabled = wave.or(desired.wave_enabled);
+                desired.fusion_enabled = fusion.or(desired.fusion_enabled);
+                if let Some(hours) = studio_lifespan_hours {
+                    desired.studios = Some(StudiosSettings { lifespan_hours: Some(hours) });
+                }
+                if let Some(visibility) = data_link_visibility {
+                    desired.data_links = Some(DataLinkSettings { visibility });
+                }
+                if desired == DesiredWorkspaceSettings::default() {
+                    error!("No settings given");
+                    process::exit(1);
+                }
+
+                let mut targets = Vec::new();
+                match org {
+                    Some(org) => {
+                        let org = resolve_org(&client, &org).await;
+                        match client.list_workspaces(org.id).await {
+                            Ok(response) => {
+                                for workspace in response.workspaces {
+                                    targets.push((org.id, format!("{}/{}", org.name, workspace.name), workspace.id));
+                                }
+                            }
+                            Err(e) => {
+                                error!("Failed to list workspaces: {}", e);
+                                process::exit(1);
+                            }
+                        }
+                    }
+                    None => {
+                        for path in workspaces {
+                            let (org, workspace) = resolve_workspace_path(&client, &path).await;
+                            targets.push((org.id, path, workspace.id));
+                        }
+                    }
+                }
+
+                let mut failed = 0;
+                for (org_id, path, workspace_id) in targets {
+                    match client.apply_workspace_settings(org_id, workspace_id, &desired, dry_run).await {
+                        Ok(changes) if changes.is_empty() => println!("{}: up to date", path),
+                        Ok(changes) => {
+                            println!("{}:{}", path, if dry_run { " (dry run)" } else { "" });
+                            for change in changes {
+                                println!("  {}", change);
+                            }
+                        }
+                        Err(e) => {
+                            error!("Failed to configure {}: {}", path, e);
+                            failed += 1;
+                        }
+                    }
+                }
+                if failed > 0 {
+                    process::exit(1);
+                }
+            }
+        },
+        Commands::ComputeEnv(cmd) => match cmd {
+            ComputeEnvCommands::List { workspace_id, status: _ } => {
+                match client.list_compute_envs(workspace_id, None).await {
+                    Ok(response) => {
+                        println!("Found {} compute environments:", response.compute_envs.len());
+                        for ce in response.compute_envs {
+                            println!("\nID:          {}", ce.id);
+                            println!("Name:        {}", ce.name);
+                            println!("Platform:    {}", ce.platform);
+                            println!("Status:      {}", ce.status);
+                            if let Some(message) = &ce.message {
+                                println!("Message:     {}", message);   
+                            }
+                            if let Some(last_used) = &ce.last_used {
+                                println!("Last Used:   {}", last_used);
+                            }
+                            if let Some(primary) = ce.primary {
+                                println!("Primary:     {}", primary);
+                            }
+                            println!("Workspace:   {}", ce.workspace_name);
+                            println!("Visibility:  {}", ce.visibility);
+                            println!("Work Dir:    {}", ce.work_dir);
+                            if let Some(region) = &ce.region {
+                                println!("Region:      {}", region);
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        error!("Failed to list compute environments: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            ComputeEnvCommands::Get { compute_env_id, workspace_id } => {
+                match client.get_compute_env(&compute_env_id, workspace_id).await {
+                    Ok(ce) => {
+                        println!("ID:            {}", ce.id);
+                        println!("Name:          {}", ce.name);
+                        if let Some(desc) = &ce.description {
+                            println!("Description:   {}", desc);
+                        }
+                        println!("Platform:      {}", ce.platform);
+                        println!("Status:        {}", ce.status);
+                        if let Some(message) = ce.message {
+                            println!("Message:       {}", message);
+                        }
+                        println!("Date Created:  {}", ce.date_created);
+                        println!("Last Updated:  {}", ce.last_updated);
+                        if let Some(last_used) = ce.last_used {
+                            println!("Last Used:     {}", last_used);
+                        }
+                        if let Some(primary) = ce.primary {
+                            println!("Primary:       {}", primary);
+                        }
+                        println!("Org ID:        {}", ce.org_id);
+                        println!("Workspace ID:  {}", ce.workspace_id);
+                        
+                    }
+                    Err(e) => {
+                        error!("Failed to get compute environment: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            ComputeEnvCommands::ValidateName { workspace_id, name } => {
+                match client.validate_compute_env_name(workspace_id, &name).await {
+                    Ok(_) => println!("Compute environment name '{}' is valid", name),
+                    Err(e) => {
+                        error!("Name validation failed: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            ComputeEnvCommands::Create { workspace_id, file } => {
+                let request: CreateComputeEnvRequest = match fs::read_to_string(&file) {
+                    Ok(content) => match serde_json::from_str(&content) {
+                        Ok(request) => request,
+                        Err(e) => {
+                            error!("Invalid compute environment definition in {}: {}", file.display(), e);
+                            process::exit(1);
+                        }
+                    },
+                    Err(e) => {
+                        error!("Failed to read {}: {}", file.display(), e);
+                        process::exit(1);
+                    }
+                };
+                let name = request.compute_env.name.clone();
+                match client.create_compute_env(workspace_id, request).await {
+                    Ok(id) => println!("Compute environment '{}' created with ID {}", name, id),
+                    Err(e) => {
+                        error!("Failed to create compute environment: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            ComputeEnvCommands::Delete { compute_env_id, workspace_id } => {
+                match client.delete_compute_env(&compute_env_id, workspace_id).await {
+                    Ok(_) => println!("Compute environment '{}' deleted", compute_env_id),
+                    Err(e) => {
+                        error!("Failed to delete compute environment: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            ComputeEnvCommands::Wait { compute_env_id, workspace_id, timeout, interval } => {
+                let result = client
+                    .wait_for_compute_env(
+                        &compute_env_id,
+                        workspace_id,
+                        Duration::from_secs(interval),
+                        timeout.map(Duration::from_secs),
+                    )
+                    .await;
+                match result {
+                    Ok(ce) => println!("Compute environment '{}' is {}", ce.name, ce.status),
+                    Err(e) => {
+                        error!("Compute environment did not become available: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            ComputeEnvCommands::Primary { workspace_id, set: Some(compute_env_id) } => {
+                match client.set_primary_compute_env(&compute_env_id, workspace_id).await {
+                    Ok(_) => println!("Compute environment '{}' is now primary", compute_env_id),
+                    Err(e) => {
+                        error!("Failed to set primary compute environment: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            ComputeEnvCommands::Primary { workspace_id, set: None } => {
+                match client.get_primary_compute_env(workspace_id).await {
+                    Ok(Some(ce)) => {
+                        println!("ID:          {}", ce.id);
+                        println!("Name:        {}", ce.name);
+                        println!("Platform:    {}", ce.platform);
+                        println!("Status:      {}", ce.status);
+                    }
+                    Ok(None) => println!("No primary compute environment set"),
+                    Err(e) => {
+                        error!("Failed to get primary compute environment: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+        }
+        Commands::Secrets(cmd) => match cmd {
+            SecretCommands::List { scope } => {
+                match client.list_pipeline_secrets(scope.scope()).await {
+                    Ok(response) => {
+                        println!("Found {} pipeline secrets:", response.pipeline_secrets.len());
+                        for secret in response.pipeline_secrets {
+                            print!("{:<8} {}", secret.id, secret.name);
+                            if let Some(last_used) = secret.last_used {
+                                print!("  (last used {})", last_used);
+                            }
+                            println!();
+                        }
+                    }
+                    Err(e) => {
+                        error!("Failed to list pipeline secrets: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            SecretCommands::Add { scope, name, value_file } => {
+                let value = match read_secret_value(value_file.as_deref()) {
+                    Ok(value) => value,
+                    Err(e) => {
+                        error!("Failed to read secret value: {}", e);
+                        process::exit(1);
+                    }
+                };
+                match client.create_pipeline_secret(scope.scope(), name.as_str(), value).await {
+                    Ok(id) => println!("Pipeline secret '{}' added with ID {}", name, id),
+                    Err(e) => {
+                        error!("Failed to add pipeline secret: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            SecretCommands::Update { scope, name, value_file } => {
+                let secret = match client.find_pipeline_secret_by_name(scope.scope(), &name).await {
+                    Ok(Some(secret)) => secret,
+                    Ok(None) => {
+                        error!("Pipeline secret '{}' not found in {}", name, scope.scope());
+                        process::exit(1);
+                    }
+                    Err(e) => {
+                        error!("Failed to find pipeline secret: {}", e);
+                        process::exit(1);
+                    }
+                };
+                let value = match read_secret_value(value_file.as_deref()) {
+                    Ok(value) => value,
+                    Err(e) => {
+                        error!("Failed to read secret value: {}", e);
+                        process::exit(1);
+                    }
+                };
+                match client.update_pipeline_secret(scope.scope(), secret.id, value).await {
+                    Ok(_) => println!("Pipeline secret '{}' updated", name),
+                    Err(e) => {
+                        error!("Failed to update pipeline secret: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            SecretCommands::Delete { scope, name } => {
+                let secret = match client.find_pipeline_secret_by_name(scope.scope(), &name).await {
+                    Ok(Some(secret)) => secret,
+                    Ok(None) => {
+                        error!("Pipeline secret '{}' not found in {}", name, scope.scope());
+                        process::exit(1);
+                    }
+                    Err(e) => {
+                        error!("Failed to find pipeline secret: {}", e);
+                        process::exit(1);
+                    }
+                };
+                match client.delete_pipeline_secret(scope.scope(), secret.id).await {
+                    Ok(_) => println!("Pipeline secret '{}' deleted", name),
+                    Err(e) => {
+                        error!("Failed to delete pipeline secret: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            SecretCommands::Sync { scope, file, prune, dry_run } => {
+                let entries = match fs::read_to_string(&file) {
+                    Ok(content) => match parse_env_file(&content) {
+                        Ok(entries) => entries,
+                        Err(e) => {
+                            error!("Failed to parse {}: {}", file.display(), e);
+                            process::exit(1);
+                        }
+                    },
+                    Err(e) => {
+                        error!("Failed to read {}: {}", file.display(), e);
+                        process::exit(1);
+                    }
+                };
+                let plan = match client.plan_pipeline_secret_sync(scope.scope(), entries, prune).await {
+                    Ok(plan) => plan,
+                    Err(e) => {
+                        error!("Failed to plan pipeline secret sync: {}", e);
+                        process::exit(1);
+                    }
+                };
+                if plan.is_empty() {
+                    println!("No changes");
+                    return;
+                }
+                for change in &plan.changes {
+                    println!("{}", change);
+                }
+                if dry_run {
+                    return;
+                }
+                match client.apply_pipeline_secret_sync(scope.scope(), plan).await {
+                    Ok(_) => println!("Pipeline secrets synced"),
+                    Err(e) => {
+                        error!("Failed to sync pipeline secrets: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+        },
+        Commands::Platforms(cmd) => match cmd {
+            PlatformCommands::List { workspace_id } => {
+                match client.list_platforms(workspace_id, None::<i64>).await {
+                    Ok(response) => {
+                        println!("Found {} platforms:", response.platforms.len());
+                        for platform in response.platforms {
+                            println!("{:<24} {}", platform.id, platform.name);
+                            println!("  Credentials: {}", platform.credentials_providers.join(", "));
+                        }
+                    }
+                    Err(e) => {
+                        error!("Failed to list platforms: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            PlatformCommands::Regions { platform_id, workspace_id } => {
+                match client.list_platform_regions(platform_id, workspace_id).await {
+                    Ok(response) => {
+                        for region in response.regions {
+                            println!("{:<24} {}", region.id, region.name);
+                        }
+                    }
+                    Err(e) => {
+                        error!("Failed to list platform regions: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            PlatformCommands::Describe { platform_id, workspace_id, region, credentials_id } => {
+                let result = client
+                    .get_platform_details(
+                        platform_id,
+                        workspace_id,
+                        region.as_deref(),
+                        credentials_id.as_deref(),
+                    )
+                    .await;
+                match result {
+                    Ok(response) => print_platform_metainfo(&response.metainfo),
+                    Err(e) => {
+                        error!("Failed to describe platform: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+        },
+        Commands::Data(cmd) => match cmd {
+            DataCommands::Ls { path: None, workspace_id, refresh } => {
+                if refresh {
+                    if let Err(e) = client.refresh_data_links(workspace_id, None).await {
+                        error!("Failed to refresh data links: {}", e);
+                        process::exit(1);
+                    }
+                }
+                match client.list_data_links(workspace_id, None, None, None, None).await {
+                    Ok(response) => {
+                        println!("Found {} data links:", response.data_links.len());
+                        for link in response.data_links {
+                            println!("{:<32} {:<8} {}", link.name, link.provider, link.resource_ref);
+                        }
+                    }
+                    Err(e) => {
+                        error!("Failed to list data links: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            DataCommands::Ls { path: Some(path), workspace_id, refresh } => {
+                if refresh {
+                    if let Err(e) = client.refresh_data_links(workspace_id, None).await {
+                        error!("Failed to refresh data links: {}", e);
+                        process::exit(1);
+                    }
+                }
+                let link = resolve_data_link(&client, workspace_id, &path.link).await;
+                match client.browse_data_link_all(workspace_id, &link.id, Some(&path.path)).await {
+                    Ok(items) => {
+                        for item in items {
+                            match item.item_type {
+                                DataLinkItemType::Folder => println!("{:>12}  {}/", "", item.name),
+                                DataLinkItemType::File => println!("{:>12}  {}", item.size, item.name),
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        error!("Failed to list {}: {}", path, e);
+                        process::exit(1);
+                    }
+                }
+            },
+            DataCommands::Cp { source, destination, workspace_id } => {
+                match (
+                    DataLinkPath::is_data_link_path(&source),
+                    DataLinkPath::is_data_link_path(&destination),
+                ) {
+                    (true, false) => {
+                        let remote: DataLinkPath = match source.parse() {
+                            Ok(remote) => remote,
+                            Err(e) => {
+                                error!("{}", e);
+                                process::exit(1);
+                            }
+                        };
+                        let Some(file_name) = remote.file_name() else {
+                            error!("{} does not name a file", remote);
+                            process::exit(1);
+                        };
+                        let mut target = PathBuf::from(&destination);
+                        if target.is_dir() {
+                            target.push(file_name);
+                        }
+                        let link = resolve_data_link(&client, workspace_id, &remote.link).await;
+                        let content = match client.download_from_data_link(workspace_id, &link.id, &remote.path).await {
+                            Ok(content) => content,
+                            Err(e) => {
+                                error!("Failed to download {}: {}", remote, e);
+                                process::exit(1);
+                            }
+                        };
+                        if let Err(e) = fs::write(&target, &content) {
+                            error!("Failed to write {}: {}", target.display(), e);
+                            process::exit(1);
+                        }
+                        println!("Downloaded {} to {} ({} bytes)", remote, target.display(), content.len());
+                    }
+                    (false, true) => {
+                        let remote: DataLinkPath = match destination.parse() {
+                            Ok(remote) => remote,
+                            Err(e) => {
+                                error!("{}", e);
+                                process::exit(1);
+                            }
+                        };
+                        let local = Path::new(&source);
+                        let Some(file_name) = local.file_name().and_then(|n| n.to_str()) else {
+                            error!("{} does not name a file", source);
+                            process::exit(1);
+                        };
+                        let content = match fs::read(local) {
+                            Ok(content) => content,
+                            Err(e) => {
+                                error!("Failed to read {}: {}", source, e);
+                                process::exit(1);
+                            }
+                        };
+                        let size = content.len();
+                        let link = resolve_data_link(&client, workspace_id, &remote.link).await;
+                        let result = client
+                            .upload_to_data_link(workspace_id, &link.id, Some(&remote.path), file_name, content, None)
+                            .await;
+                        match result {
+                            Ok(_) => println!("Uploaded {} to {} ({} bytes)", source, remote, size),
+                            Err(e) => {
+                                error!("Failed to upload {}: {}", source, e);
+                                process::exit(1);
+                            }
+                        }
+                    }
+                    _ => {
+                        error!("Exactly one of the source and destination must be a dl://<data-link>/<path> location");
+                        process::exit(1);
+                    }
+                }
+            },
+        },
+        Commands::Datasets(cmd) => match cmd {
+            DatasetCommands::List { workspace_id } => {
+                match client.list_datasets(workspace_id).await {
+                    Ok(response) => {
+                        println!("Found {} datasets:", response.datasets.len());
+                        for dataset in response.datasets {
+                            print!("{:<24} {}", dataset.id, dataset.name);
+                            if let Some(description) = dataset.description {
+                                print!("  {}", description);
                             }
+                            println!();
+                        }
+                    }
+                    Err(e) => {
+                        error!("Failed to list datasets: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            DatasetCommands::Create { workspace_id, name, description, file } => {
+                // Check the file before creating anything
+                let file = file.map(|path| read_dataset_file(&path, None));
+                let request = CreateDatasetRequest { name, description };
+                let dataset = match client.create_dataset(workspace_id, request).await {
+                    Ok(dataset) => dataset,
+                    Err(e) => {
+                        error!("Failed to create dataset: {}", e);
+                        process::exit(1);
+                    }
+                };
+                println!("Dataset '{}' created with ID {}", dataset.name, dataset.id);
+                if let Some(file) = file {
+                    match client.upload_dataset_version(workspace_id, &dataset.id, file).await {
+                        Ok(version) => println!("Uploaded version {}: {}", version.version, version.url),
+                        Err(e) => {
+                            error!("Failed to upload dataset version: {}", e);
+                            process::exit(1);
+                        }
+                    }
+                }
+            },
+            DatasetCommands::Update { workspace_id, name, new_name, description } => {
+                let dataset = resolve_dataset(&client, workspace_id, &name).await;
+                let request = UpdateDatasetRequest {
+                    name: new_name.unwrap_or(dataset.name),
+                    description: description.or(dataset.description),
+                };
+                match client.update_dataset(workspace_id, &dataset.id, request).await {
+                    Ok(_) => println!("Dataset '{}' updated", name),
+                    Err(e) => {
+                        error!("Failed to update dataset: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            DatasetCommands::Delete { workspace_id, name } => {
+                let dataset = resolve_dataset(&client, workspace_id, &name).await;
+                match client.delete_dataset(workspace_id, &dataset.id).await {
+                    Ok(_) => println!("Dataset '{}' deleted", name),
+                    Err(e) => {
+                        error!("Failed to delete dataset: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            DatasetCommands::Upload { workspace_id, name, file, header, no_header } => {
+                let has_header = match (header, no_header) {
+                    (true, _) => Some(true),
+                    (_, true) => Some(false),
+                    _ => None,
+                };
+                let file = read_dataset_file(&file, has_header);
+                println!(
+                    "Uploading {} ({} rows, {} columns, {})",
+                    file.file_name,
+                    file.rows,
+                    file.columns,
+                    if file.has_header { "with header" } else { "no header" },
+                );
+                let dataset = resolve_dataset(&client, workspace_id, &name).await;
+                match client.upload_dataset_version(workspace_id, &dataset.id, file).await {
+                    Ok(version) => println!("Uploaded version {}: {}", version.version, version.url),
+                    Err(e) => {
+                        error!("Failed to upload dataset version: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            DatasetCommands::Versions { workspace_id, name } => {
+                let dataset = resolve_dataset(&client, workspace_id, &name).await;
+                match client.list_dataset_versions(workspace_id, &dataset.id).await {
+                    Ok(response) => {
+                        for version in response.versions {
+                            println!("{:<4} {:<24} {}", version.version, version.file_name, version.url);
+                        }
+                    }
+                    Err(e) => {
+                        error!("Failed to list dataset versions: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            DatasetCommands::Download { workspace_id, name, version, out } => {
+                let dataset = resolve_dataset(&client, workspace_id, &name).await;
+                let versions = match client.list_dataset_versions(workspace_id, &dataset.id).await {
+                    Ok(response) => response.versions,
+                    Err(e) => {
+                        error!("Failed to list dataset versions: {}", e);
+                        process::exit(1);
+                    }
+                };
+                let selected = match version {
+                    Some(number) => versions.into_iter().find(|v| v.version == number),
+                    None => versions.into_iter().max_by_key(|v| v.version),
+                };
+                let Some(selected) = selected else {
+                    error!("Dataset '{}' has no matching version", name);
+                    process::exit(1);
+                };
+                let result = client
+                    .download_dataset_version(workspace_id, &dataset.id, selected.version, &selected.file_name)
+                    .await;
+                let content = match result {
+                    Ok(content) => content,
+                    Err(e) => {
+                        error!("Failed to download dataset version: {}", e);
+                        process::exit(1);
+                    }
+                };
+                let target = out.unwrap_or_else(|| PathBuf::from(&selected.file_name));
+                if let Err(e) = fs::write(&target, &content) {
+                    error!("Failed to write {}: {}", target.display(), e);
+                    process::exit(1);
+                }
+                println!("Downloaded version {} to {}", selected.version, target.display());
+            },
+        },
+        Commands::Studios(cmd) => match cmd {
+            StudioCommands::List { workspace_id } => {
+                match client.list_studios(workspace_id, None, None, None).await {
+                    Ok(response) => {
+                        println!("Found {} studios:", response.studios.len());
+                        for studio in response.studios {
+                            println!("{:<12} {:<10} {}", studio.session_id, studio.status(), studio.name);
+                        }
+                    }
+                    Err(e) => {
+                        error!("Failed to list studios: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            StudioCommands::Templates { workspace_id } => {
+                match client.list_studio_templates(workspace_id).await {
+                    Ok(response) => {
+                        for template in response.templates {
+                            println!("{:<12} {}", template.tool.unwrap_or_default(), template.repository);
+                        }
+                    }
+                    Err(e) => {
+                        error!("Failed to list studio templates: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            StudioCommands::Create {
+                workspace_id,
+                name,
+                template,
+                compute_env_id,
+                mounts,
+                description,
+                cpu,
+                memory,
+                gpu,
+                start,
+                org_id,
+            } => {
+                let mut request = CreateStudioRequest::new(name, template, compute_env_id)
+                    .resources(cpu, memory, gpu);
+                if let Some(description) = description {
+                    request = request.description(description);
+                }
+                for mount in mounts {
+                    let link = resolve_data_link(&client, workspace_id, &mount).await;
+                    request = request.mount_data_link(link.id);
+                }
+                let studio = match client.create_studio(workspace_id, request, false).await {
+                    Ok(studio) => studio,
+                    Err(e) => {
+                        error!("Failed to create studio: {}", e);
+                        process::exit(1);
+                    }
+                };
+                println!("Studio '{}' created with session ID {}", studio.name, studio.session_id);
+                if start {
+                    if let Err(e) = start_studio(&client, org_id, workspace_id, &studio.session_id).await {
+                        error!("Failed to start studio: {}", e);
+                        process::exit(1);
+                    }
+                    println!("Studio {} starting", studio.session_id);
+                }
+            },
+            StudioCommands::Start { workspace_id, session_id, org_id, wait } => {
+                if let Err(e) = start_studio(&client, org_id, workspace_id, &session_id).await {
+                    error!("Failed to start studio: {}", e);
+                    process::exit(1);
+                }
+                println!("Studio {} starting", session_id);
+                if wait {
+                    match client
+                        .wait_for_studio(workspace_id, &session_id, StudioStatus::Running, Duration::from_secs(10), None)
+                        .await
+                    {
+                        Ok(studio) => println!("Studio running at {}", studio.studio_url.unwrap_or_default()),
+                        Err(e) => {
+                            error!("{}", e);
+                            process::exit(1);
+                        }
+                    }
+                }
+            },
+            StudioCommands::Stop { workspace_id, session_id } => {
+                match client.stop_studio(workspace_id, &session_id).await {
+                    Ok(_) => println!("Studio {} stopping", session_id),
+                    Err(e) => {
+                        error!("Failed to stop studio: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            StudioCommands::Delete { workspace_id, session_id } => {
+                match client.delete_studio(workspace_id, &session_id).await {
+                    Ok(_) => println!("Studio {} deleted", session_id),
+                    Err(e) => {
+                        error!("Failed to delete studio: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            StudioCommands::Status { workspace_id, session_id } => {
+                match client.get_studio(workspace_id, &session_id).await {
+                    Ok(studio) => {
+                        println!("Name:   {}", studio.name);
+                        println!("Status: {}", studio.status());
+                        if let Some(message) = &studio.status_info.message {
+                            println!("        {}", message);
+                        }
+                        if let Some(url) = &studio.studio_url {
+                            println!("URL:    {}", url);
+                        }
+                        if let Some(last_activity) = studio.last_activity() {
+                            println!("Active: {}", last_activity);
+                        }
+                    }
+                    Err(e) => {
+                        error!("Failed to get studio: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            StudioCommands::Wait { workspace_id, session_id, timeout, interval } => {
+                let result = client
+                    .wait_for_studio(
+                        workspace_id,
+                        &session_id,
+                        StudioStatus::Running,
+                        Duration::from_secs(interval),
+                        timeout.map(Duration::from_secs),
+                    )
+                    .await;
+                match result {
+                    Ok(studio) => println!("Studio running at {}", studio.studio_url.unwrap_or_default()),
+                    Err(e) => {
+                        error!("{}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            StudioCommands::Cleanup { workspace_id, idle_hours, dry_run } => {
+                let cutoff = chrono::Utc::now() - chrono::Duration::hours(idle_hours);
+                let idle = match client.list_idle_studios(workspace_id, cutoff).await {
+                    Ok(idle) => idle,
+                    Err(e) => {
+                        error!("Failed to list studios: {}", e);
+                        process::exit(1);
+                    }
+                };
+                if idle.is_empty() {
+                    println!("No idle studios");
+                }
+                for studio in idle {
+                    if dry_run {
+                        println!("Would stop {} ({})", studio.session_id, studio.name);
+                        continue;
+                    }
+                    match client.stop_studio(workspace_id, &studio.session_id).await {
+                        Ok(_) => println!("Stopped {} ({})", studio.session_id, studio.name),
+                        Err(e) => {
+                            error!("Failed to stop studio {}: {}", studio.session_id, e);
+                            process::exit(1);
+                        }
+                    }
+                }
+            },
+        },
+        Commands::Actions(cmd) => match cmd {
+            ActionCommands::List { workspace_id } => {
+                match client.list_actions(workspace_id).await {
+                    Ok(response) => {
+                        println!("Found {} actions:", response.actions.len());
+                        for action in response.actions {
+                            println!(
+                                "{:<24} {:<8} {:<8} {}",
+                                action.name,
+                                action.source,
+                                action.status,
+                                action.pipeline.unwrap_or_default()
+                            );
                         }
                     }
-                    (None, None) => {
-                        error!("Either --org-id or --org-name must be specified");
+                    Err(e) => {
+                        error!("Failed to list actions: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            ActionCommands::Get { workspace_id, name } => {
+                let action = resolve_action(&client, workspace_id, &name).await;
+                println!("ID:        {}", action.id);
+                println!("Name:      {}", action.name);
+                println!("Source:    {}", action.source);
+                println!("Status:    {}", action.status);
+                println!("Pipeline:  {}", action.launch.pipeline);
+                if let Some(revision) = action.launch.revision {
+                    println!("Revision:  {}", revision);
+                }
+                if let Some(hook_url) = action.hook_url {
+                    println!("Hook URL:  {}", hook_url);
+                }
+            },
+            ActionCommands::Create {
+                workspace_id,
+                name,
+                source,
+                pipeline,
+                compute_env_id,
+                work_dir,
+                revision,
+                params_file,
+                profiles,
+            } => {
+                let launch = ActionLaunch {
+                    compute_env_id,
+                    pipeline,
+                    work_dir,
+                    revision,
+                    params_text: params_file.as_deref().map(read_text_file),
+                    config_profiles: profiles,
+                    ..Default::default()
+                };
+                let request = match source {
+                    ActionSource::Github => CreateActionRequest::github(name.as_str(), launch),
+                    ActionSource::Tower => CreateActionRequest::tower(name.as_str(), launch),
+                    ActionSource::Unknown => {
+                        error!("Unknown action source; expected github or tower");
+                        process::exit(1);
+                    }
+                };
+                match client.create_action(workspace_id, request).await {
+                    Ok(id) => println!("Action '{}' created with ID {}", name, id),
+                    Err(e) => {
+                        error!("Failed to create action: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            ActionCommands::Update { workspace_id, name, new_name, compute_env_id, work_dir, revision, params_file } => {
+                let action = resolve_action(&client, workspace_id, &name).await;
+                let mut launch = action.launch;
+                if compute_env_id.is_some() {
+                    launch.compute_env_id = compute_env_id;
+                }
+                if work_dir.is_some() {
+                    launch.work_dir = work_dir;
+                }
+                if revision.is_some() {
+                    launch.revision = revision;
+                }
+                if let Some(path) = params_file {
+                    launch.params_text = Some(read_text_file(&path));
+                }
+                let request = UpdateActionRequest {
+                    name: new_name.unwrap_or(action.name),
+                    launch,
+                };
+                match client.update_action(workspace_id, &action.id, request).await {
+                    Ok(_) => println!("Action '{}' updated", name),
+                    Err(e) => {
+                        error!("Failed to update action: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            ActionCommands::Pause { workspace_id, name } => {
+                let action = resolve_action(&client, workspace_id, &name).await;
+                match client.pause_action(workspace_id, &action.id).await {
+                    Ok(_) => println!("Action '{}' paused", name),
+                    Err(e) => {
+                        error!("Failed to pause action: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            ActionCommands::Resume { workspace_id, name } => {
+                let action = resolve_action(&client, workspace_id, &name).await;
+                match client.resume_action(workspace_id, &action.id).await {
+                    Ok(_) => println!("Action '{}' resumed", name),
+                    Err(e) => {
+                        error!("Failed to resume action: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            ActionCommands::Delete { workspace_id, name } => {
+                let action = resolve_action(&client, workspace_id, &name).await;
+                match client.delete_action(workspace_id, &action.id).await {
+                    Ok(_) => println!("Action '{}' deleted", name),
+                    Err(e) => {
+                        error!("Failed to delete action: {}", e);
                         process::exit(1);
                     }
+                }
+            },
+            ActionCommands::Trigger { workspace_id, name, params_file, params } => {
+                let mut payload = match params_file {
+                    Some(path) => match serde_json::from_str(&read_text_file(&path)) {
+                        Ok(serde_json::Value::Object(map)) => map,
+                        Ok(_) => {
+                            error!("{} must contain a JSON object", path.display());
+                            process::exit(1);
+                        }
+                        Err(e) => {
+                            error!("Failed to parse {}: {}", path.display(), e);
+                            process::exit(1);
+                        }
+                    },
+                    None => serde_json::Map::new(),
                 };
-
-                match client.list_workspaces(org_id).await {
-                    Ok(workspaces) => {
-                        println!("Found {} workspaces:", workspaces.workspaces.len());
-                        for workspace in workspaces.workspaces {
-                            println!("{} ({})", workspace.name, workspace.id);
-                            if let Some(desc) = workspace.description {
-                                println!("  {}", desc);
-                            }
-                            println!("  Visibility: {}", workspace.visibility);
-                            println!();
+                for param in params {
+                    let Some((key, value)) = param.split_once('=') else {
+                        error!("Invalid parameter '{}', expected KEY=VALUE", param);
+                        process::exit(1);
+                    };
+                    payload.insert(key.to_string(), serde_json::Value::String(value.to_string()));
+                }
+                let action = resolve_action(&client, workspace_id, &name).await;
+                match client.trigger_action(workspace_id, &action.id, payload).await {
+                    Ok(workflow_id) => println!("Action '{}' launched workflow {}", name, workflow_id),
+                    Err(e) => {
+                        error!("Failed to trigger action: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+        },
+        Commands::Tokens(cmd) => match cmd {
+            TokenCommands::List => match client.list_access_tokens().await {
+                Ok(response) => {
+                    println!("Found {} tokens:", response.tokens.len());
+                    for token in response.tokens {
+                        print!("{:<8} {}", token.id, token.name);
+                        if let Some(last_used) = token.last_used {
+                            print!("  (last used {})", last_used);
                         }
+                        println!();
+                    }
+                }
+                Err(e) => {
+                    error!("Failed to list tokens: {}", e);
+                    process::exit(1);
+                }
+            },
+            TokenCommands::Create { name } => match client.create_access_token(name.as_str()).await {
+                Ok(created) => {
+                    eprintln!("Token '{}' created with ID {}. It will not be shown again:", name, created.token.id);
+                    println!("{}", created.access_key.expose());
+                }
+                Err(e) => {
+                    error!("Failed to create token: {}", e);
+                    process::exit(1);
+                }
+            },
+            TokenCommands::Delete { name } => {
+                let tokens = match client.list_access_tokens().await {
+                    Ok(response) => response.tokens,
+                    Err(e) => {
+                        error!("Failed to list tokens: {}", e);
+                        process::exit(1);
                     }
+                };
+                let Some(token) = tokens.into_iter().find(|t| t.name == name) else {
+                    error!("Token '{}' not found", name);
+                    process::exit(1);
+                };
+                match client.delete_access_token(token.id).await {
+                    Ok(_) => println!("Token '{}' deleted", name),
                     Err(e) => {
-                        error!("Failed to list workspaces: {}", e);
+                        error!("Failed to delete token: {}", e);
                         process::exit(1);
                     }
                 }
             },
-            WorkspaceCommands::View { org_id, id } => {
-                match client.get_workspace(org_id, id).await {
-                    Ok(workspace) => {
-                        println!("ID:          {}", workspace.id);
-                        println!("Name:        {}", workspace.name);
-                        println!("Full Name:   {}", workspace.full_name);
-                        if let Some(desc) = workspace.description {
-                            println!("Description: {}", desc);
-                        }
-                        println!("Visibility:  {}", workspace.visibility);
+            TokenCommands::Rotate { name, new_name, out } => {
+                let target = match (out, token_source) {
+                    (Some(path), _) | (None, TokenSource::File(path)) => path,
+                    (None, TokenSource::Env) => {
+                        error!("The token comes from TOWER_ACCESS_TOKEN; pass --out to choose where to write the new token");
+                        process::exit(1);
                     }
+                };
+                let tokens = match client.list_access_tokens().await {
+                    Ok(response) => response.tokens,
                     Err(e) => {
-                        error!("Failed to get workspace: {}", e);
+                        error!("Failed to list tokens: {}", e);
+                        process::exit(1);
+                    }
+                };
+                let Some(old) = tokens.into_iter().find(|t| t.name == name) else {
+                    error!("Token '{}' not found", name);
+                    process::exit(1);
+                };
+
+                let new_name = new_name.unwrap_or_else(|| rotated_token_name(&name, chrono::Utc::now()));
+                let created = match client.create_access_token(new_name.as_str()).await {
+                    Ok(created) => created,
+                    Err(e) => {
+                        error!("Failed to create token: {}", e);
+                        process::exit(1);
+                    }
+                };
+                let rotated = client.with_token(created.access_key.expose().to_string());
+                if let Err(e) = rotated.list_access_tokens().await {
+                    error!(
+                        "New token '{}' failed verification: {}. {}",
+                        new_name,
+                        e,
+                        discard_new_token(&client, created.token.id).await
+                    );
+                    process::exit(1);
+                }
+                if let Err(e) = write_token_file(&target, created.access_key.expose()) {
+                    error!(
+                        "Failed to write {}: {}. {}",
+                        target.display(),
+                        e,
+                        discard_new_token(&client, created.token.id).await
+                    );
+                    process::exit(1);
+                }
+
+                match rotated.delete_access_token(old.id).await {
+                    Ok(_) => println!("Rotated '{}' to '{}', written to {}", name, new_name, target.display()),
+                    Err(e) => {
+                        error!("New token is in place but deleting '{}' failed: {}", name, e);
                         process::exit(1);
                     }
                 }
+            },
+        },
+        Commands::Whoami => {
+            let user = match client.get_user_info().await {
+                Ok(user) => user,
+                Err(e) => {
+                    error!("Failed to get user info: {}", e);
+                    process::exit(1);
+                }
+            };
+            println!("ID:        {}", user.id);
+            println!("User name: {}", user.user_name);
+            println!("Email:     {}", user.email);
+            if let Some(avatar) = &user.avatar {
+                println!("Avatar:    {}", avatar);
+            }
+            match client.list_user_workspaces_and_orgs(user.id).await {
+                Ok(rows) => {
+                    println!("Organizations:");
+                    for org in rows.iter().filter(|r| !r.is_workspace()) {
+                        let role = org.org_role().map(|r| r.to_string()).unwrap_or_default();
+                        println!("  {:<32} {}", org.org_name, role);
+                    }
+                }
+                Err(e) => {
+                    error!("Failed to list organizations: {}", e);
+                    process::exit(1);
+                }
             }
         },
-        Commands::ComputeEnv(cmd) => match cmd {
-            ComputeEnvCommands::List { workspace_id, status: _ } => {
-                match client.list_compute_envs(workspace_id, None).await {
-                    Ok(response) => {
-                        println!("Found {} compute environments:", response.compute_envs.len());
-                        for ce in response.compute_envs {
-                            println!("\nID:          {}", ce.id);
-                            println!("Name:        {}", ce.name);
-                            println!("Platform:    {}", ce.platform);
-                            println!("Status:      {}", ce.status);
-                            if let Some(message) = &ce.message {
-                                println!("Message:     {}", message);   
-                            }
-                            if let Some(last_used) = &ce.last_used {
-                                println!("Last Used:   {}", last_used);
-                            }
-                            if let Some(primary) = ce.primary {
-                                println!("Primary:     {}", primary);
-                            }
-                            println!("Workspace:   {}", ce.workspace_name);
-                            println!("Visibility:  {}", ce.visibility);
-                            println!("Work Dir:    {}", ce.work_dir);
-                            if let Some(region) = &ce.region {
-                                println!("Region:      {}", region);
-                            }
+        Commands::Info => match client.get_service_info().await {
+            Ok(info) => {
+                println!("Version:     {}", info.version);
+                println!("API version: {}", info.api_version);
+                if let Some(commit_id) = &info.commit_id {
+                    println!("Commit:      {}", commit_id);
+                }
+                println!("Auth types:  {}", info.auth_types.join(", "));
+                println!("Features:");
+                for feature in [Feature::DataLinks, Feature::DataStudios, Feature::Fusion, Feature::Wave] {
+                    let supported = if info.supports(feature) { "yes" } else { "no" };
+                    println!("  {:<14} {}", feature.to_string(), supported);
+                }
+                println!("Flags:");
+                for (flag, enabled) in info.feature_flags() {
+                    println!("  {:<32} {}", flag, enabled);
+                }
+            }
+            Err(e) => {
+                error!("Failed to get service info: {}", e);
+                process::exit(1);
+            }
+        },
+        Commands::Teams(cmd) => match cmd {
+            TeamCommands::List { org } => {
+                let org = resolve_org(&client, &org).await;
+                match client.list_organization_teams(org.id.0).await {
+                    Ok(teams) => {
+                        println!("Found {} teams:", teams.total_size);
+                        for team in teams.teams {
+                            println!("{:<8} {:<40} {} members", team.team_id, team.name, team.members_count);
                         }
                     }
                     Err(e) => {
-                        error!("Failed to list compute environments: {}", e);
+                        error!("Failed to list teams: {}", e);
                         process::exit(1);
                     }
                 }
             },
-            ComputeEnvCommands::Get { compute_env_id, workspace_id } => {
-                match client.get_compute_env(&compute_env_id, workspace_id).await {
-                    Ok(ce) => {
-                        println!("ID:            {}", ce.id);
-                        println!("Name:          {}", ce.name);
-                        if let Some(desc) = &ce.description {
-                            println!("Description:   {}", desc);
+            TeamCommands::Members { team, org, search, max, offset } => {
+                let org = resolve_org(&client, &org).await;
+                let team = resolve_team(&client, org.id.0, &team).await;
+                let members = match max {
+                    None => client.list_all_team_members(org.id.0, team.team_id, 100, search.as_deref()).await,
+                    max => client
+                        .list_team_members(org.id.0, team.team_id, max, offset, search.as_deref())
+                        .await
+                        .map(|page| page.members),
+                };
+                match members {
+                    Ok(members) => {
+                        for member in members {
+                            println!("{:<8} {:<32} {}", member.member_id, member.user_name, member.email);
                         }
-                        println!("Platform:      {:?}", ce.platform);
-                        println!("Status:        {}", ce.status);
-                        if let Some(message) = ce.message {
-                            println!("Message:       {}", message);
+                    }
+                    Err(e) => {
+                        error!("Failed to list team members: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            TeamCommands::Create { name, org, description } => {
+                let org = resolve_org(&client, &org).await;
+                let mut builder = CreateTeamRequestBuilder::new().name(name);
+                if let Some(description) = description {
+                    builder = builder.description(description);
+                }
+                let request = match builder.build() {
+                    Ok(request) => request,
+                    Err(e) => {
+                        error!("Invalid team: {}", e);
+                        process::exit(1);
+                    }
+                };
+                match client.create_team(org.id.0, request).await {
+                    Ok(team) => println!("Created team {} ({})", team.name, team.team_id),
+                    Err(e) => {
+                        error!("Failed to create team: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            TeamCommands::Delete { team, org } => {
+                let org = resolve_org(&client, &org).await;
+                let team = resolve_team(&client, org.id.0, &team).await;
+                match client.delete_team(org.id.0, team.team_id).await {
+                    Ok(()) => println!("Deleted team {}", team.name),
+                    Err(e) => {
+                        error!("Failed to delete team: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            TeamCommands::AddMember { team, user, org } => {
+                let org = resolve_org(&client, &org).await;
+                let team = resolve_team(&client, org.id.0, &team).await;
+                let request = CreateTeamMemberRequest { user_name_or_email: user };
+                match client.create_team_member(org.id.0, team.team_id, request).await {
+                    Ok(member) => println!("Added {} to team {}", member.user_name, team.name),
+                    Err(e) => {
+                        error!("Failed to add team member: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            TeamCommands::RemoveMember { team, user, org } => {
+                let org = resolve_org(&client, &org).await;
+                let team = resolve_team(&client, org.id.0, &team).await;
+                let members = match client.list_all_team_members(org.id.0, team.team_id, 100, None).await {
+                    Ok(members) => members,
+                    Err(e) => {
+                        error!("Failed to list team members: {}", e);
+                        process::exit(1);
+                    }
+                };
+                let Some(member) = members.into_iter().find(|m| m.user_name == user || m.email == user) else {
+                    error!("'{}' is not a member of team {}", user, team.name);
+                    process::exit(1);
+                };
+                match client.delete_team_member(org.id.0, team.team_id, member.member_id).await {
+                    Ok(()) => println!("Removed {} from team {}", member.user_name, team.name),
+                    Err(e) => {
+                        error!("Failed to remove team member: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            TeamCommands::Workspaces { team, org } => {
+                let org = resolve_org(&client, &org).await;
+                let team = resolve_team(&client, org.id.0, &team).await;
+                match client.list_team_participations(org.id.0, team.team_id).await {
+                    Ok(participations) => {
+                        println!("Team {} participates in {} workspaces:", team.name, participations.len());
+                        for participation in participations {
+                            println!(
+                                "{:<8} {:<40} {}",
+                                participation.workspace.id,
+                                participation.workspace.name,
+                                participation.wsp_role
+                            );
                         }
-                        println!("Date Created:  {}", ce.date_created);
-                        println!("Last Updated:  {}", ce.last_updated);
-                        println!("Last Used:     {}", ce.last_used);
-                        if let Some(primary) = ce.primary {
-                            println!("Primary:       {}", primary);
+                    }
+                    Err(e) => {
+                        error!("Failed to list team workspaces: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            TeamCommands::RemoveFromWorkspace { team, org, workspace } => {
+                let org = resolve_org(&client, &org).await;
+                let team = resolve_team(&client, org.id.0, &team).await;
+                let workspace = resolve_workspace(&client, org.id.0, &workspace).await;
+                match client.remove_team_from_workspace(org.id.0, workspace.id.0, team.team_id).await {
+                    Ok(true) => println!("Removed team {} from workspace {}", team.name, workspace.name),
+                    Ok(false) => println!("Team {} is not a participant of workspace {}", team.name, workspace.name),
+                    Err(e) => {
+                        error!("Failed to remove team from workspace: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+        },
+        Commands::Participants(cmd) => match cmd {
+            ParticipantCommands::List { workspace } => {
+                let (org, workspace) = resolve_workspace_path(&client, &workspace).await;
+                match client.list_all_workspace_participants(org.id.0, workspace.id, 100).await {
+                    Ok(participants) => {
+                        println!("Found {} participants:", participants.len());
+                        for participant in participants {
+                            println!(
+                                "{:<8} {:<12} {:<32} {:<40} {}",
+                                participant.participant_id,
+                                participant.participant_type.to_string(),
+                                participant.name(),
+                                participant.email.as_deref().unwrap_or("-"),
+                                participant.wsp_role
+                            );
                         }
-                        println!("Org ID:        {}", ce.org_id);
-                        println!("Workspace ID:  {}", ce.workspace_id);
-                        
                     }
                     Err(e) => {
-                        error!("Failed to get compute environment: {}", e);
+                        error!("Failed to list participants: {}", e);
                         process::exit(1);
                     }
                 }
             },
-            ComputeEnvCommands::ValidateName { workspace_id, name } => {
-                match client.validate_compute_env_name(workspace_id, &name).await {
-                    Ok(_) => println!("Compute environment name '{}' is valid", name),
+            ParticipantCommands::Add { name, workspace, role, team } => {
+                let (org, workspace) = resolve_workspace_path(&client, &workspace).await;
+                let request = if team {
+                    let team = resolve_team(&client, org.id.0, &name).await;
+                    AddWorkspaceParticipantRequest::with_team_id(team.team_id)
+                } else {
+                    AddWorkspaceParticipantRequest::with_user_name_or_email(name)
+                };
+                match client.add_workspace_participant_with_role(org.id.0, workspace.id, request, role).await {
+                    Ok(participant) => println!(
+                        "Added {} to {} as {}",
+                        participant.name(),
+                        workspace.name,
+                        participant.wsp_role
+                    ),
                     Err(e) => {
-                        error!("Name validation failed: {}", e);
+                        error!("Failed to add participant: {}", e);
                         process::exit(1);
                     }
                 }
             },
-        }
-    }
-}
-
+            ParticipantCommands::UpdateRole { participant, role, workspace } => {
+                let (org, workspace) = resolve_workspace_path(&client, &workspace).await;
+                let participant = resolve_participant(&client, org.id.0, workspace.id.0, &participant).await;
+                match client
+                    .update_workspace_participant_role(org.id.0, workspace.id, participant.participant_id, role.clone())
+                    .await
+                {
+                    Ok(()) => println!(
+                        "Changed {} from {} to {} in {}",
+                        participant.name(),
+                        participant.wsp_role,
+                        role,
+                        workspace.name
+                    ),
+                    Err(e) => {
+                        error!("Failed to update participant role: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+            ParticipantCommands::Remove { participant, workspace } => {
+                let (org, workspace) = resolve_workspace_path(&client, &workspace).await;
+                let participant = resolve_participant(&client, org.id.0, workspace.id.0, &participant).await;
+                match client
+                    .delete_workspace_participant(org.id.0, workspace.id, participant.participant_id)
+                    .await
+                {
+                    Ok(()) => println!("Removed {} from {}", participant.name(), workspace.name),
+                    Err(e) => {
+                        error!("Failed to remove participant: {}", e);
+                        process::exit(1);
+                    }
+                }
+            },
+        },
+        Commands::Audit(cmd) => match cmd {
+            AuditCommands::Access { org, format } => {
+                let org = resolve_org(&client, &org).await;
+                let matrix = match client.get_access_matrix(org.id.0).await {
+                    Ok(matrix) => matrix,
+                    Err(e) => {
+                        error!("Failed to build access matrix: {}", e);
+                        process::exit(1);
+                    }
+                };
+                match format {
+                    OutputFormat::Table => print!("{}", matrix.to_table()),
+                    OutputFormat::Csv => print!("{}", matrix.to_csv()),
+                    OutputFormat::Json => match serde_json::to_string_pretty(&matrix) {
+                        Ok(json) => println!("{}", json),
+                        Err(e) => {
+                            error!("Failed to serialize access matrix: {}", e);
+                            process::exit(1);
+                        }
+                    },
+                }
+            },
+        },
+        Commands::Plan { file, prune } => {
+            let spec = read_org_spec(&file);
+            let (_, plan) = plan_org_spec(&client, &spec, prune).await;
+            print!("{}", plan);
+        },
+        Commands::Apply { file, prune, auto_approve } => {
+            let spec = read_org_spec(&file);
+            let (org, plan) = plan_org_spec(&client, &spec, prune).await;
+            print!("{}", plan);
+            if plan.is_empty() {
+                return;
+            }
+            println!();
+            let deletions = plan.count(ChangeKind::Delete);
+            if deletions > 0 && !auto_approve && !confirm_deletions(deletions) {
+                error!("Apply cancelled; pass --auto-approve to delete without confirmation");
+                process::exit(1);
+            }
+            match client.apply_org_plan(org.id, &plan).await {
+                Ok(report) => {
+                    for step in &report.steps {
+                        match &step.error {
+                            None => println!("  ok      {}", step.description),
+                            Some(e) => println!("  failed  {}: {}", step.description, e),
+                        }
+                    }
+                    if !report.is_complete() {
+                        error!("Apply failed; {} changes were not attempted", report.skipped);
+                        process::exit(1);
+                    }
+                    println!("Applied {} changes", report.steps.len());
+                }
+                Err(e) => {
+                    error!("Failed to apply: {}", e);
+                    process::exit(1);
+                }
+            }
+        },
+        Commands::Export { workspace, out, format } => {
+            let (org, workspace) = resolve_workspace_path(&client, &workspace).await;
+            let export = match client.export_workspace(org.id, workspace.id).await {
+                Ok(export) => export,
+                Err(e) => {
+                    error!("Failed to export {}/{}: {}", org.name, workspace.name, e);
+                    process::exit(1);
+                }
+            };
+            let files = match export.render(format) {
+                Ok(files) => files,
+                Err(e) => {
+                    error!("Failed to render export: {}", e);
+                    process::exit(1);
+                }
+            };
+            if let Err(e) = write_export(&out, &files) {
+                error!("Failed to write export to {}: {}", out.display(), e);
+                process::exit(1);
+            }
+            println!("Exported {}/{} to {} ({} files)", org.name, workspace.name, out.display(), files.len());
+        },
+        Commands::Migrate {
+            source,
+            target,
+            source_url,
+            target_url,
+            target_token_file,
+            mapping,
+            secrets_file,
+            dry_run,
+        } => {
+            let options = read_migration_options(mapping.as_deref(), secrets_file.as_deref(), dry_run);
+            let source_client = match &source_url {
+                Some(url) => client_for_url(url, read_token(cli.token_file.clone()).0, cli.verbose),
+                None => client,
+            };
+            let target_token = match target_token_file {
+                Some(path) => read_token(Some(path)).0,
+                None => read_token(cli.token_file.clone()).0,
+            };
+            let target_client = match &target_url {
+                Some(url) => client_for_url(url, target_token, cli.verbose),
+                None => source_client.with_token(target_token),
+            };
 
+            let (source_org, source_ws) = resolve_workspace_path(&source_client, &source).await;
+            let (target_org, target_ws) = resolve_workspace_path(&target_client, &target).await;
+            let report = match source_client
+                .migrate_workspace(source_org.id, source_ws.id, &target_client, target_org.id, target_ws.id, &options)
+                .await
+            {
+                Ok(report) => report,
+                Err(e) => {
+                    error!("Failed to migrate {} to {}: {}", source, target, e);
+                    process::exit(1);
+                }
+            };
 
+            if dry_run {
+                println!("Dry run, nothing was copied");
+            }
+            for resource in &report.resources {
+                println!("{}", resource);
+            }
+            println!();
+            print!("{}", report.summary());
+            if !report.is_complete() {
+                process::exit(1);
+            }
+        },
+    }
+}
@@ -0,0 +1,262 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use std::fmt;
+use std::str::FromStr;
+use super::common::{Validate, ValidationError, validation};
+use super::label::Label;
+
+/// What triggers an action: pushes to a GitHub repository, or calls to its
+/// Platform webhook
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ActionSource {
+    Github,
+    Tower,
+    /// A source this client doesn't know
+    #[serde(other)]
+    Unknown,
+}
+
+impl fmt::Display for ActionSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ActionSource::Github => write!(f, "github"),
+            ActionSource::Tower => write!(f, "tower"),
+            ActionSource::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl FromStr for ActionSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "github" => Ok(ActionSource::Github),
+            "tower" | "webhook" => Ok(ActionSource::Tower),
+            other => Err(format!("unknown action source '{}', expected github or tower", other)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ActionStatus {
+    Creating,
+    Active,
+    Error,
+    Paused,
+    /// A status this client doesn't know
+    #[serde(other)]
+    Unknown,
+}
+
+impl fmt::Display for ActionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ActionStatus::Creating => write!(f, "CREATING"),
+            ActionStatus::Active => write!(f, "ACTIVE"),
+            ActionStatus::Error => write!(f, "ERROR"),
+            ActionStatus::Paused => write!(f, "PAUSED"),
+            ActionStatus::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+/// The launch configuration an action runs with
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ActionLaunch {
+    #[serde(rename = "computeEnvId", skip_serializing_if = "Option::is_none")]
+    pub compute_env_id: Option<String>,
+    /// Pipeline repository URL
+    pub pipeline: String,
+    #[serde(rename = "workDir", skip_serializing_if = "Option::is_none")]
+    pub work_dir: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revision: Option<String>,
+    /// Pipeline parameters as JSON or YAML text
+    #[serde(rename = "paramsText", skip_serializing_if = "Option::is_none")]
+    pub params_text: Option<String>,
+    #[serde(rename = "configProfiles", skip_serializing_if = "Vec::is_empty")]
+    pub config_profiles: Vec<String>,
+    #[serde(rename = "configText", skip_serializing_if = "Option::is_none")]
+    pub config_text: Option<String>,
+    #[serde(rename = "preRunScript", skip_serializing_if = "Option::is_none")]
+    pub pre_run_script: Option<String>,
+    #[serde(rename = "postRunScript", skip_serializing_if = "Option::is_none")]
+    pub post_run_script: Option<String>,
+    #[serde(rename = "pullLatest", skip_serializing_if = "Option::is_none")]
+    pub pull_latest: Option<bool>,
+    #[serde(rename = "labelIds", skip_serializing_if = "Vec::is_empty")]
+    pub label_ids: Vec<i64>,
+    /// Launch fields not modelled above, e.g. `mainScript` or `resume`,
+    /// passed through as they are
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+/// An action as returned when listing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionSummary {
+    pub id: String,
+    pub name: String,
+    pub pipeline: Option<String>,
+    pub source: ActionSource,
+    pub status: ActionStatus,
+    #[serde(rename = "lastSeen")]
+    pub last_seen: Option<DateTime<Utc>>,
+    #[serde(rename = "dateCreated")]
+    pub date_created: Option<DateTime<Utc>>,
+    /// The hook URL of Tower webhook actions
+    pub endpoint: Option<String>,
+    #[serde(default)]
+    pub labels: Vec<Label>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListActionsResponse {
+    pub actions: Vec<ActionSummary>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Action {
+    pub id: String,
+    pub name: String,
+    pub source: ActionSource,
+    pub status: ActionStatus,
+    pub message: Option<String>,
+    #[serde(rename = "hookId")]
+    pub hook_id: Option<String>,
+    /// URL to POST to in order to trigger a Tower webhook action
+    #[serde(rename = "hookUrl")]
+    pub hook_url: Option<String>,
+    pub launch: ActionLaunch,
+    #[serde(rename = "lastSeen")]
+    pub last_seen: Option<DateTime<Utc>>,
+    #[serde(rename = "dateCreated")]
+    pub date_created: Option<DateTime<Utc>>,
+    #[serde(rename = "lastUpdated")]
+    pub last_updated: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub labels: Vec<Label>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DescribeActionResponse {
+    pub action: Action,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateActionRequest {
+    pub name: String,
+    pub source: ActionSource,
+    pub launch: ActionLaunch,
+}
+
+impl CreateActionRequest {
+    /// An action launched on every push to the pipeline's GitHub repository
+    pub fn github(name: impl Into<String>, launch: ActionLaunch) -> Self {
+        Self { name: name.into(), source: ActionSource::Github, launch }
+    }
+
+    /// An action launched by calls to its Platform webhook
+    pub fn tower(name: impl Into<String>, launch: ActionLaunch) -> Self {
+        Self { name: name.into(), source: ActionSource::Tower, launch }
+    }
+}
+
+impl Validate for CreateActionRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate_action_name(&self.name)?;
+        validation::validate_required(&self.launch.pipeline, "pipeline")
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateActionResponse {
+    #[serde(rename = "actionId")]
+    pub action_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateActionRequest {
+    pub name: String,
+    pub launch: ActionLaunch,
+}
+
+impl Validate for UpdateActionRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate_action_name(&self.name)?;
+        validation::validate_required(&self.launch.pipeline, "pipeline")
+    }
+}
+
+/// Body of a webhook trigger
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LaunchActionRequest {
+    pub params: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LaunchActionResponse {
+    #[serde(rename = "workflowId")]
+    pub workflow_id: String,
+}
+
+fn validate_action_name(name: &str) -> Result<(), ValidationError> {
+    validation::validate_required(name, "name")?;
+    validation::validate_max_length(name, 100, "name")?;
+    lazy_static::lazy_static! {
+        static ref NAME_PATTERN: regex::Regex = regex::Regex::new(
+            r"^[a-zA-Z\d](?:[-_a-zA-Z\d]*[a-zA-Z\d])?$"
+        ).unwrap();
+    }
+    validation::validate_pattern(name, &NAME_PATTERN, "name")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_unknown_source_and_status() {
+        let summary: ActionSummary = serde_json::from_value(json!({
+            "id": "act-1",
+            "name": "nightly",
+            "source": "gitlab",
+            "status": "SUSPENDED"
+        }))
+        .unwrap();
+        assert_eq!(summary.source, ActionSource::Unknown);
+        assert_eq!(summary.status, ActionStatus::Unknown);
+    }
+
+    #[test]
+    fn test_create_action_request() {
+        let launch = ActionLaunch {
+            compute_env_id: Some("ce-1".to_string()),
+            pipeline: "https://github.com/nf-core/rnaseq".to_string(),
+            revision: Some("3.14.0".to_string()),
+            ..Default::default()
+        };
+        let request = CreateActionRequest::tower("rnaseq-ci", launch);
+        assert!(request.validate().is_ok());
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            json!({
+                "name": "rnaseq-ci",
+                "source": "tower",
+                "launch": {
+                    "computeEnvId": "ce-1",
+                    "pipeline": "https://github.com/nf-core/rnaseq",
+                    "revision": "3.14.0"
+                }
+            })
+        );
+
+        let invalid = CreateActionRequest::github("bad name", ActionLaunch::default());
+        assert!(invalid.validate().is_err());
+    }
+}
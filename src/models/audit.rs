@@ -0,0 +1,304 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use super::common::{OrgRole, ParticipantType, WspRole};
+use super::member::{Member, Participant};
+
+/// How a user came to have a role in a workspace
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "name", rename_all = "camelCase")]
+pub enum AccessSource {
+    /// The user is a participant in their own right
+    Direct,
+    /// Through membership of the named team
+    Team(String),
+    /// Organization owners have full access to every workspace
+    OrgOwner,
+}
+
+impl fmt::Display for AccessSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AccessSource::Direct => write!(f, "direct"),
+            AccessSource::Team(name) => write!(f, "team {}", name),
+            AccessSource::OrgOwner => write!(f, "organization owner"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AccessGrant {
+    pub role: WspRole,
+    pub source: AccessSource,
+}
+
+/// A user's access to one workspace: the highest of all the roles granted
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkspaceAccess {
+    #[serde(rename = "effectiveRole")]
+    pub effective_role: WspRole,
+    pub grants: Vec<AccessGrant>,
+}
+
+impl WorkspaceAccess {
+    fn grant(&mut self, grant: AccessGrant) {
+        if grant.role > self.effective_role {
+            self.effective_role = grant.role.clone();
+        }
+        self.grants.push(grant);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserAccess {
+    #[serde(rename = "userName")]
+    pub user_name: String,
+    pub email: Option<String>,
+    /// Access by workspace name; workspaces without access are left out
+    pub workspaces: BTreeMap<String, WorkspaceAccess>,
+}
+
+/// Effective workspace roles of every user in an organization
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AccessMatrix {
+    pub organization: String,
+    pub workspaces: Vec<String>,
+    /// Sorted by user name
+    pub users: Vec<UserAccess>,
+}
+
+/// The participants of one workspace, as input to [`AccessMatrix::build`]
+#[derive(Debug)]
+pub struct WorkspaceParticipants {
+    pub workspace: String,
+    pub participants: Vec<Participant>,
+}
+
+impl AccessMatrix {
+    /// Builds the matrix from each workspace's participants, the members of
+    /// the teams among them (by team ID), and the organization members, whose
+    /// owners get the owner role everywhere.
+    pub fn build(
+        organization: impl Into<String>,
+        org_members: &[Member],
+        workspaces: &[WorkspaceParticipants],
+        team_members: &HashMap<i64, Vec<Member>>,
+    ) -> Self {
+        let mut users: BTreeMap<String, UserAccess> = BTreeMap::new();
+        let mut grant = |user_name: &str, email: Option<&str>, workspace: &str, grant: AccessGrant| {
+            let user = users.entry(user_name.to_string()).or_insert_with(|| UserAccess {
+                user_name: user_name.to_string(),
+                email: None,
+                workspaces: BTreeMap::new(),
+            });
+            if user.email.is_none() {
+                user.email = email.map(str::to_string);
+            }
+            match user.workspaces.get_mut(workspace) {
+                Some(access) => access.grant(grant),
+                None => {
+                    let access = WorkspaceAccess { effective_role: grant.role.clone(), grants: vec![grant] };
+                    user.workspaces.insert(workspace.to_string(), access);
+                }
+            }
+        };
+
+        for entry in workspaces {
+            for participant in &entry.participants {
+                match participant.participant_type {
+                    ParticipantType::Team => {
+                        let team_name = participant.team_name.clone().unwrap_or_default();
+                        let members = participant.team_id.and_then(|id| team_members.get(&id));
+                        for member in members.into_iter().flatten() {
+                            grant(&member.user_name, Some(&member.email), &entry.workspace, AccessGrant {
+                                role: participant.wsp_role.clone(),
+                                source: AccessSource::Team(team_name.clone()),
+                            });
+                        }
+                    }
+                    _ => {
+                        if let Some(user_name) = &participant.user_name {
+                            grant(user_name, participant.email.as_deref(), &entry.workspace, AccessGrant {
+                                role: participant.wsp_role.clone(),
+                                source: AccessSource::Direct,
+                            });
+                        }
+                    }
+                }
+            }
+            for owner in org_members.iter().filter(|m| m.org_role() == Some(OrgRole::Owner)) {
+                grant(&owner.user_name, Some(&owner.email), &entry.workspace, AccessGrant {
+                    role: WspRole::Owner,
+                    source: AccessSource::OrgOwner,
+                });
+            }
+        }
+
+        let mut workspace_names: Vec<String> = workspaces.iter().map(|w| w.workspace.clone()).collect();
+        workspace_names.sort();
+        Self {
+            organization: organization.into(),
+            workspaces: workspace_names,
+            users: users.into_values().collect(),
+        }
+    }
+
+    pub fn effective_role(&self, user_name: &str, workspace: &str) -> Option<&WspRole> {
+        self.users
+            .iter()
+            .find(|u| u.user_name == user_name)
+            .and_then(|u| u.workspaces.get(workspace))
+            .map(|access| &access.effective_role)
+    }
+
+    /// One row per user and one column per workspace; cells without access are empty
+    pub fn to_csv(&self) -> String {
+        let mut out = String::new();
+        let header: Vec<String> = ["user", "email"]
+            .into_iter()
+            .map(str::to_string)
+            .chain(self.workspaces.iter().cloned())
+            .collect();
+        push_csv_row(&mut out, &header);
+        for user in &self.users {
+            let row: Vec<String> = [user.user_name.clone(), user.email.clone().unwrap_or_default()]
+                .into_iter()
+                .chain(self.workspaces.iter().map(|w| self.role_label(user, w)))
+                .collect();
+            push_csv_row(&mut out, &row);
+        }
+        out
+    }
+
+    /// An aligned text table; cells without access show `-`
+    pub fn to_table(&self) -> String {
+        let mut rows = vec![
+            std::iter::once("USER".to_string()).chain(self.workspaces.iter().cloned()).collect::<Vec<_>>(),
+        ];
+        for user in &self.users {
+            rows.push(
+                std::iter::once(user.user_name.clone())
+                    .chain(self.workspaces.iter().map(|w| {
+                        let label = self.role_label(user, w);
+                        if label.is_empty() { "-".to_string() } else { label }
+                    }))
+                    .collect(),
+            );
+        }
+
+        let widths: Vec<usize> = (0..rows[0].len())
+            .map(|col| rows.iter().map(|row| row[col].len()).max().unwrap_or(0))
+            .collect();
+        let mut out = String::new();
+        for row in rows {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect();
+            out.push_str(cells.join("  ").trim_end());
+            out.push('\n');
+        }
+        out
+    }
+
+    fn role_label(&self, user: &UserAccess, workspace: &str) -> String {
+        user.workspaces
+            .get(workspace)
+            .map(|access| access.effective_role.to_string())
+            .unwrap_or_default()
+    }
+}
+
+fn push_csv_row(out: &mut String, fields: &[String]) {
+    let escaped: Vec<String> = fields
+        .iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.clone()
+            }
+        })
+        .collect();
+    out.push_str(&escaped.join(","));
+    out.push('\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::member::fixtures::member;
+    use serde_json::json;
+
+    fn participant(value: serde_json::Value) -> Participant {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn matrix() -> AccessMatrix {
+        let workspaces = vec![
+            WorkspaceParticipants {
+                workspace: "production".to_string(),
+                participants: vec![
+                    participant(json!({
+                        "participantId": 1, "userName": "alice", "email": "alice@example.com",
+                        "wspRole": "launch", "type": "MEMBER"
+                    })),
+                    participant(json!({
+                        "participantId": 2, "teamId": 7, "teamName": "bioinfo",
+                        "wspRole": "maintain", "type": "TEAM"
+                    })),
+                ],
+            },
+            WorkspaceParticipants {
+                workspace: "dev".to_string(),
+                participants: vec![participant(json!({
+                    "participantId": 3, "userName": "carol", "wspRole": "view", "type": "COLLABORATOR"
+                }))],
+            },
+        ];
+        let team_members = HashMap::from([(7, vec![member(2, "alice", "member"), member(3, "bob", "member")])]);
+        let org_members = vec![member(1, "olivia", "OWNER"), member(2, "alice", "member")];
+        AccessMatrix::build("acme", &org_members, &workspaces, &team_members)
+    }
+
+    #[test]
+    fn test_effective_role_is_highest_grant() {
+        let matrix = matrix();
+        assert_eq!(matrix.workspaces, vec!["dev", "production"]);
+        assert_eq!(matrix.effective_role("alice", "production"), Some(&WspRole::Maintain));
+        assert_eq!(matrix.effective_role("bob", "production"), Some(&WspRole::Maintain));
+        assert_eq!(matrix.effective_role("bob", "dev"), None);
+        assert_eq!(matrix.effective_role("carol", "dev"), Some(&WspRole::View));
+        assert_eq!(matrix.effective_role("olivia", "dev"), Some(&WspRole::Owner));
+
+        let alice = &matrix.users[0].workspaces["production"];
+        assert_eq!(alice.grants.len(), 2);
+        assert_eq!(alice.grants[1].source, AccessSource::Team("bioinfo".to_string()));
+    }
+
+    #[test]
+    fn test_render_csv_and_table() {
+        let matrix = matrix();
+        assert_eq!(
+            matrix.to_csv(),
+            "user,email,dev,production\n\
+             alice,alice@example.com,,maintain\n\
+             bob,bob@example.com,,maintain\n\
+             carol,,view,\n\
+             olivia,olivia@example.com,owner,owner\n"
+        );
+        let table = matrix.to_table();
+        assert!(table.starts_with("USER    dev    production\n"));
+        assert!(table.contains("bob     -      maintain\n"));
+    }
+
+    #[test]
+    fn test_json_sources() {
+        let value = serde_json::to_value(matrix()).unwrap();
+        assert_eq!(
+            value["users"][0]["workspaces"]["production"]["grants"][1]["source"],
+            json!({ "type": "team", "name": "bioinfo" })
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::str::FromStr;
 
 /// Strongly typed organization ID
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -35,8 +36,45 @@ impl From<i64> for WorkspaceId {
     }
 }
 
-/// Role within an organization
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+/// A sensitive value such as a password, token or key.
+///
+/// Serializes as a plain string but never reveals its contents in `Debug` output.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Secret(String);
+
+impl Secret {
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    /// Returns the underlying secret value
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Secret(***)")
+    }
+}
+
+impl From<String> for Secret {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for Secret {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+/// Role within an organization. Roles order by privilege, from
+/// `Collaborator` up to `Owner`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 #[serde(rename_all = "lowercase")]
 pub enum OrgRole {
     Owner,
@@ -44,8 +82,76 @@ pub enum OrgRole {
     Collaborator,
 }
 
-/// Role within a workspace
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+impl fmt::Display for OrgRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OrgRole::Owner => write!(f, "owner"),
+            OrgRole::Member => write!(f, "member"),
+            OrgRole::Collaborator => write!(f, "collaborator"),
+        }
+    }
+}
+
+impl OrgRole {
+    fn rank(&self) -> u8 {
+        match self {
+            OrgRole::Collaborator => 0,
+            OrgRole::Member => 1,
+            OrgRole::Owner => 2,
+        }
+    }
+
+    /// Whether the organization role alone allows `action` in any workspace.
+    /// Only owners have access to workspaces they don't participate in.
+    pub fn allows(&self, action: Action) -> bool {
+        self.workspace_role().is_some_and(|role| role.allows(action))
+    }
+
+    /// The workspace role the organization role implies in every workspace
+    pub fn workspace_role(&self) -> Option<WspRole> {
+        match self {
+            OrgRole::Owner => Some(WspRole::Owner),
+            OrgRole::Member | OrgRole::Collaborator => None,
+        }
+    }
+
+    /// The role a user with this organization role and `participant_role`
+    /// in a workspace effectively has there
+    pub fn effective_workspace_role(&self, participant_role: Option<WspRole>) -> Option<WspRole> {
+        self.workspace_role().into_iter().chain(participant_role).max()
+    }
+}
+
+impl PartialOrd for OrgRole {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrgRole {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
+impl FromStr for OrgRole {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "owner" => Ok(OrgRole::Owner),
+            "member" => Ok(OrgRole::Member),
+            "collaborator" => Ok(OrgRole::Collaborator),
+            other => Err(format!(
+                "unknown organization role '{}', expected owner, member or collaborator",
+                other
+            )),
+        }
+    }
+}
+
+/// Role within a workspace. Roles order by privilege, from `View` up to `Owner`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 #[serde(rename_all = "lowercase")]
 pub enum WspRole {
     Owner,
@@ -56,6 +162,145 @@ pub enum WspRole {
     View,
 }
 
+impl fmt::Display for WspRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WspRole::Owner => write!(f, "owner"),
+            WspRole::Admin => write!(f, "admin"),
+            WspRole::Maintain => write!(f, "maintain"),
+            WspRole::Launch => write!(f, "launch"),
+            WspRole::Connect => write!(f, "connect"),
+            WspRole::View => write!(f, "view"),
+        }
+    }
+}
+
+impl WspRole {
+    /// The least privileged role that allows `action`
+    pub fn minimum_for(action: Action) -> WspRole {
+        match action {
+            Action::Launch => WspRole::Launch,
+            Action::EditPipelines => WspRole::Maintain,
+            Action::ManageComputeEnvs | Action::ManageCredentials | Action::ManageParticipants => {
+                WspRole::Admin
+            }
+        }
+    }
+
+    pub fn allows(&self, action: Action) -> bool {
+        *self >= Self::minimum_for(action)
+    }
+
+    /// Fails with [`PermissionDenied`] unless the role allows `action`
+    pub fn require(&self, action: Action) -> Result<(), PermissionDenied> {
+        require_role(Some(self.clone()), action).map(|_| ())
+    }
+
+    fn rank(&self) -> u8 {
+        match self {
+            WspRole::View => 0,
+            WspRole::Connect => 1,
+            WspRole::Launch => 2,
+            WspRole::Maintain => 3,
+            WspRole::Admin => 4,
+            WspRole::Owner => 5,
+        }
+    }
+}
+
+impl PartialOrd for WspRole {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for WspRole {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
+impl FromStr for WspRole {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "owner" => Ok(WspRole::Owner),
+            "admin" => Ok(WspRole::Admin),
+            "maintain" => Ok(WspRole::Maintain),
+            "launch" => Ok(WspRole::Launch),
+            "connect" => Ok(WspRole::Connect),
+            "view" => Ok(WspRole::View),
+            other => Err(format!(
+                "unknown workspace role '{}', expected owner, admin, maintain, launch, connect or view",
+                other
+            )),
+        }
+    }
+}
+
+/// Something a workspace role may allow
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Launch,
+    EditPipelines,
+    ManageComputeEnvs,
+    ManageCredentials,
+    ManageParticipants,
+}
+
+impl Action {
+    pub const ALL: [Action; 5] = [
+        Action::Launch,
+        Action::EditPipelines,
+        Action::ManageComputeEnvs,
+        Action::ManageCredentials,
+        Action::ManageParticipants,
+    ];
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Action::Launch => write!(f, "launch pipelines"),
+            Action::EditPipelines => write!(f, "edit pipelines"),
+            Action::ManageComputeEnvs => write!(f, "manage compute environments"),
+            Action::ManageCredentials => write!(f, "manage credentials"),
+            Action::ManageParticipants => write!(f, "manage participants"),
+        }
+    }
+}
+
+/// A role that doesn't allow an action
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+#[error("Not permitted to {action}: requires the {required} role, but you have {}", describe_role(.role))]
+pub struct PermissionDenied {
+    pub action: Action,
+    /// `None` when there is no access to the workspace at all
+    pub role: Option<WspRole>,
+    pub required: WspRole,
+}
+
+fn describe_role(role: &Option<WspRole>) -> String {
+    match role {
+        Some(role) => format!("the {} role", role),
+        None => "no access to the workspace".to_string(),
+    }
+}
+
+/// Returns `role` if it allows `action`, and fails with [`PermissionDenied`]
+/// otherwise; `None` stands for no access to the workspace at all
+pub fn require_role(role: Option<WspRole>, action: Action) -> Result<WspRole, PermissionDenied> {
+    match role {
+        Some(role) if role.allows(action) => Ok(role),
+        role => Err(PermissionDenied {
+            action,
+            role,
+            required: WspRole::minimum_for(action),
+        }),
+    }
+}
+
 /// Type of participant in a workspace
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "UPPERCASE")]
@@ -65,6 +310,16 @@ pub enum ParticipantType {
     Collaborator,
 }
 
+impl fmt::Display for ParticipantType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParticipantType::Member => write!(f, "member"),
+            ParticipantType::Team => write!(f, "team"),
+            ParticipantType::Collaborator => write!(f, "collaborator"),
+        }
+    }
+}
+
 /// Validation errors for field constraints
 #[derive(Debug, thiserror::Error)]
 pub enum ValidationError {
@@ -74,6 +329,10 @@ pub enum ValidationError {
     Pattern { field: &'static str },
     #[error("Field '{field}' is required")]
     Required { field: &'static str },
+    #[error("Field '{field}' is invalid: {reason}")]
+    Invalid { field: &'static str, reason: &'static str },
+    #[error("Row {row} has {found} columns, expected {expected}")]
+    ColumnCount { row: usize, expected: usize, found: usize },
 }
 
 /// Validation trait for request types
@@ -97,6 +356,14 @@ pub mod validation {
         }
     }
 
+    pub fn validate_required(value: &str, field: &'static str) -> Result<(), ValidationError> {
+        if value.trim().is_empty() {
+            Err(ValidationError::Required { field })
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn validate_pattern(
         value: &str,
         pattern: &regex::Regex,
@@ -115,6 +382,47 @@ mod tests {
     use super::*;
     use regex::Regex;
 
+    #[test]
+    fn test_role_ordering() {
+        assert!(WspRole::Owner > WspRole::Admin);
+        assert!(WspRole::Launch > WspRole::Connect);
+        assert_eq!([WspRole::View, WspRole::Maintain, WspRole::Launch].into_iter().max(), Some(WspRole::Maintain));
+        assert!(OrgRole::Owner > OrgRole::Member && OrgRole::Member > OrgRole::Collaborator);
+    }
+
+    #[test]
+    fn test_role_allows() {
+        assert!(!WspRole::Connect.allows(Action::Launch));
+        assert!(WspRole::Launch.allows(Action::Launch));
+        assert!(!WspRole::Launch.allows(Action::EditPipelines));
+        assert!(WspRole::Maintain.allows(Action::EditPipelines));
+        assert!(!WspRole::Maintain.allows(Action::ManageComputeEnvs));
+        assert!(Action::ALL.iter().all(|a| WspRole::Admin.allows(*a)));
+
+        assert!(Action::ALL.iter().all(|a| OrgRole::Owner.allows(*a)));
+        assert!(!OrgRole::Member.allows(Action::Launch));
+        assert_eq!(
+            OrgRole::Owner.effective_workspace_role(Some(WspRole::View)),
+            Some(WspRole::Owner)
+        );
+        assert_eq!(OrgRole::Member.effective_workspace_role(None), None);
+    }
+
+    #[test]
+    fn test_require_role_message() {
+        assert!(WspRole::Admin.require(Action::ManageCredentials).is_ok());
+        let error = WspRole::Launch.require(Action::ManageCredentials).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Not permitted to manage credentials: requires the admin role, but you have the launch role"
+        );
+        let error = require_role(None, Action::Launch).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Not permitted to launch pipelines: requires the launch role, but you have no access to the workspace"
+        );
+    }
+
     #[test]
     fn test_max_length_validation() {
         assert!(validation::validate_max_length("test", 5, "field").is_ok());
@@ -143,4 +451,12 @@ mod tests {
         assert!(err.to_string().contains("test"));
         assert!(err.to_string().contains("pattern"));
     }
+
+    #[test]
+    fn test_secret_debug_is_redacted() {
+        let secret = Secret::new("super-secret");
+        assert_eq!(format!("{:?}", secret), "Secret(***)");
+        assert_eq!(secret.expose(), "super-secret");
+        assert_eq!(serde_json::to_string(&secret).unwrap(), "\"super-secret\"");
+    }
 } 
\ No newline at end of file
@@ -1,10 +1,16 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 use chrono::{DateTime, Utc};
 use std::fmt;
+use super::common::{Validate, ValidationError, validation};
 // Platform-specific configurations will be in separate files
+mod builder;
 mod config;
+pub use builder::*;
 pub use config::*;
-#[derive(Debug, Serialize, Deserialize)]
+pub use super::label::Label;
+use super::platform::PlatformId;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum ComputeEnvStatus {
     Creating,
@@ -24,23 +30,7 @@ impl fmt::Display for ComputeEnvStatus {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-pub enum Platform {
-    AwsBatch,
-    GoogleLifesciences,
-    GoogleBatch,
-    AzureBatch,
-    K8sPlatform,
-    EksPlatform,
-    GkePlatform,
-    UgePlatform,
-    SlurmPlatform,
-    LsfPlatform,
-    AltairPlatform,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EnvVar {
     pub name: String,
     pub value: String,
@@ -48,18 +38,9 @@ pub struct EnvVar {
     pub compute: bool,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct Label {
-    pub id: i64,
-    pub name: String,
-    pub value: String,
-    pub resource: bool,
-    #[serde(rename = "isDefault")]
-    pub is_default: bool,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "discriminator")]
+#[allow(clippy::large_enum_variant)]
 pub enum ComputeConfig {
     #[serde(rename = "aws-batch")]
     AwsBatch(AwsBatchConfig),
@@ -87,19 +68,82 @@ pub enum ComputeConfig {
     Moab(MoabConfig),
 }
 
+impl Validate for ComputeConfig {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            ComputeConfig::AwsBatch(config) => config.validate(),
+            ComputeConfig::GoogleLifeSciences(config) => config.validate(),
+            ComputeConfig::GoogleBatch(config) => config.validate(),
+            ComputeConfig::AzureBatch(config) => config.validate(),
+            ComputeConfig::Lsf(config) => config.base.validate(),
+            ComputeConfig::Slurm(config) => config.base.validate(),
+            ComputeConfig::Kubernetes(config) => config.validate(),
+            ComputeConfig::Eks(config) => config.validate(),
+            ComputeConfig::Gke(config) => config.validate(),
+            ComputeConfig::Uge(config) => config.base.validate(),
+            ComputeConfig::Altair(config) => config.base.validate(),
+            ComputeConfig::Moab(config) => config.base.validate(),
+        }
+    }
+}
+
+impl ComputeConfig {
+    /// The platform this configuration targets
+    pub fn platform(&self) -> PlatformId {
+        match self {
+            ComputeConfig::AwsBatch(_) => PlatformId::AwsBatch,
+            ComputeConfig::GoogleLifeSciences(_) => PlatformId::GoogleLifeSciences,
+            ComputeConfig::GoogleBatch(_) => PlatformId::GoogleBatch,
+            ComputeConfig::AzureBatch(_) => PlatformId::AzureBatch,
+            ComputeConfig::Lsf(_) => PlatformId::Lsf,
+            ComputeConfig::Slurm(_) => PlatformId::Slurm,
+            ComputeConfig::Kubernetes(_) => PlatformId::Kubernetes,
+            ComputeConfig::Eks(_) => PlatformId::Eks,
+            ComputeConfig::Gke(_) => PlatformId::Gke,
+            ComputeConfig::Uge(_) => PlatformId::Uge,
+            ComputeConfig::Altair(_) => PlatformId::Altair,
+            ComputeConfig::Moab(_) => PlatformId::Moab,
+        }
+    }
+
+    /// Whether the configuration turns on Fusion v2
+    pub fn uses_fusion(&self) -> bool {
+        match self {
+            ComputeConfig::AwsBatch(config) => config.fusion2_enabled,
+            ComputeConfig::GoogleBatch(config) => config.fusion2_enabled,
+            ComputeConfig::AzureBatch(config) => config.fusion2_enabled,
+            ComputeConfig::Eks(config) => config.fusion2_enabled,
+            ComputeConfig::Gke(config) => config.fusion2_enabled,
+            _ => false,
+        }
+    }
+
+    /// Whether the configuration turns on Wave containers
+    pub fn uses_wave(&self) -> bool {
+        match self {
+            ComputeConfig::AwsBatch(config) => config.wave_enabled,
+            ComputeConfig::GoogleBatch(config) => config.wave_enabled,
+            ComputeConfig::AzureBatch(config) => config.wave_enabled,
+            ComputeConfig::Eks(config) => config.wave_enabled,
+            ComputeConfig::Gke(config) => config.wave_enabled,
+            _ => false,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ComputeEnvDetails {
     pub id: String,
     pub name: String,
     pub description: Option<String>,
-    pub platform: Platform,
+    pub platform: PlatformId,
     pub config: ComputeConfig,
     #[serde(rename = "dateCreated")]
     pub date_created: DateTime<Utc>,
     #[serde(rename = "lastUpdated")]
     pub last_updated: DateTime<Utc>,
     #[serde(rename = "lastUsed")]
-    pub last_used: DateTime<Utc>,
+    pub last_used: Option<DateTime<Utc>>,
     pub deleted: Option<bool>,
     pub status: ComputeEnvStatus,
     pub message: Option<String>,
@@ -112,7 +156,7 @@ pub struct ComputeEnvDetails {
     pub org_id: i64,
     #[serde(rename = "workspaceId")]
     pub workspace_id: i64,
-    // pub labels: Option<Vec<Label>>,
+    pub labels: Option<Vec<Label>>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -121,13 +165,19 @@ pub struct GetComputeEnvResponse {
     pub compute_env: ComputeEnvDetails,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetPrimaryComputeEnvResponse {
+    #[serde(rename = "computeEnv")]
+    pub compute_env: Option<ComputeEnvDetails>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ComputeEnv {
     #[serde(rename = "credentialsId")] 
     pub credentials_id: String,
     pub id: String,
     pub name: String,
-    pub platform: String,
+    pub platform: PlatformId,
     pub region: Option<String>,
     pub status: ComputeEnvStatus,
     pub visibility: String,
@@ -139,6 +189,7 @@ pub struct ComputeEnv {
     #[serde(rename = "lastUsed")]
     pub last_used: Option<DateTime<Utc>>,
     pub primary: Option<bool>,
+    pub labels: Option<Vec<Label>>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -154,3 +205,95 @@ pub struct UpdateComputeEnvRequest {
     pub credentials_id: String,
 }
 
+/// A compute environment to be created. The `platform` sent to the API is
+/// derived from the configuration.
+#[derive(Debug, Deserialize)]
+pub struct NewComputeEnv {
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(rename = "credentialsId")]
+    pub credentials_id: String,
+    pub config: ComputeConfig,
+}
+
+impl Serialize for NewComputeEnv {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Wire<'a> {
+            name: &'a str,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            description: &'a Option<String>,
+            platform: PlatformId,
+            #[serde(rename = "credentialsId")]
+            credentials_id: &'a str,
+            config: &'a ComputeConfig,
+        }
+
+        Wire {
+            name: &self.name,
+            description: &self.description,
+            platform: self.config.platform(),
+            credentials_id: &self.credentials_id,
+            config: &self.config,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateComputeEnvRequest {
+    #[serde(rename = "computeEnv")]
+    pub compute_env: NewComputeEnv,
+    #[serde(rename = "labelIds", default, skip_serializing_if = "Vec::is_empty")]
+    pub label_ids: Vec<i64>,
+}
+
+impl CreateComputeEnvRequest {
+    pub fn new(
+        name: impl Into<String>,
+        credentials_id: impl Into<String>,
+        config: impl Into<ComputeConfig>,
+    ) -> Self {
+        Self {
+            compute_env: NewComputeEnv {
+                name: name.into(),
+                description: None,
+                credentials_id: credentials_id.into(),
+                config: config.into(),
+            },
+            label_ids: Vec::new(),
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.compute_env.description = Some(description.into());
+        self
+    }
+
+    pub fn label_ids(mut self, label_ids: Vec<i64>) -> Self {
+        self.label_ids = label_ids;
+        self
+    }
+}
+
+impl Validate for CreateComputeEnvRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validation::validate_max_length(&self.compute_env.name, 100, "name")?;
+        lazy_static::lazy_static! {
+            static ref NAME_PATTERN: regex::Regex = regex::Regex::new(
+                r"^[a-zA-Z\d](?:[-_a-zA-Z\d]*[a-zA-Z\d])?$"
+            ).unwrap();
+        }
+        validation::validate_pattern(&self.compute_env.name, &NAME_PATTERN, "name")?;
+        if let Some(ref desc) = self.compute_env.description {
+            validation::validate_max_length(desc, 2000, "description")?;
+        }
+        self.compute_env.config.validate()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateComputeEnvResponse {
+    #[serde(rename = "computeEnvId")]
+    pub compute_env_id: String,
+}
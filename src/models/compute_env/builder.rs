@@ -0,0 +1,832 @@
+use super::{ComputeConfig, EnvVar};
+use super::config::*;
+use crate::models::common::{Validate, ValidationError};
+
+impl From<AwsBatchConfig> for ComputeConfig {
+    fn from(config: AwsBatchConfig) -> Self {
+        ComputeConfig::AwsBatch(config)
+    }
+}
+
+impl From<GoogleLifeSciencesConfig> for ComputeConfig {
+    fn from(config: GoogleLifeSciencesConfig) -> Self {
+        ComputeConfig::GoogleLifeSciences(config)
+    }
+}
+
+impl From<GoogleBatchConfig> for ComputeConfig {
+    fn from(config: GoogleBatchConfig) -> Self {
+        ComputeConfig::GoogleBatch(config)
+    }
+}
+
+impl From<AzureBatchConfig> for ComputeConfig {
+    fn from(config: AzureBatchConfig) -> Self {
+        ComputeConfig::AzureBatch(config)
+    }
+}
+
+impl From<LsfConfig> for ComputeConfig {
+    fn from(config: LsfConfig) -> Self {
+        ComputeConfig::Lsf(config)
+    }
+}
+
+impl From<SlurmConfig> for ComputeConfig {
+    fn from(config: SlurmConfig) -> Self {
+        ComputeConfig::Slurm(config)
+    }
+}
+
+impl From<KubernetesConfig> for ComputeConfig {
+    fn from(config: KubernetesConfig) -> Self {
+        ComputeConfig::Kubernetes(config)
+    }
+}
+
+impl From<EksConfig> for ComputeConfig {
+    fn from(config: EksConfig) -> Self {
+        ComputeConfig::Eks(config)
+    }
+}
+
+impl From<GkeConfig> for ComputeConfig {
+    fn from(config: GkeConfig) -> Self {
+        ComputeConfig::Gke(config)
+    }
+}
+
+impl From<UgeConfig> for ComputeConfig {
+    fn from(config: UgeConfig) -> Self {
+        ComputeConfig::Uge(config)
+    }
+}
+
+impl From<AltairConfig> for ComputeConfig {
+    fn from(config: AltairConfig) -> Self {
+        ComputeConfig::Altair(config)
+    }
+}
+
+impl From<MoabConfig> for ComputeConfig {
+    fn from(config: MoabConfig) -> Self {
+        ComputeConfig::Moab(config)
+    }
+}
+
+/// Setters for the fields every platform shares through `BaseConfig`.
+/// Takes the field path from the builder to its `BaseConfig`.
+macro_rules! base_config_setters {
+    ($($path:ident).+) => {
+        pub fn work_dir(mut self, work_dir: impl Into<String>) -> Self {
+            self.$($path).+.work_dir = work_dir.into();
+            self
+        }
+
+        pub fn pre_run_script(mut self, script: impl Into<String>) -> Self {
+            self.$($path).+.pre_run_script = Some(script.into());
+            self
+        }
+
+        pub fn post_run_script(mut self, script: impl Into<String>) -> Self {
+            self.$($path).+.post_run_script = Some(script.into());
+            self
+        }
+
+        pub fn nextflow_config(mut self, config: impl Into<String>) -> Self {
+            self.$($path).+.nextflow_config = Some(config.into());
+            self
+        }
+
+        /// Adds an environment variable to both the head and compute jobs
+        pub fn env(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+            self.$($path).+.environment.push(EnvVar {
+                name: name.into(),
+                value: value.into(),
+                head: true,
+                compute: true,
+            });
+            self
+        }
+    };
+}
+
+#[derive(Debug, Default)]
+pub struct AwsBatchConfigBuilder {
+    config: AwsBatchConfig,
+}
+
+impl AwsBatchConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    base_config_setters!(config.base);
+
+    pub fn region(mut self, region: impl Into<String>) -> Self {
+        self.config.region = region.into();
+        self
+    }
+
+    fn forge_mut(&mut self) -> &mut AwsForgeConfig {
+        self.config.forge.get_or_insert_with(|| AwsForgeConfig {
+            instance_type: "SPOT".to_string(),
+            max_cpus: 256,
+            ..Default::default()
+        })
+    }
+
+    /// Uses Batch Forge to create the queues, starting from spot instances
+    /// with 0 to 256 CPUs
+    pub fn forge(mut self) -> Self {
+        self.forge_mut();
+        self
+    }
+
+    /// Use spot instances (`true`) or on-demand EC2 instances (`false`)
+    pub fn spot(mut self, spot: bool) -> Self {
+        self.forge_mut().instance_type = if spot { "SPOT" } else { "EC2" }.to_string();
+        self
+    }
+
+    pub fn min_cpus(mut self, min_cpus: i32) -> Self {
+        self.forge_mut().min_cpus = min_cpus;
+        self
+    }
+
+    pub fn max_cpus(mut self, max_cpus: i32) -> Self {
+        self.forge_mut().max_cpus = max_cpus;
+        self
+    }
+
+    pub fn instance_types(mut self, instance_types: Vec<String>) -> Self {
+        self.forge_mut().instance_types = instance_types;
+        self
+    }
+
+    pub fn gpu_enabled(mut self, enabled: bool) -> Self {
+        self.forge_mut().gpu_enabled = enabled;
+        self
+    }
+
+    pub fn vpc_id(mut self, vpc_id: impl Into<String>) -> Self {
+        self.forge_mut().vpc_id = Some(vpc_id.into());
+        self
+    }
+
+    pub fn subnets(mut self, subnets: Vec<String>) -> Self {
+        self.forge_mut().subnets = subnets;
+        self
+    }
+
+    pub fn security_groups(mut self, security_groups: Vec<String>) -> Self {
+        self.forge_mut().security_groups = security_groups;
+        self
+    }
+
+    pub fn allow_buckets(mut self, buckets: Vec<String>) -> Self {
+        self.forge_mut().allow_buckets = buckets;
+        self
+    }
+
+    pub fn ebs_boot_size(mut self, size_gb: i32) -> Self {
+        self.forge_mut().ebs_boot_size = Some(size_gb);
+        self
+    }
+
+    pub fn arm64_enabled(mut self, enabled: bool) -> Self {
+        self.forge_mut().arm64_enabled = Some(enabled);
+        self
+    }
+
+    /// Uses an existing Batch queue for the head job instead of a forged one
+    pub fn head_queue(mut self, queue: impl Into<String>) -> Self {
+        self.config.head_queue = Some(queue.into());
+        self
+    }
+
+    /// Uses an existing Batch queue for compute jobs instead of a forged one
+    pub fn compute_queue(mut self, queue: impl Into<String>) -> Self {
+        self.config.compute_queue = Some(queue.into());
+        self
+    }
+
+    pub fn head_job_role(mut self, role: impl Into<String>) -> Self {
+        self.config.head_job_role = Some(role.into());
+        self
+    }
+
+    pub fn compute_job_role(mut self, role: impl Into<String>) -> Self {
+        self.config.compute_job_role = Some(role.into());
+        self
+    }
+
+    pub fn head_job_resources(mut self, cpus: i32, memory_mb: i32) -> Self {
+        self.config.head_job_cpus = Some(cpus);
+        self.config.head_job_memory_mb = Some(memory_mb);
+        self
+    }
+
+    pub fn cli_path(mut self, path: impl Into<String>) -> Self {
+        self.config.cli_path = Some(path.into());
+        self
+    }
+
+    pub fn wave_enabled(mut self, enabled: bool) -> Self {
+        self.config.wave_enabled = enabled;
+        self
+    }
+
+    pub fn fusion2_enabled(mut self, enabled: bool) -> Self {
+        self.config.fusion2_enabled = enabled;
+        self
+    }
+
+    pub fn nvme_storage_enabled(mut self, enabled: bool) -> Self {
+        self.config.nvnme_storage_enabled = enabled;
+        self
+    }
+
+    pub fn build(self) -> Result<AwsBatchConfig, ValidationError> {
+        self.config.validate()?;
+        Ok(self.config)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct GoogleBatchConfigBuilder {
+    config: GoogleBatchConfig,
+}
+
+impl GoogleBatchConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    base_config_setters!(config.base);
+
+    pub fn project_id(mut self, project_id: impl Into<String>) -> Self {
+        self.config.project_id = project_id.into();
+        self
+    }
+
+    pub fn location(mut self, location: impl Into<String>) -> Self {
+        self.config.location = location.into();
+        self
+    }
+
+    pub fn spot(mut self, spot: bool) -> Self {
+        self.config.spot = spot;
+        self
+    }
+
+    pub fn machine_type(mut self, machine_type: impl Into<String>) -> Self {
+        self.config.machine_type = Some(machine_type.into());
+        self
+    }
+
+    pub fn cpu_platform(mut self, cpu_platform: impl Into<String>) -> Self {
+        self.config.cpu_platform = Some(cpu_platform.into());
+        self
+    }
+
+    pub fn boot_disk_size_gb(mut self, size_gb: i32) -> Self {
+        self.config.boot_disk_size_gb = Some(size_gb);
+        self
+    }
+
+    pub fn use_private_address(mut self, private: bool) -> Self {
+        self.config.use_private_address = private;
+        self
+    }
+
+    pub fn network(mut self, network: impl Into<String>) -> Self {
+        self.config.network = Some(network.into());
+        self
+    }
+
+    pub fn subnetwork(mut self, subnetwork: impl Into<String>) -> Self {
+        self.config.subnetwork = Some(subnetwork.into());
+        self
+    }
+
+    pub fn service_account(mut self, service_account: impl Into<String>) -> Self {
+        self.config.service_account = Some(service_account.into());
+        self
+    }
+
+    pub fn label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.config.labels.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn head_job_resources(mut self, cpus: i32, memory_mb: i32) -> Self {
+        self.config.head_job_cpus = Some(cpus);
+        self.config.head_job_memory_mb = Some(memory_mb);
+        self
+    }
+
+    pub fn nfs(mut self, target: impl Into<String>, mount: impl Into<String>) -> Self {
+        self.config.nfs_target = Some(target.into());
+        self.config.nfs_mount = Some(mount.into());
+        self
+    }
+
+    pub fn wave_enabled(mut self, enabled: bool) -> Self {
+        self.config.wave_enabled = enabled;
+        self
+    }
+
+    pub fn fusion2_enabled(mut self, enabled: bool) -> Self {
+        self.config.fusion2_enabled = enabled;
+        self
+    }
+
+    pub fn build(self) -> Result<GoogleBatchConfig, ValidationError> {
+        self.config.validate()?;
+        Ok(self.config)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct AzureBatchConfigBuilder {
+    config: AzureBatchConfig,
+}
+
+impl AzureBatchConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    base_config_setters!(config.base);
+
+    pub fn region(mut self, region: impl Into<String>) -> Self {
+        self.config.region = region.into();
+        self
+    }
+
+    /// Uses Batch Forge to create a pool of `vm_count` VMs of type `vm_type`
+    pub fn forge(mut self, vm_type: impl Into<String>, vm_count: i32) -> Self {
+        let forge = self.config.forge.get_or_insert_with(AzureForgeConfig::default);
+        forge.vm_type = vm_type.into();
+        forge.vm_count = vm_count;
+        self
+    }
+
+    pub fn auto_scale(mut self, auto_scale: bool) -> Self {
+        self.config.forge.get_or_insert_with(AzureForgeConfig::default).auto_scale = auto_scale;
+        self
+    }
+
+    pub fn container_registry_ids(mut self, ids: Vec<String>) -> Self {
+        self.config.forge.get_or_insert_with(AzureForgeConfig::default).container_reg_ids = Some(ids);
+        self
+    }
+
+    /// Uses an existing Batch pool for the head job instead of a forged one
+    pub fn head_pool(mut self, pool: impl Into<String>) -> Self {
+        self.config.head_pool = Some(pool.into());
+        self
+    }
+
+    pub fn delete_jobs_on_completion(mut self, policy: DeleteJobsOnCompletion) -> Self {
+        self.config.delete_jobs_on_completion = policy;
+        self
+    }
+
+    pub fn delete_pools_on_completion(mut self, delete: bool) -> Self {
+        self.config.delete_pools_on_completion = delete;
+        self
+    }
+
+    pub fn token_duration(mut self, duration: impl Into<String>) -> Self {
+        self.config.token_duration = Some(duration.into());
+        self
+    }
+
+    pub fn managed_identity_client_id(mut self, client_id: impl Into<String>) -> Self {
+        self.config.managed_identity_client_id = Some(client_id.into());
+        self
+    }
+
+    pub fn wave_enabled(mut self, enabled: bool) -> Self {
+        self.config.wave_enabled = enabled;
+        self
+    }
+
+    pub fn fusion2_enabled(mut self, enabled: bool) -> Self {
+        self.config.fusion2_enabled = enabled;
+        self
+    }
+
+    pub fn build(self) -> Result<AzureBatchConfig, ValidationError> {
+        self.config.validate()?;
+        Ok(self.config)
+    }
+}
+
+/// Builds Kubernetes, EKS and GKE configurations, which share most of their fields
+#[derive(Debug, Default)]
+pub struct KubernetesConfigBuilder {
+    base: BaseKubernetesConfig,
+    region: String,
+    cluster_name: String,
+    wave_enabled: bool,
+    fusion2_enabled: bool,
+}
+
+impl KubernetesConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    base_config_setters!(base.base);
+
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.base.namespace = namespace.into();
+        self
+    }
+
+    /// API server URL; required for plain Kubernetes only
+    pub fn server(mut self, server: impl Into<String>) -> Self {
+        self.base.server = Some(server.into());
+        self
+    }
+
+    /// Cluster CA certificate; required for plain Kubernetes only
+    pub fn ssl_cert(mut self, ssl_cert: impl Into<String>) -> Self {
+        self.base.ssl_cert = Some(ssl_cert.into());
+        self
+    }
+
+    /// Cluster region; required for EKS and GKE
+    pub fn region(mut self, region: impl Into<String>) -> Self {
+        self.region = region.into();
+        self
+    }
+
+    /// Cluster name; required for EKS and GKE
+    pub fn cluster_name(mut self, cluster_name: impl Into<String>) -> Self {
+        self.cluster_name = cluster_name.into();
+        self
+    }
+
+    pub fn head_service_account(mut self, account: impl Into<String>) -> Self {
+        self.base.head_service_account = Some(account.into());
+        self
+    }
+
+    pub fn compute_service_account(mut self, account: impl Into<String>) -> Self {
+        self.base.compute_service_account = Some(account.into());
+        self
+    }
+
+    pub fn storage_claim(mut self, claim_name: impl Into<String>, mount_path: impl Into<String>) -> Self {
+        self.base.storage_claim_name = Some(claim_name.into());
+        self.base.storage_mount_path = Some(mount_path.into());
+        self
+    }
+
+    pub fn pod_cleanup(mut self, policy: PodCleanup) -> Self {
+        self.base.pod_cleanup = policy;
+        self
+    }
+
+    pub fn head_pod_spec(mut self, spec: impl Into<String>) -> Self {
+        self.base.head_pod_spec = Some(spec.into());
+        self
+    }
+
+    pub fn service_pod_spec(mut self, spec: impl Into<String>) -> Self {
+        self.base.service_pod_spec = Some(spec.into());
+        self
+    }
+
+    pub fn head_job_resources(mut self, cpus: i32, memory_mb: i32) -> Self {
+        self.base.head_job_cpus = Some(cpus);
+        self.base.head_job_memory_mb = Some(memory_mb);
+        self
+    }
+
+    /// Only applies to EKS and GKE
+    pub fn wave_enabled(mut self, enabled: bool) -> Self {
+        self.wave_enabled = enabled;
+        self
+    }
+
+    /// Only applies to EKS and GKE
+    pub fn fusion2_enabled(mut self, enabled: bool) -> Self {
+        self.fusion2_enabled = enabled;
+        self
+    }
+
+    pub fn build_k8s(self) -> Result<KubernetesConfig, ValidationError> {
+        let config = KubernetesConfig { base: self.base };
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub fn build_eks(self) -> Result<EksConfig, ValidationError> {
+        let config = EksConfig {
+            base: self.base,
+            region: self.region,
+            cluster_name: self.cluster_name,
+            wave_enabled: self.wave_enabled,
+            fusion2_enabled: self.fusion2_enabled,
+        };
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub fn build_gke(self) -> Result<GkeConfig, ValidationError> {
+        let config = GkeConfig {
+            base: self.base,
+            region: self.region,
+            cluster_name: self.cluster_name,
+            wave_enabled: self.wave_enabled,
+            fusion2_enabled: self.fusion2_enabled,
+        };
+        config.validate()?;
+        Ok(config)
+    }
+}
+
+/// Builds configurations for HPC schedulers reached over SSH (Slurm, LSF,
+/// UGE, Altair PBS Pro and Moab)
+#[derive(Debug)]
+pub struct GridConfigBuilder {
+    base: BaseGridConfig,
+    unit_for_limits: Option<String>,
+    per_job_mem_limit: bool,
+    per_task_reserve: bool,
+}
+
+impl Default for GridConfigBuilder {
+    fn default() -> Self {
+        Self {
+            base: BaseGridConfig {
+                port: 22,
+                max_queue_size: 100,
+                ..Default::default()
+            },
+            unit_for_limits: None,
+            per_job_mem_limit: false,
+            per_task_reserve: false,
+        }
+    }
+}
+
+impl GridConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    base_config_setters!(base.base);
+
+    pub fn host_name(mut self, host_name: impl Into<String>) -> Self {
+        self.base.host_name = host_name.into();
+        self
+    }
+
+    pub fn user_name(mut self, user_name: impl Into<String>) -> Self {
+        self.base.user_name = user_name.into();
+        self
+    }
+
+    pub fn port(mut self, port: i32) -> Self {
+        self.base.port = port;
+        self
+    }
+
+    pub fn launch_dir(mut self, launch_dir: impl Into<String>) -> Self {
+        self.base.launch_dir = Some(launch_dir.into());
+        self
+    }
+
+    pub fn head_queue(mut self, queue: impl Into<String>) -> Self {
+        self.base.head_queue = Some(queue.into());
+        self
+    }
+
+    pub fn compute_queue(mut self, queue: impl Into<String>) -> Self {
+        self.base.compute_queue = Some(queue.into());
+        self
+    }
+
+    pub fn max_queue_size(mut self, size: i32) -> Self {
+        self.base.max_queue_size = size;
+        self
+    }
+
+    /// Scheduler options for the head job, optionally also applied to compute jobs
+    pub fn head_job_options(mut self, options: impl Into<String>, propagate: bool) -> Self {
+        self.base.head_job_options = Some(options.into());
+        self.base.propagate_head_job_options = propagate;
+        self
+    }
+
+    /// Only applies to LSF
+    pub fn unit_for_limits(mut self, unit: impl Into<String>) -> Self {
+        self.unit_for_limits = Some(unit.into());
+        self
+    }
+
+    /// Only applies to LSF
+    pub fn per_job_mem_limit(mut self, enabled: bool) -> Self {
+        self.per_job_mem_limit = enabled;
+        self
+    }
+
+    /// Only applies to LSF
+    pub fn per_task_reserve(mut self, enabled: bool) -> Self {
+        self.per_task_reserve = enabled;
+        self
+    }
+
+    pub fn build_slurm(self) -> Result<SlurmConfig, ValidationError> {
+        self.base.validate()?;
+        Ok(SlurmConfig { base: self.base })
+    }
+
+    pub fn build_lsf(self) -> Result<LsfConfig, ValidationError> {
+        self.base.validate()?;
+        Ok(LsfConfig {
+            base: self.base,
+            unit_for_limits: self.unit_for_limits,
+            per_job_mem_limit: self.per_job_mem_limit,
+            per_task_reserve: self.per_task_reserve,
+        })
+    }
+
+    pub fn build_uge(self) -> Result<UgeConfig, ValidationError> {
+        self.base.validate()?;
+        Ok(UgeConfig { base: self.base })
+    }
+
+    pub fn build_altair(self) -> Result<AltairConfig, ValidationError> {
+        self.base.validate()?;
+        Ok(AltairConfig { base: self.base })
+    }
+
+    pub fn build_moab(self) -> Result<MoabConfig, ValidationError> {
+        self.base.validate()?;
+        Ok(MoabConfig { base: self.base })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aws_batch_builder() {
+        let config = AwsBatchConfigBuilder::new()
+            .region("eu-west-1")
+            .work_dir("s3://bucket/work")
+            .max_cpus(500)
+            .vpc_id("vpc-1")
+            .subnets(vec!["subnet-1".to_string()])
+            .wave_enabled(true)
+            .fusion2_enabled(true)
+            .env("FOO", "bar")
+            .build()
+            .unwrap();
+
+        let forge = config.forge.as_ref().unwrap();
+        assert_eq!(forge.instance_type, "SPOT");
+        assert_eq!(forge.min_cpus, 0);
+        assert_eq!(forge.max_cpus, 500);
+        assert!(forge.dragen_ami_id.is_none());
+        assert_eq!(config.base.environment[0].name, "FOO");
+        assert!(config.validate().is_ok());
+
+        let value = serde_json::to_value(ComputeConfig::from(config)).unwrap();
+        assert_eq!(value["discriminator"], "aws-batch");
+        assert_eq!(value["workDir"], "s3://bucket/work");
+        assert_eq!(value["forge"]["maxCpus"], 500);
+    }
+
+    #[test]
+    fn test_aws_batch_builder_requires_region() {
+        let result = AwsBatchConfigBuilder::new().work_dir("s3://bucket").build();
+        assert!(matches!(result, Err(ValidationError::Required { field: "region" })));
+    }
+
+    #[test]
+    fn test_aws_batch_validation() {
+        let builder = || AwsBatchConfigBuilder::new().region("eu-west-1").work_dir("s3://bucket");
+
+        assert!(matches!(
+            builder().min_cpus(10).max_cpus(5).build(),
+            Err(ValidationError::Invalid { field: "minCpus", .. })
+        ));
+        assert!(matches!(
+            builder().work_dir("gs://bucket").build(),
+            Err(ValidationError::Invalid { field: "workDir", .. })
+        ));
+        assert!(matches!(
+            builder().subnets(vec!["subnet-1".to_string()]).build(),
+            Err(ValidationError::Required { field: "vpcId" })
+        ));
+        assert!(matches!(
+            builder().vpc_id("vpc-1").build(),
+            Err(ValidationError::Required { field: "subnets" })
+        ));
+    }
+
+    #[test]
+    fn test_google_batch_builder() {
+        let config = GoogleBatchConfigBuilder::new()
+            .project_id("my-project")
+            .location("europe-west2")
+            .work_dir("gs://bucket/work")
+            .spot(true)
+            .label("team", "genomics")
+            .build()
+            .unwrap();
+        assert!(config.validate().is_ok());
+
+        let result = GoogleBatchConfigBuilder::new()
+            .project_id("my-project")
+            .location("europe-west2")
+            .work_dir("gs://bucket/work")
+            .subnetwork("subnet")
+            .build();
+        assert!(matches!(result, Err(ValidationError::Required { field: "network" })));
+    }
+
+    #[test]
+    fn test_azure_batch_builder() {
+        let config = AzureBatchConfigBuilder::new()
+            .region("westeurope")
+            .work_dir("az://container/work")
+            .forge("Standard_D4_v3", 4)
+            .auto_scale(true)
+            .build()
+            .unwrap();
+        assert!(config.validate().is_ok());
+        assert!(config.forge.as_ref().unwrap().auto_scale);
+
+        let result = AzureBatchConfigBuilder::new()
+            .region("westeurope")
+            .work_dir("s3://bucket")
+            .build();
+        assert!(matches!(result, Err(ValidationError::Invalid { field: "workDir", .. })));
+    }
+
+    #[test]
+    fn test_kubernetes_builders() {
+        let builder = || {
+            KubernetesConfigBuilder::new()
+                .namespace("nextflow")
+                .work_dir("/scratch")
+                .storage_claim("nf-pvc", "/scratch")
+        };
+
+        assert!(matches!(builder().build_k8s(), Err(ValidationError::Required { field: "server" })));
+        let k8s = builder()
+            .server("https://k8s.example.com")
+            .ssl_cert("CERT")
+            .build_k8s()
+            .unwrap();
+        assert!(k8s.validate().is_ok());
+
+        assert!(matches!(builder().build_eks(), Err(ValidationError::Required { field: "region" })));
+        let eks = builder().region("us-east-1").cluster_name("prod").build_eks().unwrap();
+        assert!(eks.validate().is_ok());
+
+        let gke = builder().region("europe-west2").cluster_name("prod").build_gke().unwrap();
+        assert_eq!(ComputeConfig::from(gke).platform(), crate::models::platform::PlatformId::Gke);
+    }
+
+    #[test]
+    fn test_grid_builders() {
+        let builder = || {
+            GridConfigBuilder::new()
+                .host_name("login.hpc.example.com")
+                .user_name("nf")
+                .work_dir("/scratch/work")
+                .head_queue("long")
+        };
+
+        let slurm = builder().build_slurm().unwrap();
+        assert_eq!(slurm.base.port, 22);
+        assert_eq!(slurm.base.max_queue_size, 100);
+        assert!(slurm.base.validate().is_ok());
+
+        let lsf = builder().unit_for_limits("MB").per_job_mem_limit(true).build_lsf().unwrap();
+        assert_eq!(lsf.unit_for_limits.as_deref(), Some("MB"));
+
+        assert!(builder().build_uge().is_ok());
+        assert!(builder().build_altair().is_ok());
+        assert!(matches!(
+            builder().port(0).build_moab(),
+            Err(ValidationError::Invalid { field: "port", .. })
+        ));
+
+        assert!(matches!(
+            GridConfigBuilder::new().user_name("nf").work_dir("/scratch").build_slurm(),
+            Err(ValidationError::Required { field: "hostName" })
+        ));
+    }
+}
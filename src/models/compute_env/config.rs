@@ -2,8 +2,10 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use super::EnvVar;
+use crate::models::common::{Validate, ValidationError, validation};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct BaseConfig {
     #[serde(rename = "workDir")]
     pub work_dir: String,
@@ -16,7 +18,8 @@ pub struct BaseConfig {
     pub nextflow_config: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AwsForgeConfig {
     #[serde(rename = "type")]
     pub instance_type: String,
@@ -66,7 +69,7 @@ pub struct AwsForgeConfig {
     #[serde(rename = "dragenEnabled")]
     pub dragen_enabled: bool,
     #[serde(rename = "dragenAmiId")]
-    pub dragen_ami_id: String,
+    pub dragen_ami_id: Option<String>,
     #[serde(rename = "ebsBootSize")]
     pub ebs_boot_size: Option<i32>,
     #[serde(rename = "ecsConfig")]
@@ -79,7 +82,8 @@ pub struct AwsForgeConfig {
     pub dragen_instance_type: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AwsBatchConfig {
     #[serde(flatten)]
     pub base: BaseConfig,
@@ -90,13 +94,13 @@ pub struct AwsBatchConfig {
     #[serde(rename = "dragenQueue")]
     pub dragen_queue: Option<String>,
     #[serde(rename = "dragenInstanceType")]
-    pub dragen_instance_type: String,
+    pub dragen_instance_type: Option<String>,
     #[serde(rename = "computeJobRole")]
     pub compute_job_role: Option<String>,
     #[serde(rename = "executionRole")]
-    pub execution_role: String,
+    pub execution_role: Option<String>,
     #[serde(rename = "headQueue")]
-    pub head_queue: String,
+    pub head_queue: Option<String>,
     #[serde(rename = "headJobRole")]
     pub head_job_role: Option<String>,
     #[serde(rename = "cliPath")]
@@ -113,12 +117,13 @@ pub struct AwsBatchConfig {
     pub nvnme_storage_enabled: bool,
     #[serde(rename = "logGroup")]
     pub log_group: Option<String>,
-    pub forge: AwsForgeConfig,
+    pub forge: Option<AwsForgeConfig>,
     #[serde(rename = "forgedResources")]
     pub forged_resources: Vec<HashMap<String, serde_json::Value>>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct GoogleLifeSciencesConfig {
     #[serde(flatten)]
     pub base: BaseConfig,
@@ -151,54 +156,56 @@ pub struct GoogleLifeSciencesConfig {
     pub nfs_mount: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct GoogleBatchConfig {
     #[serde(flatten)]
     pub base: BaseConfig,
     pub location: String,
     pub spot: bool,
     #[serde(rename = "bootDiskSizeGb")]
-    pub boot_disk_size_gb: i32,
+    pub boot_disk_size_gb: Option<i32>,
     #[serde(rename = "cpuPlatform")]
-    pub cpu_platform: String,
+    pub cpu_platform: Option<String>,
     #[serde(rename = "machineType")]
-    pub machine_type: String,
+    pub machine_type: Option<String>,
     #[serde(rename = "projectId")]
     pub project_id: String,
     #[serde(rename = "sshDaemon")]
     pub ssh_daemon: bool,
     #[serde(rename = "sshImage")]
-    pub ssh_image: String,
+    pub ssh_image: Option<String>,
     #[serde(rename = "debugMode")]
-    pub debug_mode: i32,
+    pub debug_mode: Option<i32>,
     #[serde(rename = "copyImage")]
-    pub copy_image: String,
+    pub copy_image: Option<String>,
     #[serde(rename = "usePrivateAddress")]
     pub use_private_address: bool,
     pub labels: HashMap<String, String>,
     #[serde(rename = "headJobCpus")]
-    pub head_job_cpus: i32,
+    pub head_job_cpus: Option<i32>,
     #[serde(rename = "headJobMemoryMb")]
-    pub head_job_memory_mb: i32,
+    pub head_job_memory_mb: Option<i32>,
     #[serde(rename = "nfsTarget")]
-    pub nfs_target: String,
+    pub nfs_target: Option<String>,
     #[serde(rename = "nfsMount")]
-    pub nfs_mount: String,
+    pub nfs_mount: Option<String>,
     #[serde(rename = "waveEnabled")]
     pub wave_enabled: bool,
     #[serde(rename = "fusion2Enabled")]
     pub fusion2_enabled: bool,
     #[serde(rename = "serviceAccount")]
-    pub service_account: String,
-    pub network: String,
-    pub subnetwork: String,
+    pub service_account: Option<String>,
+    pub network: Option<String>,
+    pub subnetwork: Option<String>,
     #[serde(rename = "headJobInstanceTemplate")]
-    pub head_job_instance_template: String,
+    pub head_job_instance_template: Option<String>,
     #[serde(rename = "computeJobsInstanceTemplate")]
-    pub compute_jobs_instance_template: String,
+    pub compute_jobs_instance_template: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AzureForgeConfig {
     #[serde(rename = "vmType")]
     pub vm_type: String,
@@ -212,15 +219,17 @@ pub struct AzureForgeConfig {
     pub container_reg_ids: Option<Vec<String>>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum DeleteJobsOnCompletion {
+    #[default]
     OnSuccess,
     Always,
     Never,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AzureBatchConfig {
     #[serde(flatten)]
     pub base: BaseConfig,
@@ -229,7 +238,7 @@ pub struct AzureBatchConfig {
     pub head_pool: Option<String>,
     #[serde(rename = "autoPoolMode")]
     pub auto_pool_mode: Option<bool>,
-    pub forge: AzureForgeConfig,
+    pub forge: Option<AzureForgeConfig>,
     #[serde(rename = "tokenDuration")]
     pub token_duration: Option<String>,
     #[serde(rename = "deleteJobsOnCompletion")]
@@ -244,90 +253,97 @@ pub struct AzureBatchConfig {
     pub managed_identity_client_id: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct BaseGridConfig {
     #[serde(flatten)]
     pub base: BaseConfig,
     #[serde(rename = "launchDir")]
-    pub launch_dir: String,
+    pub launch_dir: Option<String>,
     #[serde(rename = "userName")]
     pub user_name: String,
     #[serde(rename = "hostName")]
     pub host_name: String,
     pub port: i32,
     #[serde(rename = "headQueue")]
-    pub head_queue: String,
+    pub head_queue: Option<String>,
     #[serde(rename = "computeQueue")]
-    pub compute_queue: String,
+    pub compute_queue: Option<String>,
     #[serde(rename = "maxQueueSize")]
     pub max_queue_size: i32,
     #[serde(rename = "headJobOptions")]
-    pub head_job_options: String,
+    pub head_job_options: Option<String>,
     #[serde(rename = "propagateHeadJobOptions")]
     pub propagate_head_job_options: bool,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct LsfConfig {
     #[serde(flatten)]
     pub base: BaseGridConfig,
     #[serde(rename = "unitForLimits")]
-    pub unit_for_limits: String,
+    pub unit_for_limits: Option<String>,
     #[serde(rename = "perJobMemLimit")]
     pub per_job_mem_limit: bool,
     #[serde(rename = "perTaskReserve")]
     pub per_task_reserve: bool,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct SlurmConfig {
     #[serde(flatten)]
     pub base: BaseGridConfig,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum PodCleanup {
+    #[default]
     OnSuccess,
     Always,
     Never,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct BaseKubernetesConfig {
     #[serde(flatten)]
     pub base: BaseConfig,
-    pub server: String,
+    pub server: Option<String>,
     #[serde(rename = "sslCert")]
-    pub ssl_cert: String,
+    pub ssl_cert: Option<String>,
     pub namespace: String,
     #[serde(rename = "computeServiceAccount")]
-    pub compute_service_account: String,
+    pub compute_service_account: Option<String>,
     #[serde(rename = "headServiceAccount")]
-    pub head_service_account: String,
+    pub head_service_account: Option<String>,
     #[serde(rename = "storageClaimName")]
-    pub storage_claim_name: String,
+    pub storage_claim_name: Option<String>,
     #[serde(rename = "storageMountPath")]
-    pub storage_mount_path: String,
+    pub storage_mount_path: Option<String>,
     #[serde(rename = "podCleanup")]
     pub pod_cleanup: PodCleanup,
     #[serde(rename = "headPodSpec")]
-    pub head_pod_spec: String,
+    pub head_pod_spec: Option<String>,
     #[serde(rename = "servicePodSpec")]
-    pub service_pod_spec: String,
+    pub service_pod_spec: Option<String>,
     #[serde(rename = "headJobCpus")]
-    pub head_job_cpus: i32,
+    pub head_job_cpus: Option<i32>,
     #[serde(rename = "headJobMemoryMb")]
-    pub head_job_memory_mb: i32,
+    pub head_job_memory_mb: Option<i32>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct KubernetesConfig {
     #[serde(flatten)]
     pub base: BaseKubernetesConfig,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct EksConfig {
     #[serde(flatten)]
     pub base: BaseKubernetesConfig,
@@ -340,7 +356,8 @@ pub struct EksConfig {
     pub fusion2_enabled: bool,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct GkeConfig {
     #[serde(flatten)]
     pub base: BaseKubernetesConfig,
@@ -353,20 +370,140 @@ pub struct GkeConfig {
     pub wave_enabled: bool,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct UgeConfig {
     #[serde(flatten)]
     pub base: BaseGridConfig,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AltairConfig {
     #[serde(flatten)]
     pub base: BaseGridConfig,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct MoabConfig {
     #[serde(flatten)]
     pub base: BaseGridConfig,
-} 
\ No newline at end of file
+}
+
+fn validate_work_dir(base: &BaseConfig, scheme: &'static str, reason: &'static str) -> Result<(), ValidationError> {
+    validation::validate_required(&base.work_dir, "workDir")?;
+    if !base.work_dir.starts_with(scheme) {
+        return Err(ValidationError::Invalid { field: "workDir", reason });
+    }
+    Ok(())
+}
+
+impl Validate for AwsBatchConfig {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate_work_dir(&self.base, "s3://", "must be an s3:// path for AWS Batch")?;
+        validation::validate_required(&self.region, "region")?;
+
+        if let Some(ref forge) = self.forge {
+            if forge.min_cpus < 0 {
+                return Err(ValidationError::Invalid { field: "minCpus", reason: "must not be negative" });
+            }
+            if forge.min_cpus > forge.max_cpus {
+                return Err(ValidationError::Invalid { field: "minCpus", reason: "must not exceed maxCpus" });
+            }
+            let has_network = !forge.subnets.is_empty() || !forge.security_groups.is_empty();
+            if has_network && forge.vpc_id.is_none() {
+                return Err(ValidationError::Required { field: "vpcId" });
+            }
+            if forge.vpc_id.is_some() && forge.subnets.is_empty() {
+                return Err(ValidationError::Required { field: "subnets" });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Validate for GoogleLifeSciencesConfig {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate_work_dir(&self.base, "gs://", "must be a gs:// path for Google Cloud")?;
+        validation::validate_required(&self.region, "region")?;
+        validation::validate_required(&self.project_id, "projectId")
+    }
+}
+
+impl Validate for GoogleBatchConfig {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate_work_dir(&self.base, "gs://", "must be a gs:// path for Google Cloud")?;
+        validation::validate_required(&self.location, "location")?;
+        validation::validate_required(&self.project_id, "projectId")?;
+        if self.subnetwork.is_some() && self.network.is_none() {
+            return Err(ValidationError::Required { field: "network" });
+        }
+        Ok(())
+    }
+}
+
+impl Validate for AzureBatchConfig {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate_work_dir(&self.base, "az://", "must be an az:// path for Azure Batch")?;
+        validation::validate_required(&self.region, "region")?;
+        if let Some(ref forge) = self.forge {
+            validation::validate_required(&forge.vm_type, "vmType")?;
+            if forge.vm_count < 1 {
+                return Err(ValidationError::Invalid { field: "vmCount", reason: "must be at least 1" });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Validate for BaseGridConfig {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validation::validate_required(&self.base.work_dir, "workDir")?;
+        validation::validate_required(&self.user_name, "userName")?;
+        validation::validate_required(&self.host_name, "hostName")?;
+        if !(1..=65535).contains(&self.port) {
+            return Err(ValidationError::Invalid { field: "port", reason: "must be between 1 and 65535" });
+        }
+        if self.max_queue_size < 1 {
+            return Err(ValidationError::Invalid { field: "maxQueueSize", reason: "must be at least 1" });
+        }
+        Ok(())
+    }
+}
+
+impl Validate for BaseKubernetesConfig {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validation::validate_required(&self.base.work_dir, "workDir")?;
+        validation::validate_required(&self.namespace, "namespace")
+    }
+}
+
+impl Validate for KubernetesConfig {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.base.validate()?;
+        if self.base.server.is_none() {
+            return Err(ValidationError::Required { field: "server" });
+        }
+        if self.base.ssl_cert.is_none() {
+            return Err(ValidationError::Required { field: "sslCert" });
+        }
+        Ok(())
+    }
+}
+
+impl Validate for EksConfig {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.base.validate()?;
+        validation::validate_required(&self.region, "region")?;
+        validation::validate_required(&self.cluster_name, "clusterName")
+    }
+}
+
+impl Validate for GkeConfig {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.base.validate()?;
+        validation::validate_required(&self.region, "region")?;
+        validation::validate_required(&self.cluster_name, "clusterName")
+    }
+}
@@ -0,0 +1,343 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use std::fmt;
+use super::common::{Secret, Validate, ValidationError, validation};
+
+/// Credentials provider identifier
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CredentialsProvider {
+    #[serde(rename = "aws")]
+    Aws,
+    #[serde(rename = "google")]
+    Google,
+    #[serde(rename = "azure")]
+    Azure,
+    #[serde(rename = "azure_entra")]
+    AzureEntra,
+    #[serde(rename = "ssh")]
+    Ssh,
+    #[serde(rename = "k8s")]
+    Kubernetes,
+    #[serde(rename = "github")]
+    GitHub,
+    #[serde(rename = "gitlab")]
+    GitLab,
+    #[serde(rename = "bitbucket")]
+    Bitbucket,
+    #[serde(rename = "container-reg")]
+    ContainerRegistry,
+    #[serde(other)]
+    Other,
+}
+
+impl fmt::Display for CredentialsProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CredentialsProvider::Aws => write!(f, "aws"),
+            CredentialsProvider::Google => write!(f, "google"),
+            CredentialsProvider::Azure => write!(f, "azure"),
+            CredentialsProvider::AzureEntra => write!(f, "azure_entra"),
+            CredentialsProvider::Ssh => write!(f, "ssh"),
+            CredentialsProvider::Kubernetes => write!(f, "k8s"),
+            CredentialsProvider::GitHub => write!(f, "github"),
+            CredentialsProvider::GitLab => write!(f, "gitlab"),
+            CredentialsProvider::Bitbucket => write!(f, "bitbucket"),
+            CredentialsProvider::ContainerRegistry => write!(f, "container-reg"),
+            CredentialsProvider::Other => write!(f, "other"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct AwsKeys {
+    #[serde(rename = "accessKey", skip_serializing_if = "Option::is_none")]
+    pub access_key: Option<String>,
+    #[serde(rename = "secretKey", skip_serializing_if = "Option::is_none")]
+    pub secret_key: Option<Secret>,
+    #[serde(rename = "assumeRoleArn", skip_serializing_if = "Option::is_none")]
+    pub assume_role_arn: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct GoogleKeys {
+    /// Service account key JSON
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Secret>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct AzureKeys {
+    #[serde(rename = "batchName")]
+    pub batch_name: String,
+    #[serde(rename = "storageName")]
+    pub storage_name: String,
+    #[serde(rename = "batchKey", skip_serializing_if = "Option::is_none")]
+    pub batch_key: Option<Secret>,
+    #[serde(rename = "storageKey", skip_serializing_if = "Option::is_none")]
+    pub storage_key: Option<Secret>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct AzureEntraKeys {
+    #[serde(rename = "batchName")]
+    pub batch_name: String,
+    #[serde(rename = "storageName")]
+    pub storage_name: String,
+    #[serde(rename = "tenantId")]
+    pub tenant_id: String,
+    #[serde(rename = "clientId")]
+    pub client_id: String,
+    #[serde(rename = "clientSecret", skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<Secret>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SshKeys {
+    #[serde(rename = "privateKey", skip_serializing_if = "Option::is_none")]
+    pub private_key: Option<Secret>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub passphrase: Option<Secret>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct KubernetesKeys {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<Secret>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub certificate: Option<String>,
+    #[serde(rename = "privateKey", skip_serializing_if = "Option::is_none")]
+    pub private_key: Option<Secret>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct GitHubKeys {
+    pub username: String,
+    /// Personal access token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<Secret>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct GitLabKeys {
+    pub username: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<Secret>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<Secret>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct BitbucketKeys {
+    pub username: String,
+    /// App password
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<Secret>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ContainerRegistryKeys {
+    #[serde(rename = "userName")]
+    pub user_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<Secret>,
+    pub registry: String,
+}
+
+/// Provider-specific credential payload.
+///
+/// Secret fields are only sent when creating or updating credentials; the
+/// API never returns them.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "discriminator")]
+pub enum CredentialKeys {
+    #[serde(rename = "aws")]
+    Aws(AwsKeys),
+    #[serde(rename = "google")]
+    Google(GoogleKeys),
+    #[serde(rename = "azure")]
+    Azure(AzureKeys),
+    #[serde(rename = "azure_entra")]
+    AzureEntra(AzureEntraKeys),
+    #[serde(rename = "ssh")]
+    Ssh(SshKeys),
+    #[serde(rename = "k8s")]
+    Kubernetes(KubernetesKeys),
+    #[serde(rename = "github")]
+    GitHub(GitHubKeys),
+    #[serde(rename = "gitlab")]
+    GitLab(GitLabKeys),
+    #[serde(rename = "bitbucket")]
+    Bitbucket(BitbucketKeys),
+    #[serde(rename = "container-reg")]
+    ContainerRegistry(ContainerRegistryKeys),
+    #[serde(other)]
+    Other,
+}
+
+impl CredentialKeys {
+    /// The provider these keys belong to
+    pub fn provider(&self) -> CredentialsProvider {
+        match self {
+            CredentialKeys::Aws(_) => CredentialsProvider::Aws,
+            CredentialKeys::Google(_) => CredentialsProvider::Google,
+            CredentialKeys::Azure(_) => CredentialsProvider::Azure,
+            CredentialKeys::AzureEntra(_) => CredentialsProvider::AzureEntra,
+            CredentialKeys::Ssh(_) => CredentialsProvider::Ssh,
+            CredentialKeys::Kubernetes(_) => CredentialsProvider::Kubernetes,
+            CredentialKeys::GitHub(_) => CredentialsProvider::GitHub,
+            CredentialKeys::GitLab(_) => CredentialsProvider::GitLab,
+            CredentialKeys::Bitbucket(_) => CredentialsProvider::Bitbucket,
+            CredentialKeys::ContainerRegistry(_) => CredentialsProvider::ContainerRegistry,
+            CredentialKeys::Other => CredentialsProvider::Other,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Credentials {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub provider: CredentialsProvider,
+    #[serde(rename = "baseUrl")]
+    pub base_url: Option<String>,
+    pub category: Option<String>,
+    pub deleted: Option<bool>,
+    #[serde(rename = "lastUsed")]
+    pub last_used: Option<DateTime<Utc>>,
+    #[serde(rename = "dateCreated")]
+    pub date_created: Option<DateTime<Utc>>,
+    #[serde(rename = "lastUpdated")]
+    pub last_updated: Option<DateTime<Utc>>,
+    pub keys: Option<CredentialKeys>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListCredentialsResponse {
+    pub credentials: Vec<Credentials>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DescribeCredentialsResponse {
+    pub credentials: Credentials,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CredentialsInner {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub name: String,
+    pub description: Option<String>,
+    pub provider: CredentialsProvider,
+    #[serde(rename = "baseUrl", skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,
+    pub keys: CredentialKeys,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateCredentialsRequest {
+    pub credentials: CredentialsInner,
+}
+
+impl CreateCredentialsRequest {
+    pub fn new(name: impl Into<String>, keys: CredentialKeys) -> Self {
+        Self {
+            credentials: CredentialsInner {
+                id: None,
+                name: name.into(),
+                description: None,
+                provider: keys.provider(),
+                base_url: None,
+                keys,
+            },
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.credentials.description = Some(description.into());
+        self
+    }
+
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.credentials.base_url = Some(base_url.into());
+        self
+    }
+}
+
+impl Validate for CreateCredentialsRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate_credentials_inner(&self.credentials)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateCredentialsResponse {
+    #[serde(rename = "credentialsId")]
+    pub credentials_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateCredentialsRequest {
+    pub credentials: CredentialsInner,
+}
+
+impl Validate for UpdateCredentialsRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate_credentials_inner(&self.credentials)
+    }
+}
+
+fn validate_credentials_inner(inner: &CredentialsInner) -> Result<(), ValidationError> {
+    validation::validate_max_length(&inner.name, 100, "name")?;
+    lazy_static::lazy_static! {
+        static ref NAME_PATTERN: regex::Regex = regex::Regex::new(
+            r"^[a-zA-Z\d][-._a-zA-Z\d]*$"
+        ).unwrap();
+    }
+    validation::validate_pattern(&inner.name, &NAME_PATTERN, "name")?;
+    if let Some(ref desc) = inner.description {
+        validation::validate_max_length(desc, 1000, "description")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keys_debug_redacts_secrets() {
+        let keys = CredentialKeys::Aws(AwsKeys {
+            access_key: Some("AKIAEXAMPLE".to_string()),
+            secret_key: Some(Secret::new("very-secret-key")),
+            assume_role_arn: None,
+        });
+        let debug = format!("{:?}", keys);
+        assert!(debug.contains("AKIAEXAMPLE"));
+        assert!(!debug.contains("very-secret-key"));
+    }
+
+    #[test]
+    fn test_create_request_serialization() {
+        let request = CreateCredentialsRequest::new(
+            "my-github",
+            CredentialKeys::GitHub(GitHubKeys {
+                username: "octocat".to_string(),
+                password: Some(Secret::new("ghp_token")),
+            }),
+        );
+        let value = serde_json::to_value(&request).unwrap();
+        assert_eq!(value["credentials"]["provider"], "github");
+        assert_eq!(value["credentials"]["keys"]["discriminator"], "github");
+        assert_eq!(value["credentials"]["keys"]["password"], "ghp_token");
+    }
+
+    #[test]
+    fn test_credentials_name_validation() {
+        let request = CreateCredentialsRequest::new("bad name", CredentialKeys::Ssh(SshKeys::default()));
+        assert!(request.validate().is_err());
+
+        let request = CreateCredentialsRequest::new("good-name", CredentialKeys::Ssh(SshKeys::default()));
+        assert!(request.validate().is_ok());
+    }
+}
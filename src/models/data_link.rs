@@ -0,0 +1,233 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use super::credentials::CredentialsProvider;
+
+/// Cloud storage provider backing a data link
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DataLinkProvider {
+    Aws,
+    Google,
+    Azure,
+    #[serde(other)]
+    Other,
+}
+
+impl fmt::Display for DataLinkProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DataLinkProvider::Aws => write!(f, "aws"),
+            DataLinkProvider::Google => write!(f, "google"),
+            DataLinkProvider::Azure => write!(f, "azure"),
+            DataLinkProvider::Other => write!(f, "other"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataLinkCredentials {
+    pub id: String,
+    pub name: String,
+    pub provider: CredentialsProvider,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataLink {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    /// Bucket or container URI, e.g. `s3://my-bucket`
+    #[serde(rename = "resourceRef")]
+    pub resource_ref: String,
+    #[serde(rename = "type")]
+    pub link_type: Option<String>,
+    pub provider: DataLinkProvider,
+    pub region: Option<String>,
+    #[serde(default)]
+    pub credentials: Vec<DataLinkCredentials>,
+    #[serde(rename = "publicAccessible", default)]
+    pub public_accessible: bool,
+    #[serde(default)]
+    pub hidden: bool,
+    pub status: Option<String>,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListDataLinksResponse {
+    #[serde(rename = "dataLinks")]
+    pub data_links: Vec<DataLink>,
+    #[serde(rename = "totalSize")]
+    pub total_size: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DescribeDataLinkResponse {
+    #[serde(rename = "dataLink")]
+    pub data_link: DataLink,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum DataLinkItemType {
+    File,
+    Folder,
+}
+
+/// A file or folder inside a data link
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataLinkItem {
+    #[serde(rename = "type")]
+    pub item_type: DataLinkItemType,
+    pub name: String,
+    #[serde(default)]
+    pub size: i64,
+    #[serde(rename = "mimeType")]
+    pub mime_type: Option<String>,
+}
+
+/// One page of a data link listing
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DataLinkContentResponse {
+    #[serde(rename = "originalPath")]
+    pub original_path: Option<String>,
+    #[serde(default)]
+    pub objects: Vec<DataLinkItem>,
+    #[serde(rename = "nextPageToken")]
+    pub next_page_token: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DataLinkDownloadUrlResponse {
+    pub url: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DataLinkMultiPartUploadRequest {
+    #[serde(rename = "fileName")]
+    pub file_name: String,
+    #[serde(rename = "contentLength")]
+    pub content_length: i64,
+    #[serde(rename = "contentType", skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+}
+
+/// Pre-signed URLs to PUT each part of a multipart upload to
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DataLinkMultiPartUploadResponse {
+    #[serde(rename = "uploadId")]
+    pub upload_id: String,
+    #[serde(rename = "uploadUrls")]
+    pub upload_urls: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UploadEtag {
+    #[serde(rename = "eTag")]
+    pub e_tag: String,
+    #[serde(rename = "partNumber")]
+    pub part_number: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DataLinkFinishMultiPartUploadRequest {
+    #[serde(rename = "fileName")]
+    pub file_name: String,
+    #[serde(rename = "uploadId")]
+    pub upload_id: String,
+    pub tags: Vec<UploadEtag>,
+    /// Abort the upload instead of completing it
+    #[serde(rename = "withError")]
+    pub with_error: bool,
+}
+
+/// A location inside a data link, written `dl://<data-link-name>/<path>`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataLinkPath {
+    pub link: String,
+    pub path: String,
+}
+
+impl DataLinkPath {
+    pub const SCHEME: &'static str = "dl://";
+
+    /// Whether `value` uses the `dl://` scheme
+    pub fn is_data_link_path(value: &str) -> bool {
+        value.starts_with(Self::SCHEME)
+    }
+
+    /// The final path component, if any
+    pub fn file_name(&self) -> Option<&str> {
+        self.path.rsplit('/').next().filter(|name| !name.is_empty())
+    }
+
+    /// The path with its final component removed
+    pub fn parent(&self) -> &str {
+        self.path.rsplit_once('/').map(|(parent, _)| parent).unwrap_or("")
+    }
+}
+
+impl FromStr for DataLinkPath {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rest = s
+            .strip_prefix(Self::SCHEME)
+            .ok_or_else(|| format!("'{}' must start with {}", s, Self::SCHEME))?;
+        let (link, path) = rest.split_once('/').unwrap_or((rest, ""));
+        if link.is_empty() {
+            return Err(format!("'{}' is missing a data link name", s));
+        }
+        Ok(DataLinkPath {
+            link: link.to_string(),
+            path: path.trim_matches('/').to_string(),
+        })
+    }
+}
+
+impl fmt::Display for DataLinkPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}/{}", Self::SCHEME, self.link, self.path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_data_link_path() {
+        let path: DataLinkPath = "dl://my-bucket/results/multiqc.html".parse().unwrap();
+        assert_eq!(path.link, "my-bucket");
+        assert_eq!(path.path, "results/multiqc.html");
+        assert_eq!(path.file_name(), Some("multiqc.html"));
+        assert_eq!(path.parent(), "results");
+
+        let root: DataLinkPath = "dl://my-bucket".parse().unwrap();
+        assert_eq!(root.path, "");
+        assert_eq!(root.file_name(), None);
+        assert_eq!(root.parent(), "");
+
+        assert!("s3://my-bucket".parse::<DataLinkPath>().is_err());
+        assert!("dl:///path".parse::<DataLinkPath>().is_err());
+    }
+
+    #[test]
+    fn test_deserialize_data_link() {
+        let link: DataLink = serde_json::from_value(serde_json::json!({
+            "id": "v1-cloud-abc",
+            "name": "my-bucket",
+            "resourceRef": "s3://my-bucket",
+            "type": "S3-bucket",
+            "provider": "aws",
+            "region": "eu-west-1",
+            "credentials": [{ "id": "c1", "name": "aws-creds", "provider": "aws" }],
+            "publicAccessible": false,
+            "hidden": false
+        }))
+        .unwrap();
+        assert_eq!(link.provider, DataLinkProvider::Aws);
+        assert_eq!(link.credentials[0].provider, CredentialsProvider::Aws);
+    }
+}
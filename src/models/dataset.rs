@@ -0,0 +1,295 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use std::path::Path;
+use super::common::{Validate, ValidationError, validation};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Dataset {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(rename = "mediaType")]
+    pub media_type: Option<String>,
+    #[serde(default)]
+    pub deleted: bool,
+    #[serde(rename = "lastUpdated")]
+    pub last_updated: Option<DateTime<Utc>>,
+    #[serde(rename = "dateCreated")]
+    pub date_created: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListDatasetsResponse {
+    pub datasets: Vec<Dataset>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DescribeDatasetResponse {
+    pub dataset: Dataset,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateDatasetRequest {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl Validate for CreateDatasetRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate_dataset_name(&self.name)?;
+        if let Some(ref description) = self.description {
+            validation::validate_max_length(description, 1000, "description")?;
+        }
+        Ok(())
+    }
+}
+
+/// Datasets are updated with the same payload they are created with
+pub type UpdateDatasetRequest = CreateDatasetRequest;
+
+fn validate_dataset_name(name: &str) -> Result<(), ValidationError> {
+    validation::validate_required(name, "name")?;
+    validation::validate_max_length(name, 100, "name")?;
+    lazy_static::lazy_static! {
+        static ref NAME_PATTERN: regex::Regex = regex::Regex::new(
+            r"^[a-zA-Z\d](?:[-._a-zA-Z\d]*[a-zA-Z\d])?$"
+        ).unwrap();
+    }
+    validation::validate_pattern(name, &NAME_PATTERN, "name")
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DatasetVersion {
+    #[serde(rename = "datasetId")]
+    pub dataset_id: String,
+    #[serde(rename = "datasetName")]
+    pub dataset_name: Option<String>,
+    #[serde(rename = "datasetDescription")]
+    pub dataset_description: Option<String>,
+    #[serde(rename = "hasHeader", default)]
+    pub has_header: bool,
+    pub version: i64,
+    #[serde(rename = "lastUpdated")]
+    pub last_updated: Option<DateTime<Utc>>,
+    #[serde(rename = "fileName")]
+    pub file_name: String,
+    #[serde(rename = "mediaType")]
+    pub media_type: Option<String>,
+    /// URL a pipeline reads this version from
+    pub url: String,
+}
+
+impl DatasetVersion {
+    /// Sets `param` in a launch's pipeline parameters to this dataset version,
+    /// the way the Platform launch form does when a dataset is chosen as input
+    pub fn attach_to_params(&self, params: &mut serde_json::Map<String, serde_json::Value>, param: &str) {
+        params.insert(param.to_string(), serde_json::Value::String(self.url.clone()));
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListDatasetVersionsResponse {
+    pub versions: Vec<DatasetVersion>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UploadDatasetVersionResponse {
+    pub version: DatasetVersion,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatasetFormat {
+    Csv,
+    Tsv,
+}
+
+impl DatasetFormat {
+    /// Picks the format from a `.csv` or `.tsv` file extension
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "csv" => Some(DatasetFormat::Csv),
+            "tsv" => Some(DatasetFormat::Tsv),
+            _ => None,
+        }
+    }
+
+    pub fn delimiter(&self) -> char {
+        match self {
+            DatasetFormat::Csv => ',',
+            DatasetFormat::Tsv => '\t',
+        }
+    }
+
+    pub fn media_type(&self) -> &'static str {
+        match self {
+            DatasetFormat::Csv => "text/csv",
+            DatasetFormat::Tsv => "text/tab-separated-values",
+        }
+    }
+}
+
+/// A local CSV/TSV file checked and ready to upload as a dataset version
+#[derive(Debug, Clone)]
+pub struct DatasetFile {
+    pub file_name: String,
+    pub format: DatasetFormat,
+    pub content: String,
+    pub has_header: bool,
+    pub rows: usize,
+    pub columns: usize,
+}
+
+impl DatasetFile {
+    /// Parses `content` and checks every row has the same number of columns.
+    ///
+    /// Whether the first row is a header is detected unless `has_header` is given.
+    pub fn new(
+        file_name: impl Into<String>,
+        format: DatasetFormat,
+        content: impl Into<String>,
+        has_header: Option<bool>,
+    ) -> Result<Self, ValidationError> {
+        let content = content.into();
+        let records = parse_records(&content, format.delimiter());
+        let columns = records
+            .first()
+            .map(|r| r.len())
+            .ok_or(ValidationError::Required { field: "file" })?;
+
+        for (index, record) in records.iter().enumerate() {
+            if record.len() != columns {
+                return Err(ValidationError::ColumnCount {
+                    row: index + 1,
+                    expected: columns,
+                    found: record.len(),
+                });
+            }
+        }
+
+        Ok(Self {
+            file_name: file_name.into(),
+            format,
+            has_header: has_header.unwrap_or_else(|| detect_header(&records)),
+            rows: records.len(),
+            content,
+            columns,
+        })
+    }
+}
+
+/// Splits delimited text into records, honouring double-quoted fields.
+/// Blank lines are skipped.
+fn parse_records(content: &str, delimiter: char) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' if in_quotes => in_quotes = false,
+            '"' if field.is_empty() => in_quotes = true,
+            '\r' if !in_quotes => {}
+            '\n' if !in_quotes => {
+                record.push(std::mem::take(&mut field));
+                if !(record.len() == 1 && record[0].is_empty()) {
+                    records.push(std::mem::take(&mut record));
+                }
+                record.clear();
+            }
+            c if c == delimiter && !in_quotes => record.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    records
+}
+
+/// Treats the first row as a header when its cells look like column names:
+/// unique, non-numeric identifiers that do not reappear in their column
+fn detect_header(records: &[Vec<String>]) -> bool {
+    lazy_static::lazy_static! {
+        static ref COLUMN_NAME: regex::Regex = regex::Regex::new(
+            r"^[a-zA-Z_][\w .-]*$"
+        ).unwrap();
+    }
+
+    let Some((first, rest)) = records.split_first() else {
+        return false;
+    };
+
+    first.iter().enumerate().all(|(column, cell)| {
+        let cell = cell.trim();
+        COLUMN_NAME.is_match(cell)
+            && cell.parse::<f64>().is_err()
+            && first.iter().filter(|other| other.trim() == cell).count() == 1
+            && rest.iter().all(|row| row[column].trim() != cell)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dataset_file_detects_header() {
+        let csv = "sample,fastq_1,fastq_2\nS1,s3://b/s1_R1.fq.gz,s3://b/s1_R2.fq.gz\n";
+        let file = DatasetFile::new("samples.csv", DatasetFormat::Csv, csv, None).unwrap();
+        assert!(file.has_header);
+        assert_eq!(file.rows, 2);
+        assert_eq!(file.columns, 3);
+
+        let tsv = "1\t0.5\n2\t0.7\n";
+        let file = DatasetFile::new("values.tsv", DatasetFormat::Tsv, tsv, None).unwrap();
+        assert!(!file.has_header);
+
+        let file = DatasetFile::new("values.tsv", DatasetFormat::Tsv, tsv, Some(true)).unwrap();
+        assert!(file.has_header);
+    }
+
+    #[test]
+    fn test_dataset_file_column_counts() {
+        let csv = "a,b\n\"x,1\",y\n\"multi\nline\",\"say \"\"hi\"\"\"\n";
+        let file = DatasetFile::new("ok.csv", DatasetFormat::Csv, csv, None).unwrap();
+        assert_eq!(file.rows, 3);
+
+        let result = DatasetFile::new("bad.csv", DatasetFormat::Csv, "a,b\n1,2,3\n", None);
+        assert!(matches!(
+            result,
+            Err(ValidationError::ColumnCount { row: 2, expected: 2, found: 3 })
+        ));
+
+        assert!(DatasetFile::new("empty.csv", DatasetFormat::Csv, "\n", None).is_err());
+    }
+
+    #[test]
+    fn test_dataset_format_from_path() {
+        assert_eq!(DatasetFormat::from_path(Path::new("a/samples.CSV")), Some(DatasetFormat::Csv));
+        assert_eq!(DatasetFormat::from_path(Path::new("samples.tsv")), Some(DatasetFormat::Tsv));
+        assert_eq!(DatasetFormat::from_path(Path::new("samples.txt")), None);
+    }
+
+    #[test]
+    fn test_attach_to_params() {
+        let version: DatasetVersion = serde_json::from_value(serde_json::json!({
+            "datasetId": "ds1",
+            "version": 2,
+            "fileName": "samples.csv",
+            "url": "https://api.cloud.seqera.io/workspaces/1/datasets/ds1/v/2/n/samples.csv"
+        }))
+        .unwrap();
+        let mut params = serde_json::Map::new();
+        version.attach_to_params(&mut params, "input");
+        assert_eq!(params["input"], version.url.as_str());
+    }
+}
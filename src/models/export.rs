@@ -0,0 +1,191 @@
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+use super::pipeline::{Pipeline, PipelineLaunch};
+
+/// Fields that change without the configuration changing, left out of
+/// exports so that re-exports diff cleanly
+pub const VOLATILE_FIELDS: [&str; 5] = ["dateCreated", "lastUpdated", "lastUsed", "lastSeen", "lastModified"];
+
+/// File format of an export
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExportFormat {
+    #[default]
+    Json,
+    Yaml,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Json => "json",
+            ExportFormat::Yaml => "yaml",
+        }
+    }
+
+    pub fn render(&self, value: &Value) -> Result<String, String> {
+        match self {
+            ExportFormat::Json => serde_json::to_string_pretty(value)
+                .map(|json| json + "\n")
+                .map_err(|e| e.to_string()),
+            ExportFormat::Yaml => serde_yaml::to_string(value).map_err(|e| e.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for ExportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.extension())
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "json" => Ok(ExportFormat::Json),
+            "yaml" | "yml" => Ok(ExportFormat::Yaml),
+            other => Err(format!("unknown export format '{}', expected json or yaml", other)),
+        }
+    }
+}
+
+/// A pipeline together with its launch configuration
+#[derive(Debug, Clone, Serialize)]
+pub struct PipelineExport {
+    pub pipeline: Pipeline,
+    pub launch: PipelineLaunch,
+}
+
+/// A workspace's configuration as a set of documents keyed by relative path
+/// without extension, e.g. `compute-envs/aws-prod`
+#[derive(Debug, Default)]
+pub struct WorkspaceExport {
+    pub documents: BTreeMap<String, Value>,
+}
+
+impl WorkspaceExport {
+    /// Adds a document, stripping volatile fields
+    pub fn insert(&mut self, path: impl Into<String>, document: &impl Serialize) -> Result<(), serde_json::Error> {
+        let mut value = serde_json::to_value(document)?;
+        strip_volatile(&mut value);
+        self.documents.insert(path.into(), value);
+        Ok(())
+    }
+
+    /// Adds one document per resource under `dir`, named after the resource.
+    /// Resources whose names give the same file name, e.g. `nf-core/rnaseq`
+    /// and `nf-core_rnaseq`, are told apart by appending their `id`.
+    pub fn insert_each<T: Serialize>(
+        &mut self,
+        dir: &str,
+        resources: &[T],
+        name: impl Fn(&T) -> &str,
+        id: impl Fn(&T) -> String,
+    ) -> Result<(), serde_json::Error> {
+        let mut uses: BTreeMap<String, usize> = BTreeMap::new();
+        for resource in resources {
+            *uses.entry(file_name(name(resource))).or_default() += 1;
+        }
+        for resource in resources {
+            let mut stem = file_name(name(resource));
+            if uses[&stem] > 1 {
+                stem = format!("{}-{}", stem, file_name(&id(resource)));
+            }
+            let path = format!("{}/{}", dir, stem);
+            if self.documents.contains_key(&path) {
+                return Err(serde::ser::Error::custom(format!("more than one document would be exported as {}", path)));
+            }
+            self.insert(path, resource)?;
+        }
+        Ok(())
+    }
+
+    /// The files to write: relative path with extension, and contents
+    pub fn render(&self, format: ExportFormat) -> Result<Vec<(String, String)>, String> {
+        self.documents
+            .iter()
+            .map(|(path, value)| Ok((format!("{}.{}", path, format.extension()), format.render(value)?)))
+            .collect()
+    }
+}
+
+/// Removes [`VOLATILE_FIELDS`] at any depth
+pub fn strip_volatile(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.retain(|key, _| !VOLATILE_FIELDS.contains(&key.as_str()));
+            map.values_mut().for_each(strip_volatile);
+        }
+        Value::Array(items) => items.iter_mut().for_each(strip_volatile),
+        _ => {}
+    }
+}
+
+/// A resource name made safe to use as a file name
+pub fn file_name(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || "._-".contains(c) { c } else { '_' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn name(value: &Value) -> &str {
+        value["name"].as_str().unwrap()
+    }
+
+    #[test]
+    fn test_strip_volatile_fields() {
+        let mut value = json!({
+            "name": "aws-prod",
+            "dateCreated": "2024-01-01T00:00:00Z",
+            "labels": [{ "name": "project", "lastUsed": "2024-02-01T00:00:00Z" }],
+            "config": { "region": "eu-west-1", "lastUpdated": null }
+        });
+        strip_volatile(&mut value);
+        assert_eq!(
+            value,
+            json!({ "name": "aws-prod", "labels": [{ "name": "project" }], "config": { "region": "eu-west-1" } })
+        );
+    }
+
+    #[test]
+    fn test_render_is_sorted_and_stable() {
+        let mut export = WorkspaceExport::default();
+        export.insert("workspace", &json!({ "name": "genomics", "id": 10 })).unwrap();
+        export
+            .insert_each("pipelines", &[json!({ "name": "nf-core/rnaseq" })], name, |p| p["id"].to_string())
+            .unwrap();
+
+        let files = export.render(ExportFormat::Json).unwrap();
+        assert_eq!(files[0].0, "pipelines/nf-core_rnaseq.json");
+        assert_eq!(files[1], ("workspace.json".to_string(), "{\n  \"id\": 10,\n  \"name\": \"genomics\"\n}\n".to_string()));
+
+        let files = export.render(ExportFormat::Yaml).unwrap();
+        assert_eq!(files[1], ("workspace.yaml".to_string(), "id: 10\nname: genomics\n".to_string()));
+        assert_eq!("yml".parse(), Ok(ExportFormat::Yaml));
+    }
+
+    #[test]
+    fn test_insert_each_disambiguates_file_names() {
+        let mut export = WorkspaceExport::default();
+        let pipelines = [
+            json!({ "id": 1, "name": "nf-core/rnaseq" }),
+            json!({ "id": 2, "name": "nf-core_rnaseq" }),
+            json!({ "id": 3, "name": "nf-core/sarek" }),
+        ];
+        export.insert_each("pipelines", &pipelines, name, |p| p["id"].to_string()).unwrap();
+        let paths: Vec<&str> = export.documents.keys().map(String::as_str).collect();
+        assert_eq!(paths, vec!["pipelines/nf-core_rnaseq-1", "pipelines/nf-core_rnaseq-2", "pipelines/nf-core_sarek"]);
+
+        let clash = [json!({ "id": 4, "name": "nf-core/sarek" })];
+        assert!(export.insert_each("pipelines", &clash, name, |p| p["id"].to_string()).is_err());
+    }
+}
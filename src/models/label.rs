@@ -0,0 +1,168 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use super::common::{Validate, ValidationError, validation};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Label {
+    pub id: i64,
+    pub name: String,
+    /// Only set for resource labels
+    pub value: Option<String>,
+    #[serde(default)]
+    pub resource: bool,
+    #[serde(rename = "isDefault", default)]
+    pub is_default: bool,
+}
+
+/// Filter for listing labels
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LabelType {
+    Simple,
+    Resource,
+    All,
+}
+
+impl fmt::Display for LabelType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LabelType::Simple => write!(f, "simple"),
+            LabelType::Resource => write!(f, "resource"),
+            LabelType::All => write!(f, "all"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListLabelsResponse {
+    pub labels: Vec<Label>,
+    #[serde(rename = "totalSize")]
+    pub total_size: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateLabelRequest {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    pub resource: bool,
+    #[serde(rename = "isDefault")]
+    pub is_default: bool,
+}
+
+impl CreateLabelRequest {
+    /// A simple label, used for organizing pipelines, runs and compute environments
+    pub fn simple(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: None,
+            resource: false,
+            is_default: false,
+        }
+    }
+
+    /// A resource label, propagated to cloud resources as a `name=value` tag
+    pub fn resource(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: Some(value.into()),
+            resource: true,
+            is_default: false,
+        }
+    }
+
+    /// Apply this resource label by default to new compute environments and runs
+    pub fn default_label(mut self, is_default: bool) -> Self {
+        self.is_default = is_default;
+        self
+    }
+}
+
+impl Validate for CreateLabelRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate_label_part(&self.name, "name")?;
+        match (&self.value, self.resource) {
+            (Some(value), true) => validate_label_part(value, "value")?,
+            (None, true) => return Err(ValidationError::Required { field: "value" }),
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateLabelRequest {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(rename = "isDefault", skip_serializing_if = "Option::is_none")]
+    pub is_default: Option<bool>,
+}
+
+impl Validate for UpdateLabelRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate_label_part(&self.name, "name")?;
+        if let Some(ref value) = self.value {
+            validate_label_part(value, "value")?;
+        }
+        Ok(())
+    }
+}
+
+fn validate_label_part(value: &str, field: &'static str) -> Result<(), ValidationError> {
+    validation::validate_max_length(value, 39, field)?;
+    lazy_static::lazy_static! {
+        static ref LABEL_PATTERN: regex::Regex = regex::Regex::new(
+            r"^[a-zA-Z\d](?:[-_a-zA-Z\d]*[a-zA-Z\d])?$"
+        ).unwrap();
+    }
+    validation::validate_pattern(value, &LABEL_PATTERN, field)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AssociateWorkflowLabelsRequest {
+    #[serde(rename = "workflowIds")]
+    pub workflow_ids: Vec<String>,
+    #[serde(rename = "labelIds")]
+    pub label_ids: Vec<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AssociatePipelineLabelsRequest {
+    #[serde(rename = "pipelineIds")]
+    pub pipeline_ids: Vec<i64>,
+    #[serde(rename = "labelIds")]
+    pub label_ids: Vec<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AssociateComputeEnvLabelsRequest {
+    #[serde(rename = "computeEnvIds")]
+    pub compute_env_ids: Vec<String>,
+    #[serde(rename = "labelIds")]
+    pub label_ids: Vec<i64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_label_validation() {
+        assert!(CreateLabelRequest::simple("rnaseq").validate().is_ok());
+        assert!(CreateLabelRequest::simple("-bad").validate().is_err());
+        assert!(CreateLabelRequest::simple("a".repeat(40)).validate().is_err());
+        assert!(CreateLabelRequest::resource("owner", "team_a").validate().is_ok());
+        assert!(CreateLabelRequest::resource("owner", "team a").validate().is_err());
+    }
+
+    #[test]
+    fn test_resource_label_requires_value() {
+        let mut request = CreateLabelRequest::resource("owner", "x");
+        request.value = None;
+        assert!(matches!(
+            request.validate(),
+            Err(ValidationError::Required { field: "value" })
+        ));
+    }
+}
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 use super::common::{OrgRole, WspRole, ParticipantType};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -18,6 +19,14 @@ pub struct Member {
     pub role: String,
 }
 
+impl Member {
+    /// The organization role, parsed regardless of case; `None` for roles
+    /// this client doesn't know
+    pub fn org_role(&self) -> Option<OrgRole> {
+        OrgRole::from_str(&self.role).ok()
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct MemberList {
     pub members: Vec<Member>,
@@ -25,33 +34,46 @@ pub struct MemberList {
     pub total_size: i64,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// A workspace participant: either an organization member or collaborator,
+/// or a whole team. Only the fields for its `participant_type` are set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Participant {
     #[serde(rename = "participantId")]
     pub participant_id: i64,
     #[serde(rename = "memberId")]
-    pub member_id: i64,
+    pub member_id: Option<i64>,
     #[serde(rename = "userName")]
-    pub user_name: String,
-    pub email: String,
+    pub user_name: Option<String>,
+    pub email: Option<String>,
     #[serde(rename = "firstName")]
-    pub first_name: String,
+    pub first_name: Option<String>,
     #[serde(rename = "lastName")]
-    pub last_name: String,
+    pub last_name: Option<String>,
     #[serde(rename = "orgRole")]
-    pub org_role: OrgRole,
+    pub org_role: Option<OrgRole>,
     #[serde(rename = "teamId")]
-    pub team_id: i64,
+    pub team_id: Option<i64>,
     #[serde(rename = "teamName")]
-    pub team_name: String,
+    pub team_name: Option<String>,
     #[serde(rename = "wspRole")]
     pub wsp_role: WspRole,
     #[serde(rename = "type")]
     pub participant_type: ParticipantType,
     #[serde(rename = "teamAvatarUrl")]
-    pub team_avatar_url: String,
+    pub team_avatar_url: Option<String>,
     #[serde(rename = "userAvatarUrl")]
-    pub user_avatar_url: String,
+    pub user_avatar_url: Option<String>,
+}
+
+impl Participant {
+    /// The team name for team participants, the user name otherwise
+    pub fn name(&self) -> &str {
+        match self.participant_type {
+            ParticipantType::Team => self.team_name.as_deref(),
+            _ => self.user_name.as_deref(),
+        }
+        .unwrap_or_default()
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -74,4 +96,149 @@ pub struct UpdateMemberRoleRequest {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ParticipantResponse {
     pub participant: Participant,
-} 
\ No newline at end of file
+} 
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateParticipantRoleRequest {
+    pub role: WspRole,
+}
+
+/// A workspace to add an invited user to, with the role they get there
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkspaceAssignment {
+    pub workspace: String,
+    pub role: WspRole,
+}
+
+impl FromStr for WorkspaceAssignment {
+    type Err = String;
+
+    /// Parses `workspace:role`, or just `workspace` for the launch role
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (workspace, role) = match s.split_once(':') {
+            Some((workspace, role)) => (workspace, role.parse()?),
+            None => (s, WspRole::Launch),
+        };
+        if workspace.is_empty() {
+            return Err(format!("missing workspace name in '{}'", s));
+        }
+        Ok(Self { workspace: workspace.to_string(), role })
+    }
+}
+
+/// Adds a user to an organization and gives them access in one go
+#[derive(Debug, Clone)]
+pub struct InviteRequest {
+    pub user: String,
+    pub org_role: Option<OrgRole>,
+    pub teams: Vec<String>,
+    pub workspaces: Vec<WorkspaceAssignment>,
+}
+
+impl InviteRequest {
+    /// Invites `user`, a user name or email address
+    pub fn new(user: impl Into<String>) -> Self {
+        Self {
+            user: user.into(),
+            org_role: None,
+            teams: Vec::new(),
+            workspaces: Vec::new(),
+        }
+    }
+
+    /// Sets the organization role; new members otherwise keep the default `member`
+    pub fn org_role(mut self, role: OrgRole) -> Self {
+        self.org_role = Some(role);
+        self
+    }
+
+    /// Adds the user to a team, by name
+    pub fn team(mut self, team: impl Into<String>) -> Self {
+        self.teams.push(team.into());
+        self
+    }
+
+    /// Adds the user to a workspace, by name, with `role`
+    pub fn workspace(mut self, workspace: impl Into<String>, role: WspRole) -> Self {
+        self.workspaces.push(WorkspaceAssignment { workspace: workspace.into(), role });
+        self
+    }
+}
+
+/// One step of an invitation, e.g. `add to team bioinfo`
+#[derive(Debug)]
+pub struct InviteStep {
+    pub description: String,
+    /// Why the step failed, if it did
+    pub error: Option<String>,
+}
+
+impl InviteStep {
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Outcome of an invitation. Steps after joining the organization are
+/// independent, so some may fail while the others succeed.
+#[derive(Debug)]
+pub struct InviteReport {
+    pub member: Member,
+    /// Whether the user was already an organization member
+    pub existing_member: bool,
+    pub steps: Vec<InviteStep>,
+}
+
+impl InviteReport {
+    pub fn failures(&self) -> impl Iterator<Item = &InviteStep> {
+        self.steps.iter().filter(|step| !step.is_ok())
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.steps.iter().all(InviteStep::is_ok)
+    }
+}
+
+/// Fixtures shared by the tests of modules dealing with members
+#[cfg(test)]
+pub(crate) mod fixtures {
+    use super::Member;
+    use serde_json::{json, Value};
+
+    /// An organization or team member as the API returns it
+    pub fn member_json(member_id: i64, user_name: &str, role: &str) -> Value {
+        json!({
+            "memberId": member_id,
+            "userId": member_id,
+            "userName": user_name,
+            "email": format!("{}@example.com", user_name),
+            "firstName": "",
+            "lastName": "",
+            "role": role
+        })
+    }
+
+    /// The member described by [`member_json`]
+    pub fn member(member_id: i64, user_name: &str, role: &str) -> Member {
+        serde_json::from_value(member_json(member_id, user_name, role)).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_workspace_assignment() {
+        assert_eq!(
+            "production:maintain".parse(),
+            Ok(WorkspaceAssignment { workspace: "production".to_string(), role: WspRole::Maintain })
+        );
+        assert_eq!(
+            "scratch".parse(),
+            Ok(WorkspaceAssignment { workspace: "scratch".to_string(), role: WspRole::Launch })
+        );
+        assert!("production:superuser".parse::<WorkspaceAssignment>().is_err());
+        assert!(":admin".parse::<WorkspaceAssignment>().is_err());
+    }
+}
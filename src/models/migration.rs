@@ -0,0 +1,206 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use super::common::Secret;
+use super::org_spec::SpecFormat;
+
+/// How credentials and compute environments of the source workspace map to
+/// the target. Keys are source IDs or names, values target IDs or names.
+/// Resources without an entry map to the target resource of the same name.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MigrationMapping {
+    #[serde(default)]
+    pub credentials: BTreeMap<String, String>,
+    #[serde(rename = "computeEnvs", default)]
+    pub compute_envs: BTreeMap<String, String>,
+}
+
+impl MigrationMapping {
+    pub fn parse(text: &str, format: SpecFormat) -> Result<Self, String> {
+        match format {
+            SpecFormat::Yaml => serde_yaml::from_str(text).map_err(|e| e.to_string()),
+            SpecFormat::Toml => toml::from_str(text).map_err(|e| e.to_string()),
+            SpecFormat::Json => serde_json::from_str(text).map_err(|e| e.to_string()),
+        }
+    }
+
+    /// The target credentials ID or name for source credentials
+    pub fn credentials_for<'a>(&'a self, id: &str, name: &'a str) -> &'a str {
+        lookup(&self.credentials, id, name)
+    }
+
+    /// The target compute environment ID or name for a source compute environment
+    pub fn compute_env_for<'a>(&'a self, id: &str, name: &'a str) -> &'a str {
+        self.mapped_compute_env(id, name).unwrap_or(name)
+    }
+
+    /// The target compute environment ID or name for a source compute
+    /// environment with an entry in the mapping
+    pub fn mapped_compute_env(&self, id: &str, name: &str) -> Option<&str> {
+        entry(&self.compute_envs, id, name)
+    }
+}
+
+fn lookup<'a>(map: &'a BTreeMap<String, String>, id: &str, name: &'a str) -> &'a str {
+    entry(map, id, name).unwrap_or(name)
+}
+
+fn entry<'a>(map: &'a BTreeMap<String, String>, id: &str, name: &str) -> Option<&'a str> {
+    map.get(id).or_else(|| map.get(name)).map(String::as_str)
+}
+
+/// Settings of a workspace migration
+#[derive(Debug, Default)]
+pub struct MigrationOptions {
+    pub mapping: MigrationMapping,
+    /// Values of pipeline secrets, which can't be read from the source
+    pub secrets: Vec<(String, Secret)>,
+    /// Report what would be created without creating anything
+    pub dry_run: bool,
+}
+
+impl MigrationOptions {
+    pub fn secret(&self, name: &str) -> Option<&Secret> {
+        self.secrets.iter().find(|(n, _)| n == name).map(|(_, value)| value)
+    }
+}
+
+/// Kinds of resources copied by a migration, in the order they are copied
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ResourceKind {
+    Label,
+    ComputeEnv,
+    Pipeline,
+    Secret,
+    Participant,
+}
+
+impl ResourceKind {
+    pub const ALL: [ResourceKind; 5] = [
+        ResourceKind::Label,
+        ResourceKind::ComputeEnv,
+        ResourceKind::Pipeline,
+        ResourceKind::Secret,
+        ResourceKind::Participant,
+    ];
+}
+
+impl fmt::Display for ResourceKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResourceKind::Label => write!(f, "labels"),
+            ResourceKind::ComputeEnv => write!(f, "compute envs"),
+            ResourceKind::Pipeline => write!(f, "pipelines"),
+            ResourceKind::Secret => write!(f, "secrets"),
+            ResourceKind::Participant => write!(f, "participants"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MigrationOutcome {
+    Created,
+    /// Would be created, in a dry run
+    Planned,
+    /// Left alone, with the reason, e.g. that it already exists
+    Skipped(String),
+    Failed(String),
+}
+
+/// What happened to one source resource
+#[derive(Debug, Clone)]
+pub struct MigratedResource {
+    pub kind: ResourceKind,
+    pub name: String,
+    pub outcome: MigrationOutcome,
+}
+
+impl fmt::Display for MigratedResource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = self.kind.to_string();
+        let kind = kind.trim_end_matches('s');
+        match &self.outcome {
+            MigrationOutcome::Created => write!(f, "  created  {} {}", kind, self.name),
+            MigrationOutcome::Planned => write!(f, "  create   {} {}", kind, self.name),
+            MigrationOutcome::Skipped(reason) => write!(f, "  skipped  {} {}: {}", kind, self.name, reason),
+            MigrationOutcome::Failed(error) => write!(f, "  failed   {} {}: {}", kind, self.name, error),
+        }
+    }
+}
+
+/// Outcome of a migration. Resources are independent of each other apart
+/// from the ID remapping, so a failure doesn't stop the rest.
+#[derive(Debug, Default)]
+pub struct MigrationReport {
+    pub resources: Vec<MigratedResource>,
+}
+
+impl MigrationReport {
+    pub fn push(&mut self, kind: ResourceKind, name: impl Into<String>, outcome: MigrationOutcome) {
+        self.resources.push(MigratedResource { kind, name: name.into(), outcome });
+    }
+
+    pub fn failures(&self) -> impl Iterator<Item = &MigratedResource> {
+        self.resources.iter().filter(|r| matches!(r.outcome, MigrationOutcome::Failed(_)))
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.failures().next().is_none()
+    }
+
+    /// Per kind of resource: how many were created (or planned), skipped and failed
+    pub fn summary(&self) -> String {
+        let mut out = String::new();
+        for kind in ResourceKind::ALL {
+            let count = |f: fn(&MigrationOutcome) -> bool| {
+                self.resources.iter().filter(|r| r.kind == kind && f(&r.outcome)).count()
+            };
+            let created = count(|o| matches!(o, MigrationOutcome::Created | MigrationOutcome::Planned));
+            let skipped = count(|o| matches!(o, MigrationOutcome::Skipped(_)));
+            let failed = count(|o| matches!(o, MigrationOutcome::Failed(_)));
+            out.push_str(&format!(
+                "{:<13} {} created, {} skipped, {} failed\n",
+                format!("{}:", kind),
+                created,
+                skipped,
+                failed
+            ));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mapping_lookup() {
+        let mapping = MigrationMapping::parse(
+            "credentials:\n  4xK2: aws-enterprise\n  gcp-keys: gcp-prod\ncomputeEnvs:\n  aws-cloud: aws-prod\n",
+            SpecFormat::Yaml,
+        )
+        .unwrap();
+        assert_eq!(mapping.credentials_for("4xK2", "aws-keys"), "aws-enterprise");
+        assert_eq!(mapping.credentials_for("9abc", "gcp-keys"), "gcp-prod");
+        assert_eq!(mapping.credentials_for("9abc", "azure-keys"), "azure-keys");
+        assert_eq!(mapping.compute_env_for("ce1", "aws-cloud"), "aws-prod");
+        assert_eq!(mapping.mapped_compute_env("ce2", "hpc"), None);
+        assert!(MigrationMapping::parse("{\"labels\": {}}", SpecFormat::Json).is_err());
+    }
+
+    #[test]
+    fn test_report_summary() {
+        let mut report = MigrationReport::default();
+        report.push(ResourceKind::Label, "project", MigrationOutcome::Created);
+        report.push(ResourceKind::Label, "owner=a", MigrationOutcome::Skipped("already exists".into()));
+        report.push(ResourceKind::Secret, "TOKEN", MigrationOutcome::Failed("no value supplied".into()));
+
+        assert!(!report.is_complete());
+        assert_eq!(report.resources[2].to_string(), "  failed   secret TOKEN: no value supplied");
+        let summary = report.summary();
+        assert!(summary.starts_with("labels:       1 created, 1 skipped, 0 failed\n"));
+        assert!(summary.contains("secrets:      0 created, 0 skipped, 1 failed\n"));
+    }
+}
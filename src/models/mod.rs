@@ -4,4 +4,20 @@ pub mod team;
 pub mod workspace;
 pub mod member;
 pub mod platform;
-pub mod compute_env;
\ No newline at end of file
+pub mod compute_env;
+pub mod credentials;
+pub mod pipeline_secret;
+pub mod label;
+pub mod data_link;
+pub mod dataset;
+pub mod studio;
+pub mod action;
+pub mod token;
+pub mod user;
+pub mod service_info;
+pub mod workspace_settings;
+pub mod audit;
+pub mod org_spec;
+pub mod pipeline;
+pub mod export;
+pub mod migration;
\ No newline at end of file
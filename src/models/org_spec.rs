@@ -0,0 +1,874 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+use std::path::Path;
+use super::common::{ParticipantType, WorkspaceId, WspRole};
+use super::compute_env::{ComputeConfig, ComputeEnv};
+use super::label::Label;
+use super::member::{Member, Participant};
+use super::team::Team;
+use super::workspace::{Workspace, WorkspaceVisibility};
+
+/// Declarative description of an organization's workspaces and teams,
+/// as read by `pform plan` and `pform apply`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OrgSpec {
+    /// Name of the organization, which must already exist
+    pub organization: String,
+    #[serde(default)]
+    pub teams: Vec<TeamSpec>,
+    #[serde(default)]
+    pub workspaces: Vec<WorkspaceSpec>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TeamSpec {
+    pub name: String,
+    pub description: Option<String>,
+    /// User names or emails of organization members
+    #[serde(default)]
+    pub members: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WorkspaceSpec {
+    pub name: String,
+    /// Defaults to the name
+    #[serde(rename = "fullName")]
+    pub full_name: Option<String>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub visibility: WorkspaceVisibility,
+    #[serde(default)]
+    pub participants: Vec<ParticipantSpec>,
+    #[serde(default)]
+    pub labels: Vec<LabelSpec>,
+    #[serde(rename = "computeEnvs", default)]
+    pub compute_envs: Vec<ComputeEnvSpec>,
+}
+
+impl WorkspaceSpec {
+    pub fn full_name(&self) -> &str {
+        self.full_name.as_deref().unwrap_or(&self.name)
+    }
+}
+
+/// A workspace participant: either `member` (user name or email) or `team`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ParticipantSpec {
+    pub member: Option<String>,
+    pub team: Option<String>,
+    #[serde(default = "default_participant_role")]
+    pub role: WspRole,
+}
+
+fn default_participant_role() -> WspRole {
+    WspRole::Launch
+}
+
+impl ParticipantSpec {
+    fn matches(&self, participant: &Participant) -> bool {
+        match (&self.member, &self.team) {
+            (_, Some(team)) => {
+                participant.participant_type == ParticipantType::Team
+                    && participant.team_name.as_deref() == Some(team.as_str())
+            }
+            (Some(member), None) => {
+                participant.participant_type != ParticipantType::Team
+                    && (participant.user_name.as_deref() == Some(member.as_str())
+                        || participant.email.as_deref() == Some(member.as_str()))
+            }
+            (None, None) => false,
+        }
+    }
+
+    fn label(&self) -> String {
+        match (&self.member, &self.team) {
+            (_, Some(team)) => format!("team:{}", team),
+            (Some(member), None) => member.clone(),
+            (None, None) => String::new(),
+        }
+    }
+}
+
+/// A simple label, or a resource label when `value` is set
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LabelSpec {
+    pub name: String,
+    pub value: Option<String>,
+    /// Apply the resource label by default to new compute environments and runs
+    #[serde(rename = "default", default)]
+    pub is_default: bool,
+}
+
+impl LabelSpec {
+    fn matches(&self, label: &Label) -> bool {
+        label.name == self.name && label.value.as_deref().filter(|v| !v.is_empty()) == self.value.as_deref()
+    }
+
+    fn label(&self) -> String {
+        match &self.value {
+            Some(value) => format!("{}={}", self.name, value),
+            None => self.name.clone(),
+        }
+    }
+}
+
+/// A compute environment, matched by name. Existing compute environments
+/// are not reconfigured, so they can be listed by name alone, e.g. to make
+/// them primary; `credentials` and `config` are needed to create missing ones.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ComputeEnvSpec {
+    pub name: String,
+    pub description: Option<String>,
+    /// Name of the workspace credentials to use
+    pub credentials: Option<String>,
+    /// Make this the workspace's primary compute environment
+    #[serde(default)]
+    pub primary: bool,
+    pub config: Option<ComputeConfig>,
+}
+
+impl ComputeEnvSpec {
+    /// The change creating this compute environment, which it must describe fully
+    fn create(&self, workspace: &str) -> Result<Change<'_>, String> {
+        match (&self.credentials, &self.config) {
+            (Some(credentials), Some(config)) => Ok(Change::CreateComputeEnv {
+                workspace: workspace.to_string(),
+                spec: self,
+                credentials,
+                config,
+            }),
+            _ => Err(format!(
+                "compute environment {} doesn't exist in workspace {}; creating it needs credentials and config",
+                self.name, workspace
+            )),
+        }
+    }
+}
+
+/// File format of a spec
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpecFormat {
+    Yaml,
+    Toml,
+    Json,
+}
+
+impl SpecFormat {
+    /// Guesses the format from the file extension
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()?.to_lowercase().as_str() {
+            "yaml" | "yml" => Some(SpecFormat::Yaml),
+            "toml" => Some(SpecFormat::Toml),
+            "json" => Some(SpecFormat::Json),
+            _ => None,
+        }
+    }
+}
+
+impl OrgSpec {
+    /// Parses and checks a spec
+    pub fn parse(text: &str, format: SpecFormat) -> Result<Self, String> {
+        let spec: OrgSpec = match format {
+            SpecFormat::Yaml => serde_yaml::from_str(text).map_err(|e| e.to_string())?,
+            SpecFormat::Toml => toml::from_str(text).map_err(|e| e.to_string())?,
+            SpecFormat::Json => serde_json::from_str(text).map_err(|e| e.to_string())?,
+        };
+        spec.check()?;
+        Ok(spec)
+    }
+
+    /// Checks for duplicate names and ambiguous entries
+    pub fn check(&self) -> Result<(), String> {
+        unique(self.teams.iter().map(|t| t.name.clone()), "team")?;
+        unique(self.workspaces.iter().map(|w| w.name.clone()), "workspace")?;
+        for team in &self.teams {
+            unique(team.members.iter().cloned(), &format!("member of team {}", team.name))?;
+        }
+        for workspace in &self.workspaces {
+            let context = |what: &str| format!("{} in workspace {}", what, workspace.name);
+            for participant in &workspace.participants {
+                if participant.member.is_some() == participant.team.is_some() {
+                    return Err(format!(
+                        "{} must set exactly one of member and team",
+                        context("a participant")
+                    ));
+                }
+            }
+            unique(workspace.participants.iter().map(ParticipantSpec::label), &context("participant"))?;
+            unique(workspace.labels.iter().map(LabelSpec::label), &context("label"))?;
+            unique(workspace.compute_envs.iter().map(|ce| ce.name.clone()), &context("compute environment"))?;
+            if workspace.compute_envs.iter().filter(|ce| ce.primary).count() > 1 {
+                return Err(context("more than one primary compute environment"));
+            }
+        }
+        Ok(())
+    }
+
+    /// The changes that bring `state` in line with this spec. Without `prune`
+    /// nothing is deleted or removed.
+    pub fn plan<'a>(&'a self, state: &OrgState, prune: bool) -> Result<OrgPlan<'a>, String> {
+        let mut changes = Vec::new();
+
+        for spec in &self.teams {
+            let Some(live) = state.teams.iter().find(|t| t.team.name == spec.name) else {
+                changes.push(Change::CreateTeam { spec });
+                for user in &spec.members {
+                    changes.push(Change::AddTeamMember { team: spec.name.clone(), user: user.clone() });
+                }
+                continue;
+            };
+            if spec.description.is_some() && live.team.description != spec.description {
+                changes.push(Change::UpdateTeam {
+                    team_id: live.team.team_id,
+                    spec,
+                    from: live.team.description.clone(),
+                });
+            }
+            for user in &spec.members {
+                if !live.members.iter().any(|m| member_matches(m, user)) {
+                    changes.push(Change::AddTeamMember { team: spec.name.clone(), user: user.clone() });
+                }
+            }
+            if prune {
+                for member in &live.members {
+                    if !spec.members.iter().any(|user| member_matches(member, user)) {
+                        changes.push(Change::RemoveTeamMember {
+                            team: spec.name.clone(),
+                            team_id: live.team.team_id,
+                            member_id: member.member_id,
+                            user: member.user_name.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        if prune {
+            for live in &state.teams {
+                if !self.teams.iter().any(|t| t.name == live.team.name) {
+                    changes.push(Change::DeleteTeam { team_id: live.team.team_id, name: live.team.name.clone() });
+                }
+            }
+        }
+
+        for spec in &self.workspaces {
+            for participant in &spec.participants {
+                if let Some(team) = &participant.team {
+                    if self.teams.iter().any(|t| &t.name == team) {
+                        continue;
+                    }
+                    if !state.teams.iter().any(|t| &t.team.name == team) {
+                        return Err(format!("workspace {} refers to unknown team {}", spec.name, team));
+                    }
+                    if prune {
+                        return Err(format!(
+                            "workspace {} refers to team {}, which the spec doesn't list and pruning would delete",
+                            spec.name, team
+                        ));
+                    }
+                }
+            }
+            match state.workspaces.iter().find(|w| w.workspace.name == spec.name) {
+                Some(live) => plan_workspace(&mut changes, spec, live, prune)?,
+                None => {
+                    changes.push(Change::CreateWorkspace { spec });
+                    let workspace = || spec.name.clone();
+                    for label in &spec.labels {
+                        changes.push(Change::CreateLabel { workspace: workspace(), spec: label });
+                    }
+                    for compute_env in &spec.compute_envs {
+                        changes.push(compute_env.create(&spec.name)?);
+                        if compute_env.primary {
+                            changes.push(Change::SetPrimaryComputeEnv { workspace: workspace(), name: compute_env.name.clone() });
+                        }
+                    }
+                    for participant in &spec.participants {
+                        changes.push(Change::AddParticipant { workspace: workspace(), spec: participant });
+                    }
+                }
+            }
+        }
+        if prune {
+            for live in &state.workspaces {
+                if !self.workspaces.iter().any(|w| w.name == live.workspace.name) {
+                    changes.push(Change::DeleteWorkspace {
+                        workspace_id: live.workspace.id,
+                        name: live.workspace.name.clone(),
+                    });
+                }
+            }
+        }
+
+        changes.sort_by_key(Change::order);
+        Ok(OrgPlan { organization: self.organization.clone(), changes })
+    }
+}
+
+fn plan_workspace<'a>(
+    changes: &mut Vec<Change<'a>>,
+    spec: &'a WorkspaceSpec,
+    live: &WorkspaceState,
+    prune: bool,
+) -> Result<(), String> {
+    let workspace_id = live.workspace.id;
+    let workspace = || spec.name.clone();
+
+    let mut differences = Vec::new();
+    if live.workspace.full_name != spec.full_name() {
+        differences.push(format!("fullName: {:?} -> {:?}", live.workspace.full_name, spec.full_name()));
+    }
+    if spec.description.is_some() && live.workspace.description != spec.description {
+        differences.push(format!(
+            "description: {:?} -> {:?}",
+            live.workspace.description.as_deref().unwrap_or_default(),
+            spec.description.as_deref().unwrap_or_default()
+        ));
+    }
+    if !live.workspace.visibility.eq_ignore_ascii_case(&spec.visibility.to_string()) {
+        differences.push(format!("visibility: {} -> {}", live.workspace.visibility, spec.visibility));
+    }
+    if !differences.is_empty() {
+        changes.push(Change::UpdateWorkspace { workspace_id, spec, differences });
+    }
+
+    for label in &spec.labels {
+        match live.labels.iter().find(|l| label.matches(l)) {
+            Some(existing) if existing.is_default != label.is_default => changes.push(Change::UpdateLabel {
+                workspace_id,
+                workspace: workspace(),
+                label_id: existing.id,
+                spec: label,
+            }),
+            Some(_) => {}
+            None => changes.push(Change::CreateLabel { workspace: workspace(), spec: label }),
+        }
+    }
+
+    for compute_env in &spec.compute_envs {
+        let existing = live.compute_envs.iter().find(|ce| ce.name == compute_env.name);
+        if existing.is_none() {
+            changes.push(compute_env.create(&spec.name)?);
+        }
+        if compute_env.primary && existing.is_none_or(|ce| ce.primary != Some(true)) {
+            changes.push(Change::SetPrimaryComputeEnv { workspace: workspace(), name: compute_env.name.clone() });
+        }
+    }
+
+    for participant in &spec.participants {
+        match live.participants.iter().find(|p| participant.matches(p)) {
+            Some(existing) if existing.wsp_role != participant.role => changes.push(Change::UpdateParticipantRole {
+                workspace_id,
+                workspace: workspace(),
+                participant_id: existing.participant_id,
+                name: participant.label(),
+                from: existing.wsp_role.clone(),
+                to: participant.role.clone(),
+            }),
+            Some(_) => {}
+            None => changes.push(Change::AddParticipant { workspace: workspace(), spec: participant }),
+        }
+    }
+
+    if !prune {
+        return Ok(());
+    }
+    for label in &live.labels {
+        if !spec.labels.iter().any(|l| l.matches(label)) {
+            let name = match label.value.as_deref().filter(|v| !v.is_empty()) {
+                Some(value) => format!("{}={}", label.name, value),
+                None => label.name.clone(),
+            };
+            changes.push(Change::DeleteLabel { workspace_id, workspace: workspace(), label_id: label.id, label: name });
+        }
+    }
+    for compute_env in &live.compute_envs {
+        if !spec.compute_envs.iter().any(|ce| ce.name == compute_env.name) {
+            changes.push(Change::DeleteComputeEnv {
+                workspace_id,
+                workspace: workspace(),
+                compute_env_id: compute_env.id.clone(),
+                name: compute_env.name.clone(),
+            });
+        }
+    }
+    // Owners can't be removed from a workspace
+    for participant in live.participants.iter().filter(|p| p.wsp_role != WspRole::Owner) {
+        if !spec.participants.iter().any(|p| p.matches(participant)) {
+            let name = match participant.participant_type {
+                ParticipantType::Team => format!("team:{}", participant.name()),
+                _ => participant.name().to_string(),
+            };
+            changes.push(Change::RemoveParticipant {
+                workspace_id,
+                workspace: workspace(),
+                participant_id: participant.participant_id,
+                name,
+            });
+        }
+    }
+    Ok(())
+}
+
+fn member_matches(member: &Member, user: &str) -> bool {
+    member.user_name == user || member.email == user
+}
+
+fn unique(names: impl Iterator<Item = String>, what: &str) -> Result<(), String> {
+    let mut seen = HashSet::new();
+    for name in names {
+        if !seen.insert(name.clone()) {
+            return Err(format!("duplicate {} {}", what, name));
+        }
+    }
+    Ok(())
+}
+
+/// Live state of an organization, as compared against an [`OrgSpec`]
+#[derive(Debug)]
+pub struct OrgState {
+    pub teams: Vec<TeamState>,
+    pub workspaces: Vec<WorkspaceState>,
+}
+
+#[derive(Debug)]
+pub struct TeamState {
+    pub team: Team,
+    pub members: Vec<Member>,
+}
+
+#[derive(Debug)]
+pub struct WorkspaceState {
+    pub workspace: Workspace,
+    pub participants: Vec<Participant>,
+    pub labels: Vec<Label>,
+    pub compute_envs: Vec<ComputeEnv>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    Create,
+    Update,
+    Delete,
+}
+
+impl fmt::Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChangeKind::Create => write!(f, "+"),
+            ChangeKind::Update => write!(f, "~"),
+            ChangeKind::Delete => write!(f, "-"),
+        }
+    }
+}
+
+/// One change of a plan. Resources that may not exist yet are referred to by
+/// name and resolved when the plan is applied.
+#[derive(Debug)]
+pub enum Change<'a> {
+    CreateTeam { spec: &'a TeamSpec },
+    UpdateTeam { team_id: i64, spec: &'a TeamSpec, from: Option<String> },
+    DeleteTeam { team_id: i64, name: String },
+    AddTeamMember { team: String, user: String },
+    RemoveTeamMember { team: String, team_id: i64, member_id: i64, user: String },
+    CreateWorkspace { spec: &'a WorkspaceSpec },
+    UpdateWorkspace { workspace_id: WorkspaceId, spec: &'a WorkspaceSpec, differences: Vec<String> },
+    DeleteWorkspace { workspace_id: WorkspaceId, name: String },
+    CreateLabel { workspace: String, spec: &'a LabelSpec },
+    UpdateLabel { workspace_id: WorkspaceId, workspace: String, label_id: i64, spec: &'a LabelSpec },
+    DeleteLabel { workspace_id: WorkspaceId, workspace: String, label_id: i64, label: String },
+    CreateComputeEnv {
+        workspace: String,
+        spec: &'a ComputeEnvSpec,
+        credentials: &'a str,
+        config: &'a ComputeConfig,
+    },
+    SetPrimaryComputeEnv { workspace: String, name: String },
+    DeleteComputeEnv { workspace_id: WorkspaceId, workspace: String, compute_env_id: String, name: String },
+    AddParticipant { workspace: String, spec: &'a ParticipantSpec },
+    UpdateParticipantRole {
+        workspace_id: WorkspaceId,
+        workspace: String,
+        participant_id: i64,
+        name: String,
+        from: WspRole,
+        to: WspRole,
+    },
+    RemoveParticipant { workspace_id: WorkspaceId, workspace: String, participant_id: i64, name: String },
+}
+
+impl Change<'_> {
+    pub fn kind(&self) -> ChangeKind {
+        match self {
+            Change::CreateTeam { .. }
+            | Change::AddTeamMember { .. }
+            | Change::CreateWorkspace { .. }
+            | Change::CreateLabel { .. }
+            | Change::CreateComputeEnv { .. }
+            | Change::AddParticipant { .. } => ChangeKind::Create,
+            Change::UpdateTeam { .. }
+            | Change::UpdateWorkspace { .. }
+            | Change::UpdateLabel { .. }
+            | Change::SetPrimaryComputeEnv { .. }
+            | Change::UpdateParticipantRole { .. } => ChangeKind::Update,
+            Change::DeleteTeam { .. }
+            | Change::RemoveTeamMember { .. }
+            | Change::DeleteWorkspace { .. }
+            | Change::DeleteLabel { .. }
+            | Change::DeleteComputeEnv { .. }
+            | Change::RemoveParticipant { .. } => ChangeKind::Delete,
+        }
+    }
+
+    /// Position in the apply order: teams before the workspaces they join,
+    /// workspaces before their contents, and deletions in reverse
+    fn order(&self) -> u8 {
+        match self {
+            Change::CreateTeam { .. } | Change::UpdateTeam { .. } => 0,
+            Change::AddTeamMember { .. } => 1,
+            Change::CreateWorkspace { .. } | Change::UpdateWorkspace { .. } => 2,
+            Change::CreateLabel { .. } | Change::UpdateLabel { .. } => 3,
+            Change::CreateComputeEnv { .. } => 4,
+            Change::SetPrimaryComputeEnv { .. } => 5,
+            Change::AddParticipant { .. } | Change::UpdateParticipantRole { .. } => 6,
+            Change::RemoveParticipant { .. } => 7,
+            Change::DeleteComputeEnv { .. } => 8,
+            Change::DeleteLabel { .. } => 9,
+            Change::RemoveTeamMember { .. } => 10,
+            Change::DeleteWorkspace { .. } => 11,
+            Change::DeleteTeam { .. } => 12,
+        }
+    }
+
+    /// Terraform-style address, e.g. `workspace.genomics.participant[alice]`
+    pub fn address(&self) -> String {
+        match self {
+            Change::CreateTeam { spec } | Change::UpdateTeam { spec, .. } => format!("team.{}", spec.name),
+            Change::DeleteTeam { name, .. } => format!("team.{}", name),
+            Change::AddTeamMember { team, user } | Change::RemoveTeamMember { team, user, .. } => {
+                format!("team.{}.member[{}]", team, user)
+            }
+            Change::CreateWorkspace { spec } | Change::UpdateWorkspace { spec, .. } => {
+                format!("workspace.{}", spec.name)
+            }
+            Change::DeleteWorkspace { name, .. } => format!("workspace.{}", name),
+            Change::CreateLabel { workspace, spec } | Change::UpdateLabel { workspace, spec, .. } => {
+                format!("workspace.{}.label[{}]", workspace, spec.label())
+            }
+            Change::DeleteLabel { workspace, label, .. } => format!("workspace.{}.label[{}]", workspace, label),
+            Change::CreateComputeEnv { workspace, spec, .. } => {
+                format!("workspace.{}.compute_env[{}]", workspace, spec.name)
+            }
+            Change::SetPrimaryComputeEnv { workspace, name } | Change::DeleteComputeEnv { workspace, name, .. } => {
+                format!("workspace.{}.compute_env[{}]", workspace, name)
+            }
+            Change::AddParticipant { workspace, spec } => {
+                format!("workspace.{}.participant[{}]", workspace, spec.label())
+            }
+            Change::UpdateParticipantRole { workspace, name, .. } | Change::RemoveParticipant { workspace, name, .. } => {
+                format!("workspace.{}.participant[{}]", workspace, name)
+            }
+        }
+    }
+
+    /// What changes, for updates and for creates with notable attributes
+    pub fn details(&self) -> Vec<String> {
+        match self {
+            Change::UpdateTeam { spec, from, .. } => vec![format!(
+                "description: {:?} -> {:?}",
+                from.as_deref().unwrap_or_default(),
+                spec.description.as_deref().unwrap_or_default()
+            )],
+            Change::UpdateWorkspace { differences, .. } => differences.clone(),
+            Change::UpdateLabel { spec, .. } => vec![format!("default: {} -> {}", !spec.is_default, spec.is_default)],
+            Change::SetPrimaryComputeEnv { .. } => vec!["primary: false -> true".to_string()],
+            Change::UpdateParticipantRole { from, to, .. } => vec![format!("role: {} -> {}", from, to)],
+            Change::AddParticipant { spec, .. } => vec![format!("role: {}", spec.role)],
+            Change::CreateComputeEnv { credentials, config, .. } => vec![
+                format!("platform: {}", config.platform().as_str()),
+                format!("credentials: {}", credentials),
+            ],
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl fmt::Display for Change<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.kind(), self.address())
+    }
+}
+
+/// The ordered changes to apply to an organization
+#[derive(Debug)]
+pub struct OrgPlan<'a> {
+    pub organization: String,
+    pub changes: Vec<Change<'a>>,
+}
+
+impl OrgPlan<'_> {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn count(&self, kind: ChangeKind) -> usize {
+        self.changes.iter().filter(|c| c.kind() == kind).count()
+    }
+}
+
+impl fmt::Display for OrgPlan<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No changes. Organization {} matches the spec.", self.organization);
+        }
+        for change in &self.changes {
+            writeln!(f, "  {}", change)?;
+            for detail in change.details() {
+                writeln!(f, "      {}", detail)?;
+            }
+        }
+        writeln!(
+            f,
+            "\nPlan: {} to add, {} to change, {} to destroy.",
+            self.count(ChangeKind::Create),
+            self.count(ChangeKind::Update),
+            self.count(ChangeKind::Delete)
+        )
+    }
+}
+
+/// One applied change, e.g. `+ team.bioinfo`
+#[derive(Debug)]
+pub struct ApplyStep {
+    pub description: String,
+    /// Why the change failed, if it did
+    pub error: Option<String>,
+}
+
+impl ApplyStep {
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Outcome of applying a plan. Later changes may depend on earlier ones, so
+/// applying stops at the first failure.
+#[derive(Debug)]
+pub struct ApplyReport {
+    pub steps: Vec<ApplyStep>,
+    /// Changes not attempted because an earlier one failed
+    pub skipped: usize,
+}
+
+impl ApplyReport {
+    pub fn failures(&self) -> impl Iterator<Item = &ApplyStep> {
+        self.steps.iter().filter(|step| !step.is_ok())
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.skipped == 0 && self.steps.iter().all(ApplyStep::is_ok)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::member::fixtures::member;
+    use serde_json::json;
+
+    const SPEC: &str = r#"
+organization: acme
+teams:
+  - name: bioinfo
+    members: [alice, bob@example.com]
+workspaces:
+  - name: genomics
+    description: Genomics pipelines
+    participants:
+      - team: bioinfo
+        role: maintain
+      - member: carol
+    labels:
+      - name: project
+      - name: owner
+        value: team_a
+        default: true
+    computeEnvs:
+      - name: aws-prod
+        credentials: aws-keys
+        primary: true
+        config:
+          discriminator: aws-batch
+          region: eu-west-1
+          workDir: s3://acme/work
+"#;
+
+    fn live_state() -> OrgState {
+        OrgState {
+            teams: vec![
+                TeamState {
+                    team: serde_json::from_value(json!({ "teamId": 7, "name": "bioinfo", "membersCount": 2 })).unwrap(),
+                    members: vec![member(1, "alice", "member"), member(2, "dave", "member")],
+                },
+                TeamState {
+                    team: serde_json::from_value(json!({ "teamId": 8, "name": "legacy", "membersCount": 0 })).unwrap(),
+                    members: vec![],
+                },
+            ],
+            workspaces: vec![WorkspaceState {
+                workspace: serde_json::from_value(json!({
+                    "id": 10, "name": "genomics", "fullName": "genomics",
+                    "description": "Old", "visibility": "PRIVATE"
+                }))
+                .unwrap(),
+                participants: vec![
+                    serde_json::from_value(json!({
+                        "participantId": 1, "teamId": 7, "teamName": "bioinfo", "wspRole": "launch", "type": "TEAM"
+                    }))
+                    .unwrap(),
+                    serde_json::from_value(json!({
+                        "participantId": 2, "userName": "erin", "wspRole": "view", "type": "MEMBER"
+                    }))
+                    .unwrap(),
+                ],
+                labels: vec![serde_json::from_value(json!({ "id": 3, "name": "project" })).unwrap()],
+                compute_envs: vec![],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_parse_yaml_and_toml() {
+        let spec = OrgSpec::parse(SPEC, SpecFormat::Yaml).unwrap();
+        assert_eq!(spec.workspaces[0].participants[1].role, WspRole::Launch);
+        assert_eq!(spec.workspaces[0].visibility, WorkspaceVisibility::Private);
+        assert!(spec.workspaces[0].labels[1].is_default);
+
+        let toml = r#"
+            organization = "acme"
+            [[workspaces]]
+            name = "dev"
+            visibility = "SHARED"
+            participants = [{ member = "alice", role = "admin" }]
+        "#;
+        let spec = OrgSpec::parse(toml, SpecFormat::Toml).unwrap();
+        assert_eq!(spec.workspaces[0].full_name(), "dev");
+        assert_eq!(spec.workspaces[0].participants[0].role, WspRole::Admin);
+
+        assert_eq!(SpecFormat::from_path(Path::new("org.yml")), Some(SpecFormat::Yaml));
+        assert_eq!(SpecFormat::from_path(Path::new("org.txt")), None);
+    }
+
+    #[test]
+    fn test_check_rejects_ambiguous_specs() {
+        let both = "organization: acme\nworkspaces:\n  - name: dev\n    participants:\n      - { member: a, team: b }\n";
+        assert!(OrgSpec::parse(both, SpecFormat::Yaml).unwrap_err().contains("exactly one"));
+        let duplicate = "organization: acme\nteams:\n  - name: a\n  - name: a\n";
+        assert_eq!(OrgSpec::parse(duplicate, SpecFormat::Yaml).unwrap_err(), "duplicate team a");
+        assert!(OrgSpec::parse("organisation: acme", SpecFormat::Yaml).is_err());
+    }
+
+    #[test]
+    fn test_plan_without_prune() {
+        let spec = OrgSpec::parse(SPEC, SpecFormat::Yaml).unwrap();
+        let state = live_state();
+        let plan = spec.plan(&state, false).unwrap();
+        let lines: Vec<String> = plan.changes.iter().map(|c| c.to_string()).collect();
+        assert_eq!(
+            lines,
+            vec![
+                "+ team.bioinfo.member[bob@example.com]",
+                "~ workspace.genomics",
+                "+ workspace.genomics.label[owner=team_a]",
+                "+ workspace.genomics.compute_env[aws-prod]",
+                "~ workspace.genomics.compute_env[aws-prod]",
+                "~ workspace.genomics.participant[team:bioinfo]",
+                "+ workspace.genomics.participant[carol]",
+            ]
+        );
+        assert_eq!(plan.changes[1].details(), vec!["description: \"Old\" -> \"Genomics pipelines\""]);
+        assert!(plan.to_string().ends_with("Plan: 4 to add, 3 to change, 0 to destroy.\n"));
+    }
+
+    #[test]
+    fn test_plan_with_prune() {
+        let spec = OrgSpec::parse(SPEC, SpecFormat::Yaml).unwrap();
+        let state = live_state();
+        let plan = spec.plan(&state, true).unwrap();
+        let deletes: Vec<String> = plan
+            .changes
+            .iter()
+            .filter(|c| c.kind() == ChangeKind::Delete)
+            .map(|c| c.to_string())
+            .collect();
+        assert_eq!(
+            deletes,
+            vec!["- workspace.genomics.participant[erin]", "- team.bioinfo.member[dave]", "- team.legacy"]
+        );
+    }
+
+    #[test]
+    fn test_plan_rejects_references_to_pruned_teams() {
+        let spec = "organization: acme\nworkspaces:\n  - name: genomics\n    participants:\n      - team: legacy\n";
+        let spec = OrgSpec::parse(spec, SpecFormat::Yaml).unwrap();
+        let state = live_state();
+        assert!(spec.plan(&state, false).is_ok());
+        assert_eq!(
+            spec.plan(&state, true).unwrap_err(),
+            "workspace genomics refers to team legacy, which the spec doesn't list and pruning would delete"
+        );
+    }
+
+    #[test]
+    fn test_plan_compute_env_by_name() {
+        let spec = "organization: acme\nworkspaces:\n  - name: genomics\n    computeEnvs:\n      - { name: aws-prod, primary: true }\n";
+        let spec = OrgSpec::parse(spec, SpecFormat::Yaml).unwrap();
+        let mut state = live_state();
+        assert_eq!(
+            spec.plan(&state, false).unwrap_err(),
+            "compute environment aws-prod doesn't exist in workspace genomics; creating it needs credentials and config"
+        );
+
+        state.workspaces[0].compute_envs.push(
+            serde_json::from_value(json!({
+                "id": "ce-1", "name": "aws-prod", "platform": "aws-batch", "status": "AVAILABLE",
+                "visibility": "PRIVATE", "workDir": "s3://acme/work", "workspaceName": "genomics",
+                "credentialsId": "cred-1", "primary": false
+            }))
+            .unwrap(),
+        );
+        let plan = spec.plan(&state, false).unwrap();
+        let compute_envs: Vec<String> = plan
+            .changes
+            .iter()
+            .map(|c| c.to_string())
+            .filter(|c| c.contains("compute_env"))
+            .collect();
+        assert_eq!(compute_envs, vec!["~ workspace.genomics.compute_env[aws-prod]"]);
+    }
+
+    #[test]
+    fn test_plan_new_workspace_and_unknown_team() {
+        let spec = OrgSpec::parse(SPEC, SpecFormat::Yaml).unwrap();
+        let state = OrgState { teams: vec![], workspaces: vec![] };
+        let plan = spec.plan(&state, true).unwrap();
+        assert_eq!(plan.changes[0].to_string(), "+ team.bioinfo");
+        assert_eq!(plan.count(ChangeKind::Create), 9);
+        assert_eq!(plan.count(ChangeKind::Delete), 0);
+
+        let orphan = "organization: acme\nworkspaces:\n  - name: dev\n    participants:\n      - team: ghosts\n";
+        let spec = OrgSpec::parse(orphan, SpecFormat::Yaml).unwrap();
+        assert_eq!(spec.plan(&state, false).unwrap_err(), "workspace dev refers to unknown team ghosts");
+        assert!(OrgSpec { organization: "acme".into(), teams: vec![], workspaces: vec![] }
+            .plan(&state, false)
+            .unwrap()
+            .to_string()
+            .starts_with("No changes."));
+    }
+}
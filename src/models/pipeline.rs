@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use super::action::ActionLaunch;
+use super::common::{Validate, ValidationError, validation};
+use super::label::Label;
+
+/// A pipeline in a workspace's launchpad
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Pipeline {
+    #[serde(rename = "pipelineId")]
+    pub pipeline_id: i64,
+    pub name: String,
+    pub description: Option<String>,
+    pub icon: Option<String>,
+    /// Pipeline repository URL
+    pub repository: Option<String>,
+    #[serde(rename = "userName")]
+    pub user_name: Option<String>,
+    #[serde(rename = "lastUpdated")]
+    pub last_updated: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub labels: Vec<Label>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListPipelinesResponse {
+    pub pipelines: Vec<Pipeline>,
+    #[serde(rename = "totalSize")]
+    pub total_size: i64,
+}
+
+/// The compute environment a pipeline launches on
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LaunchComputeEnv {
+    pub id: String,
+    pub name: String,
+}
+
+/// A pipeline's saved launch configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineLaunch {
+    pub id: Option<String>,
+    #[serde(rename = "computeEnv")]
+    pub compute_env: Option<LaunchComputeEnv>,
+    /// Pipeline repository URL
+    pub pipeline: String,
+    #[serde(rename = "workDir")]
+    pub work_dir: Option<String>,
+    pub revision: Option<String>,
+    /// Pipeline parameters as JSON or YAML text
+    #[serde(rename = "paramsText")]
+    pub params_text: Option<String>,
+    #[serde(rename = "configProfiles", default)]
+    pub config_profiles: Vec<String>,
+    #[serde(rename = "configText")]
+    pub config_text: Option<String>,
+    #[serde(rename = "preRunScript")]
+    pub pre_run_script: Option<String>,
+    #[serde(rename = "postRunScript")]
+    pub post_run_script: Option<String>,
+    #[serde(rename = "pullLatest")]
+    pub pull_latest: Option<bool>,
+    /// Launch fields not modelled above, e.g. `mainScript` or `headJobCpus`
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+impl PipelineLaunch {
+    /// The launch request that recreates this configuration, on
+    /// `compute_env_id` and with `label_ids`. Fields not modelled here are
+    /// carried over as they are; only the source's own IDs are left out.
+    pub fn to_launch_request(&self, compute_env_id: Option<String>, label_ids: Vec<i64>) -> ActionLaunch {
+        ActionLaunch {
+            compute_env_id,
+            pipeline: self.pipeline.clone(),
+            work_dir: self.work_dir.clone(),
+            revision: self.revision.clone(),
+            params_text: self.params_text.clone(),
+            config_profiles: self.config_profiles.clone(),
+            config_text: self.config_text.clone(),
+            pre_run_script: self.pre_run_script.clone(),
+            post_run_script: self.post_run_script.clone(),
+            pull_latest: self.pull_latest,
+            label_ids,
+            other: self.other.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DescribePipelineLaunchResponse {
+    pub launch: PipelineLaunch,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreatePipelineRequest {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    pub launch: ActionLaunch,
+    #[serde(rename = "labelIds", skip_serializing_if = "Vec::is_empty")]
+    pub label_ids: Vec<i64>,
+}
+
+impl Validate for CreatePipelineRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validation::validate_max_length(&self.name, 99, "name")?;
+        if let Some(ref desc) = self.description {
+            validation::validate_max_length(desc, 1000, "description")?;
+        }
+        validation::validate_required(&self.launch.pipeline, "pipeline")
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreatePipelineResponse {
+    pub pipeline: Pipeline,
+}
@@ -0,0 +1,198 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use std::fmt;
+use super::common::{Secret, Validate, ValidationError, validation, WorkspaceId};
+
+/// Where a pipeline secret lives: in a workspace or in the current user's
+/// personal context
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecretScope {
+    Workspace(WorkspaceId),
+    User,
+}
+
+impl From<WorkspaceId> for SecretScope {
+    fn from(id: WorkspaceId) -> Self {
+        SecretScope::Workspace(id)
+    }
+}
+
+impl From<i64> for SecretScope {
+    fn from(id: i64) -> Self {
+        SecretScope::Workspace(WorkspaceId(id))
+    }
+}
+
+impl fmt::Display for SecretScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SecretScope::Workspace(id) => write!(f, "workspace {}", id),
+            SecretScope::User => write!(f, "user"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PipelineSecret {
+    pub id: i64,
+    pub name: String,
+    #[serde(rename = "lastUsed")]
+    pub last_used: Option<DateTime<Utc>>,
+    #[serde(rename = "dateCreated")]
+    pub date_created: Option<DateTime<Utc>>,
+    #[serde(rename = "lastUpdated")]
+    pub last_updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListPipelineSecretsResponse {
+    #[serde(rename = "pipelineSecrets")]
+    pub pipeline_secrets: Vec<PipelineSecret>,
+    #[serde(rename = "totalSize")]
+    pub total_size: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DescribePipelineSecretResponse {
+    #[serde(rename = "pipelineSecret")]
+    pub pipeline_secret: PipelineSecret,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreatePipelineSecretRequest {
+    pub name: String,
+    pub value: Secret,
+}
+
+impl Validate for CreatePipelineSecretRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate_secret_name(&self.name)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreatePipelineSecretResponse {
+    #[serde(rename = "secretId")]
+    pub secret_id: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdatePipelineSecretRequest {
+    pub value: Secret,
+}
+
+/// Secret names become `secrets.NAME` in Nextflow, so they must be valid identifiers
+pub fn validate_secret_name(name: &str) -> Result<(), ValidationError> {
+    validation::validate_max_length(name, 100, "name")?;
+    lazy_static::lazy_static! {
+        static ref NAME_PATTERN: regex::Regex = regex::Regex::new(
+            r"^[a-zA-Z_][a-zA-Z\d_]*$"
+        ).unwrap();
+    }
+    validation::validate_pattern(name, &NAME_PATTERN, "name")
+}
+
+/// Parses `.env`-style content into secret name/value pairs.
+///
+/// Blank lines and `#` comments are ignored, an optional `export ` prefix is
+/// accepted, and values may be wrapped in single or double quotes.
+pub fn parse_env_file(content: &str) -> Result<Vec<(String, Secret)>, String> {
+    let mut entries: Vec<(String, Secret)> = Vec::new();
+
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").unwrap_or(line);
+
+        let (name, value) = line
+            .split_once('=')
+            .ok_or_else(|| format!("line {}: expected NAME=VALUE", index + 1))?;
+        let name = name.trim();
+        validate_secret_name(name)
+            .map_err(|_| format!("line {}: invalid secret name '{}'", index + 1, name))?;
+
+        let value = value.trim();
+        let value = ['"', '\'']
+            .iter()
+            .find_map(|q| value.strip_prefix(*q).and_then(|v| v.strip_suffix(*q)))
+            .unwrap_or(value);
+
+        if entries.iter().any(|(existing, _)| existing == name) {
+            return Err(format!("line {}: duplicate secret '{}'", index + 1, name));
+        }
+        entries.push((name.to_string(), Secret::new(value)));
+    }
+
+    Ok(entries)
+}
+
+/// A single change computed by a secrets sync
+#[derive(Debug)]
+pub enum SecretChange {
+    Add { name: String, value: Secret },
+    /// Values cannot be read back from the API, so every secret present both
+    /// locally and remotely is overwritten
+    Update { id: i64, name: String, value: Secret },
+    Delete { id: i64, name: String },
+}
+
+impl fmt::Display for SecretChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SecretChange::Add { name, .. } => write!(f, "+ {}", name),
+            SecretChange::Update { name, .. } => write!(f, "~ {}", name),
+            SecretChange::Delete { name, .. } => write!(f, "- {}", name),
+        }
+    }
+}
+
+/// The set of changes needed to make remote secrets match a local file
+#[derive(Debug, Default)]
+pub struct SecretSyncPlan {
+    pub changes: Vec<SecretChange>,
+}
+
+impl SecretSyncPlan {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_env_file() {
+        let content = r#"
+# comment
+FOO=bar
+export API_TOKEN="abc=123"
+EMPTY=
+QUOTED='single'
+"#;
+        let entries = parse_env_file(content).unwrap();
+        let names: Vec<&str> = entries.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, vec!["FOO", "API_TOKEN", "EMPTY", "QUOTED"]);
+        assert_eq!(entries[0].1.expose(), "bar");
+        assert_eq!(entries[1].1.expose(), "abc=123");
+        assert_eq!(entries[2].1.expose(), "");
+        assert_eq!(entries[3].1.expose(), "single");
+    }
+
+    #[test]
+    fn test_parse_env_file_errors() {
+        assert!(parse_env_file("NOT_AN_ASSIGNMENT").unwrap_err().contains("line 1"));
+        assert!(parse_env_file("1BAD=x").is_err());
+        assert!(parse_env_file("A=1\nA=2").unwrap_err().contains("duplicate"));
+    }
+
+    #[test]
+    fn test_secret_name_validation() {
+        assert!(validate_secret_name("MY_SECRET").is_ok());
+        assert!(validate_secret_name("my-secret").is_err());
+        assert!(validate_secret_name(&"A".repeat(101)).is_err());
+    }
+}
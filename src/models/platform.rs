@@ -1,8 +1,137 @@
 use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+use super::compute_env::ComputeConfig;
+use super::credentials::CredentialsProvider;
 
+/// Canonical compute platform identifier, as used by the API (`aws-batch`,
+/// `slurm-platform`, ...). Unknown IDs are preserved in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum PlatformId {
+    AwsBatch,
+    GoogleLifeSciences,
+    GoogleBatch,
+    AzureBatch,
+    Kubernetes,
+    Eks,
+    Gke,
+    Uge,
+    Slurm,
+    Lsf,
+    Altair,
+    Moab,
+    Other(String),
+}
+
+impl PlatformId {
+    pub fn as_str(&self) -> &str {
+        match self {
+            PlatformId::AwsBatch => "aws-batch",
+            PlatformId::GoogleLifeSciences => "google-lifesciences",
+            PlatformId::GoogleBatch => "google-batch",
+            PlatformId::AzureBatch => "azure-batch",
+            PlatformId::Kubernetes => "k8s-platform",
+            PlatformId::Eks => "eks-platform",
+            PlatformId::Gke => "gke-platform",
+            PlatformId::Uge => "uge-platform",
+            PlatformId::Slurm => "slurm-platform",
+            PlatformId::Lsf => "lsf-platform",
+            PlatformId::Altair => "altair-platform",
+            PlatformId::Moab => "moab-platform",
+            PlatformId::Other(id) => id,
+        }
+    }
+
+    /// The kind of credentials compute environments on this platform use
+    pub fn credentials_provider(&self) -> Option<CredentialsProvider> {
+        match self {
+            PlatformId::AwsBatch | PlatformId::Eks => Some(CredentialsProvider::Aws),
+            PlatformId::GoogleLifeSciences | PlatformId::GoogleBatch | PlatformId::Gke => {
+                Some(CredentialsProvider::Google)
+            }
+            PlatformId::AzureBatch => Some(CredentialsProvider::Azure),
+            PlatformId::Kubernetes => Some(CredentialsProvider::Kubernetes),
+            PlatformId::Uge
+            | PlatformId::Slurm
+            | PlatformId::Lsf
+            | PlatformId::Altair
+            | PlatformId::Moab => Some(CredentialsProvider::Ssh),
+            PlatformId::Other(_) => None,
+        }
+    }
+
+    /// Whether `config` is the `ComputeConfig` variant for this platform
+    pub fn matches_config(&self, config: &ComputeConfig) -> bool {
+        config.platform() == *self
+    }
+
+    /// HPC schedulers reached over SSH
+    pub fn is_grid(&self) -> bool {
+        matches!(
+            self,
+            PlatformId::Uge | PlatformId::Slurm | PlatformId::Lsf | PlatformId::Altair | PlatformId::Moab
+        )
+    }
+}
+
+impl fmt::Display for PlatformId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl From<&str> for PlatformId {
+    fn from(id: &str) -> Self {
+        match id {
+            "aws-batch" => PlatformId::AwsBatch,
+            "google-lifesciences" => PlatformId::GoogleLifeSciences,
+            "google-batch" => PlatformId::GoogleBatch,
+            "azure-batch" => PlatformId::AzureBatch,
+            "k8s-platform" => PlatformId::Kubernetes,
+            "eks-platform" => PlatformId::Eks,
+            "gke-platform" => PlatformId::Gke,
+            "uge-platform" => PlatformId::Uge,
+            "slurm-platform" => PlatformId::Slurm,
+            "lsf-platform" => PlatformId::Lsf,
+            "altair-platform" => PlatformId::Altair,
+            "moab-platform" => PlatformId::Moab,
+            other => PlatformId::Other(other.to_string()),
+        }
+    }
+}
+
+impl From<String> for PlatformId {
+    fn from(id: String) -> Self {
+        PlatformId::from(id.as_str())
+    }
+}
+
+impl From<&String> for PlatformId {
+    fn from(id: &String) -> Self {
+        PlatformId::from(id.as_str())
+    }
+}
+
+impl From<PlatformId> for String {
+    fn from(id: PlatformId) -> Self {
+        id.as_str().to_string()
+    }
+}
+
+impl FromStr for PlatformId {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(PlatformId::from(s))
+    }
+}
+
+/// A compute platform available to a workspace
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Platform {
-    pub id: String,
+    pub id: PlatformId,
     pub name: String,
     #[serde(rename = "credentialsProviders")]
     pub credentials_providers: Vec<String>,
@@ -73,40 +202,119 @@ pub struct Filestore {
     pub location: String,
 }
 
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AwsMetaInfo {
+    pub warnings: Vec<String>,
+    #[serde(rename = "jobQueues")]
+    pub job_queues: Vec<JobQueue>,
+    pub buckets: Vec<Bucket>,
+    #[serde(rename = "fileSystems")]
+    pub file_systems: Vec<FileSystem>,
+    #[serde(rename = "efsFileSystems")]
+    pub efs_file_systems: Vec<EfsFileSystem>,
+    #[serde(rename = "keyPairs")]
+    pub key_pairs: Vec<String>,
+    pub vpcs: Vec<Vpc>,
+    pub images: Vec<Image>,
+    #[serde(rename = "securityGroups")]
+    pub security_groups: Vec<SecurityGroup>,
+    pub subnets: Vec<Subnet>,
+    #[serde(rename = "instanceFamilies")]
+    pub instance_families: Vec<String>,
+    #[serde(rename = "allocStrategy")]
+    pub alloc_strategy: Vec<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GoogleMetaInfo {
+    pub warnings: Vec<String>,
+    pub locations: Vec<String>,
+    pub zones: Vec<String>,
+    pub buckets: Vec<Bucket>,
+    pub filestores: Vec<Filestore>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AzureMetaInfo {
+    pub warnings: Vec<String>,
+    pub pools: Vec<String>,
+    pub containers: Vec<String>,
+    #[serde(rename = "vmTypes")]
+    pub vm_types: Vec<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
+pub struct Cluster {
+    pub id: String,
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KubernetesMetaInfo {
+    pub warnings: Vec<String>,
+    pub clusters: Vec<Cluster>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GridMetaInfo {
+    pub warnings: Vec<String>,
+}
+
+/// Platform-specific resources discovered with the given credentials and region.
+///
+/// The API response carries no discriminator, so the variant is chosen from
+/// the platform ID that was requested.
+#[derive(Debug, Serialize)]
 #[serde(untagged)]
 pub enum PlatformMetaInfo {
-    Type1 {
-        warnings: Vec<String>,
-        #[serde(rename = "jobQueues")]
-        job_queues: Vec<JobQueue>,
-        buckets: Vec<Bucket>,
-        #[serde(rename = "fileSystems")]
-        file_systems: Vec<FileSystem>,
-        #[serde(rename = "efsFileSystems")]
-        efs_file_systems: Vec<EfsFileSystem>,
-        #[serde(rename = "keyPairs")]
-        key_pairs: Vec<String>,
-        vpcs: Vec<Vpc>,
-        images: Vec<Image>,
-        #[serde(rename = "securityGroups")]
-        security_groups: Vec<SecurityGroup>,
-        subnets: Vec<Subnet>,
-        #[serde(rename = "instanceFamilies")]
-        instance_families: Vec<String>,
-        #[serde(rename = "allocStrategy")]
-        alloc_strategy: Vec<String>,
-    },
-    Type2 {
-        locations: Vec<String>,
-        warnings: Vec<String>,
-        zones: Vec<String>,
-        buckets: Vec<Bucket>,
-        filestores: Vec<Filestore>,
-    },
+    Aws(AwsMetaInfo),
+    Google(GoogleMetaInfo),
+    Azure(AzureMetaInfo),
+    Kubernetes(KubernetesMetaInfo),
+    Grid(GridMetaInfo),
+    /// Platforms without a typed model
+    Raw(serde_json::Value),
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+impl PlatformMetaInfo {
+    /// A missing or null `value` counts as empty metainfo
+    pub fn from_value(platform: &PlatformId, value: serde_json::Value) -> Result<Self, serde_json::Error> {
+        let value = match value {
+            serde_json::Value::Null => serde_json::Value::Object(serde_json::Map::new()),
+            value => value,
+        };
+        Ok(match platform {
+            PlatformId::AwsBatch => PlatformMetaInfo::Aws(serde_json::from_value(value)?),
+            PlatformId::GoogleBatch | PlatformId::GoogleLifeSciences => {
+                PlatformMetaInfo::Google(serde_json::from_value(value)?)
+            }
+            PlatformId::AzureBatch => PlatformMetaInfo::Azure(serde_json::from_value(value)?),
+            PlatformId::Kubernetes | PlatformId::Eks | PlatformId::Gke => {
+                PlatformMetaInfo::Kubernetes(serde_json::from_value(value)?)
+            }
+            platform if platform.is_grid() => PlatformMetaInfo::Grid(serde_json::from_value(value)?),
+            _ => PlatformMetaInfo::Raw(value),
+        })
+    }
+
+    pub fn warnings(&self) -> &[String] {
+        match self {
+            PlatformMetaInfo::Aws(info) => &info.warnings,
+            PlatformMetaInfo::Google(info) => &info.warnings,
+            PlatformMetaInfo::Azure(info) => &info.warnings,
+            PlatformMetaInfo::Kubernetes(info) => &info.warnings,
+            PlatformMetaInfo::Grid(info) => &info.warnings,
+            PlatformMetaInfo::Raw(_) => &[],
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
 pub struct PlatformDetailsResponse {
     pub metainfo: PlatformMetaInfo,
 }
@@ -120,4 +328,35 @@ pub struct PlatformRegion {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ListPlatformRegionsResponse {
     pub regions: Vec<PlatformRegion>,
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_platform_id_round_trip() {
+        for id in [
+            "aws-batch", "google-lifesciences", "google-batch", "azure-batch", "k8s-platform",
+            "eks-platform", "gke-platform", "uge-platform", "slurm-platform", "lsf-platform",
+            "altair-platform", "moab-platform",
+        ] {
+            let platform: PlatformId = id.parse().unwrap();
+            assert!(!matches!(platform, PlatformId::Other(_)), "{} should be known", id);
+            assert_eq!(platform.to_string(), id);
+            assert_eq!(serde_json::to_value(&platform).unwrap(), id);
+        }
+
+        let platform: PlatformId = serde_json::from_str("\"local-platform\"").unwrap();
+        assert_eq!(platform, PlatformId::Other("local-platform".to_string()));
+        assert_eq!(platform.to_string(), "local-platform");
+        assert!(platform.credentials_provider().is_none());
+    }
+
+    #[test]
+    fn test_platform_credentials_provider() {
+        assert_eq!(PlatformId::Eks.credentials_provider(), Some(CredentialsProvider::Aws));
+        assert_eq!(PlatformId::Gke.credentials_provider(), Some(CredentialsProvider::Google));
+        assert_eq!(PlatformId::Moab.credentials_provider(), Some(CredentialsProvider::Ssh));
+    }
+}
@@ -0,0 +1,194 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// A `major.minor.patch` API version
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ApiVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl ApiVersion {
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self { major, minor, patch }
+    }
+}
+
+impl fmt::Display for ApiVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+impl FromStr for ApiVersion {
+    type Err = String;
+
+    /// Parses `1.23.0`, `1.23` or `1.23.0-rc1`; pre-release suffixes are ignored
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let core = s.trim().split(['-', '+']).next().unwrap_or_default();
+        let mut parts = core.split('.').map(|p| p.parse::<u32>());
+        let mut next = |required: bool| match parts.next() {
+            Some(Ok(n)) => Ok(n),
+            None if !required => Ok(0),
+            _ => Err(format!("invalid API version '{}'", s)),
+        };
+        let version = ApiVersion::new(next(true)?, next(true)?, next(false)?);
+        if parts.next().is_some() {
+            return Err(format!("invalid API version '{}'", s));
+        }
+        Ok(version)
+    }
+}
+
+/// Optional server features that older Enterprise releases may lack
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Feature {
+    DataStudios,
+    DataLinks,
+    Fusion,
+    Wave,
+}
+
+impl Feature {
+    /// First API version that exposes the feature
+    pub fn min_api_version(&self) -> ApiVersion {
+        match self {
+            Feature::DataStudios => ApiVersion::new(1, 22, 0),
+            Feature::DataLinks => ApiVersion::new(1, 12, 0),
+            Feature::Fusion => ApiVersion::new(1, 9, 0),
+            Feature::Wave => ApiVersion::new(1, 9, 0),
+        }
+    }
+
+    /// Service info flag that switches the feature on or off, where there is one
+    fn flag(&self) -> Option<&'static str> {
+        match self {
+            Feature::Wave => Some("waveEnabled"),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Feature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Feature::DataStudios => write!(f, "Data Studios"),
+            Feature::DataLinks => write!(f, "Data Links"),
+            Feature::Fusion => write!(f, "Fusion"),
+            Feature::Wave => write!(f, "Wave"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceInfo {
+    /// Platform application version, e.g. `v24.1.1`
+    pub version: String,
+    #[serde(rename = "apiVersion")]
+    pub api_version: String,
+    #[serde(rename = "commitId")]
+    pub commit_id: Option<String>,
+    #[serde(rename = "authTypes", default)]
+    pub auth_types: Vec<String>,
+    #[serde(rename = "loginPath")]
+    pub login_path: Option<String>,
+    #[serde(rename = "seqeraCloud")]
+    pub seqera_cloud: Option<bool>,
+    #[serde(rename = "waveEnabled")]
+    pub wave_enabled: Option<bool>,
+    #[serde(rename = "userWorkspaceEnabled")]
+    pub user_workspace_enabled: Option<bool>,
+    #[serde(rename = "allowInstanceCredentials")]
+    pub allow_instance_credentials: Option<bool>,
+    /// Fields not modelled above, which vary between releases
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ServiceInfo {
+    /// The parsed API version, or `None` when the server reports one we can't read
+    pub fn api_version(&self) -> Option<ApiVersion> {
+        self.api_version.parse().ok()
+    }
+
+    /// All boolean flags the server reports, keyed by their API name
+    pub fn feature_flags(&self) -> BTreeMap<String, bool> {
+        let value = serde_json::to_value(self).unwrap_or_default();
+        value
+            .as_object()
+            .into_iter()
+            .flatten()
+            .filter_map(|(k, v)| v.as_bool().map(|b| (k.clone(), b)))
+            .collect()
+    }
+
+    /// Whether the server supports `feature`. An explicit flag wins over the
+    /// API version; an unreadable version is given the benefit of the doubt.
+    pub fn supports(&self, feature: Feature) -> bool {
+        if let Some(enabled) = feature.flag().and_then(|f| self.feature_flags().get(f).copied()) {
+            return enabled;
+        }
+        self.api_version()
+            .is_none_or(|version| version >= feature.min_api_version())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ServiceInfoResponse {
+    #[serde(rename = "serviceInfo")]
+    pub service_info: ServiceInfo,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn service_info(api_version: &str, wave_enabled: Option<bool>) -> ServiceInfo {
+        serde_json::from_value(json!({
+            "version": "v23.1.0",
+            "apiVersion": api_version,
+            "authTypes": ["github", "oidc"],
+            "waveEnabled": wave_enabled,
+            "groundswellEnabled": true
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_api_version() {
+        assert_eq!("1.23.0".parse(), Ok(ApiVersion::new(1, 23, 0)));
+        assert_eq!("1.9".parse(), Ok(ApiVersion::new(1, 9, 0)));
+        assert_eq!("1.25.1-rc2".parse(), Ok(ApiVersion::new(1, 25, 1)));
+        assert!("one".parse::<ApiVersion>().is_err());
+        assert!("1.2.3.4".parse::<ApiVersion>().is_err());
+        assert!(ApiVersion::new(1, 9, 0) < ApiVersion::new(1, 12, 0));
+    }
+
+    #[test]
+    fn test_supports_by_api_version() {
+        let info = service_info("1.15.0", None);
+        assert!(info.supports(Feature::DataLinks));
+        assert!(info.supports(Feature::Fusion));
+        assert!(!info.supports(Feature::DataStudios));
+
+        assert!(service_info("unknown", None).supports(Feature::DataStudios));
+    }
+
+    #[test]
+    fn test_flag_overrides_api_version() {
+        assert!(!service_info("1.30.0", Some(false)).supports(Feature::Wave));
+        assert!(service_info("1.30.0", Some(true)).supports(Feature::Wave));
+    }
+
+    #[test]
+    fn test_feature_flags() {
+        let flags = service_info("1.15.0", Some(true)).feature_flags();
+        assert_eq!(flags.get("waveEnabled"), Some(&true));
+        assert_eq!(flags.get("groundswellEnabled"), Some(&true));
+        assert!(!flags.contains_key("version"));
+    }
+}
@@ -0,0 +1,288 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use std::fmt;
+use super::common::{Validate, ValidationError, validation};
+use super::data_link::DataLink;
+use super::platform::PlatformId;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StudioStatus {
+    Starting,
+    Running,
+    Stopping,
+    Stopped,
+    Errored,
+    Building,
+    BuildFailed,
+    #[serde(other)]
+    Unknown,
+}
+
+impl StudioStatus {
+    /// Whether the studio counts towards the running studios quota
+    pub fn is_active(&self) -> bool {
+        matches!(
+            self,
+            StudioStatus::Starting | StudioStatus::Running | StudioStatus::Building
+        )
+    }
+}
+
+impl fmt::Display for StudioStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StudioStatus::Starting => write!(f, "starting"),
+            StudioStatus::Running => write!(f, "running"),
+            StudioStatus::Stopping => write!(f, "stopping"),
+            StudioStatus::Stopped => write!(f, "stopped"),
+            StudioStatus::Errored => write!(f, "errored"),
+            StudioStatus::Building => write!(f, "building"),
+            StudioStatus::BuildFailed => write!(f, "buildFailed"),
+            StudioStatus::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StudioStatusInfo {
+    pub status: StudioStatus,
+    pub message: Option<String>,
+    #[serde(rename = "lastUpdate")]
+    pub last_update: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StudioUser {
+    pub id: i64,
+    #[serde(rename = "userName")]
+    pub user_name: String,
+    pub email: Option<String>,
+    pub avatar: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StudioConnection {
+    pub id: i64,
+    #[serde(rename = "userName")]
+    pub user_name: String,
+    pub email: Option<String>,
+    #[serde(rename = "lastActive")]
+    pub last_active: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StudioComputeEnv {
+    pub id: String,
+    pub name: String,
+    pub platform: PlatformId,
+    pub region: Option<String>,
+}
+
+/// A Data Studio container image, e.g. Jupyter, RStudio or VS Code
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StudioTemplate {
+    pub repository: String,
+    pub icon: Option<String>,
+    pub status: Option<String>,
+    pub tool: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StudioConfiguration {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gpu: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpu: Option<i32>,
+    /// Memory in MB
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<i32>,
+    /// IDs of the data links mounted into the studio
+    #[serde(rename = "mountData")]
+    pub mount_data: Vec<String>,
+    #[serde(rename = "condaEnvironment", skip_serializing_if = "Option::is_none")]
+    pub conda_environment: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Studio {
+    #[serde(rename = "sessionId")]
+    pub session_id: String,
+    #[serde(rename = "workspaceId")]
+    pub workspace_id: Option<i64>,
+    pub name: String,
+    pub description: Option<String>,
+    pub user: Option<StudioUser>,
+    /// Connection URL, available once the studio is running
+    #[serde(rename = "studioUrl")]
+    pub studio_url: Option<String>,
+    #[serde(rename = "computeEnv")]
+    pub compute_env: Option<StudioComputeEnv>,
+    pub template: Option<StudioTemplate>,
+    #[serde(default)]
+    pub configuration: StudioConfiguration,
+    #[serde(rename = "dateCreated")]
+    pub date_created: Option<DateTime<Utc>>,
+    #[serde(rename = "lastUpdated")]
+    pub last_updated: Option<DateTime<Utc>>,
+    #[serde(rename = "lastStarted")]
+    pub last_started: Option<DateTime<Utc>>,
+    #[serde(rename = "activeConnections", default)]
+    pub active_connections: Vec<StudioConnection>,
+    #[serde(rename = "statusInfo")]
+    pub status_info: StudioStatusInfo,
+    #[serde(rename = "mountedDataLinks", default)]
+    pub mounted_data_links: Vec<DataLink>,
+}
+
+impl Studio {
+    pub fn status(&self) -> StudioStatus {
+        self.status_info.status
+    }
+
+    /// Most recent activity: the latest connection, or when the studio was started
+    pub fn last_activity(&self) -> Option<DateTime<Utc>> {
+        self.active_connections
+            .iter()
+            .filter_map(|c| c.last_active)
+            .chain(self.last_started)
+            .max()
+    }
+
+    /// A running studio nobody has used since `cutoff`
+    pub fn is_idle_since(&self, cutoff: DateTime<Utc>) -> bool {
+        self.status() == StudioStatus::Running
+            && self.last_activity().is_none_or(|last| last < cutoff)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListStudiosResponse {
+    pub studios: Vec<Studio>,
+    #[serde(rename = "totalSize")]
+    pub total_size: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListStudioTemplatesResponse {
+    pub templates: Vec<StudioTemplate>,
+    #[serde(rename = "totalSize")]
+    pub total_size: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateStudioRequest {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Template container image
+    #[serde(rename = "dataStudioToolUrl")]
+    pub data_studio_tool_url: String,
+    #[serde(rename = "computeEnvId")]
+    pub compute_env_id: String,
+    pub configuration: StudioConfiguration,
+}
+
+impl CreateStudioRequest {
+    pub fn new(
+        name: impl Into<String>,
+        template: impl Into<String>,
+        compute_env_id: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            data_studio_tool_url: template.into(),
+            compute_env_id: compute_env_id.into(),
+            configuration: StudioConfiguration::default(),
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Mounts a data link, by ID, into the studio
+    pub fn mount_data_link(mut self, data_link_id: impl Into<String>) -> Self {
+        self.configuration.mount_data.push(data_link_id.into());
+        self
+    }
+
+    pub fn resources(mut self, cpu: Option<i32>, memory: Option<i32>, gpu: Option<i32>) -> Self {
+        self.configuration.cpu = cpu;
+        self.configuration.memory = memory;
+        self.configuration.gpu = gpu;
+        self
+    }
+}
+
+impl Validate for CreateStudioRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validation::validate_required(&self.name, "name")?;
+        validation::validate_max_length(&self.name, 80, "name")?;
+        validation::validate_required(&self.data_studio_tool_url, "dataStudioToolUrl")?;
+        validation::validate_required(&self.compute_env_id, "computeEnvId")?;
+        if let Some(ref description) = self.description {
+            validation::validate_max_length(description, 2048, "description")?;
+        }
+        if self.configuration.cpu.is_some_and(|cpu| cpu < 1) {
+            return Err(ValidationError::Invalid { field: "cpu", reason: "must be at least 1" });
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateStudioResponse {
+    pub studio: Studio,
+}
+
+/// Returned by the start and stop endpoints
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StudioActionResponse {
+    #[serde(rename = "jobSubmitted")]
+    pub job_submitted: bool,
+    #[serde(rename = "sessionId")]
+    pub session_id: String,
+    #[serde(rename = "statusInfo")]
+    pub status_info: StudioStatusInfo,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_studio_idle_detection() {
+        let studio: Studio = serde_json::from_value(json!({
+            "sessionId": "s1",
+            "name": "notebook",
+            "lastStarted": "2024-01-01T08:00:00Z",
+            "activeConnections": [
+                { "id": 1, "userName": "alice", "lastActive": "2024-01-01T10:00:00Z" }
+            ],
+            "statusInfo": { "status": "running" }
+        }))
+        .unwrap();
+
+        assert_eq!(studio.last_activity(), Some("2024-01-01T10:00:00Z".parse().unwrap()));
+        assert!(studio.is_idle_since("2024-01-01T11:00:00Z".parse().unwrap()));
+        assert!(!studio.is_idle_since("2024-01-01T09:00:00Z".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_create_studio_request() {
+        let request = CreateStudioRequest::new("notebook", "public.cr.seqera.io/platform/data-studio-jupyter:4.2.5-0.7", "ce-1")
+            .mount_data_link("dl-1")
+            .resources(Some(2), Some(8192), None);
+        assert!(request.validate().is_ok());
+
+        let body = serde_json::to_value(&request).unwrap();
+        assert_eq!(body["configuration"], json!({ "cpu": 2, "memory": 8192, "mountData": ["dl-1"] }));
+
+        assert!(CreateStudioRequest::new("", "image", "ce-1").validate().is_err());
+    }
+}
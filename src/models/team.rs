@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
-use super::common::{Validate, ValidationError, validation};
+use super::common::{Validate, ValidationError, validation, WspRole};
+use super::workspace::Workspace;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Team {
@@ -125,4 +126,14 @@ impl Validate for UpdateTeamRequest {
         }
         Ok(())
     }
-} 
\ No newline at end of file
+} 
+
+/// A workspace the team participates in, and the role it has there
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TeamParticipation {
+    pub workspace: Workspace,
+    #[serde(rename = "participantId")]
+    pub participant_id: i64,
+    #[serde(rename = "wspRole")]
+    pub wsp_role: WspRole,
+}
@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use super::common::{Secret, Validate, ValidationError, validation};
+
+/// A personal access token. The token value itself is only returned once, on creation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessToken {
+    pub id: i64,
+    pub name: String,
+    #[serde(rename = "lastUsed")]
+    pub last_used: Option<DateTime<Utc>>,
+    #[serde(rename = "dateCreated")]
+    pub date_created: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListAccessTokensResponse {
+    pub tokens: Vec<AccessToken>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateAccessTokenRequest {
+    pub name: String,
+}
+
+impl Validate for CreateAccessTokenRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validation::validate_required(&self.name, "name")?;
+        validation::validate_max_length(&self.name, 50, "name")?;
+        lazy_static::lazy_static! {
+            static ref NAME_PATTERN: regex::Regex = regex::Regex::new(
+                r"^[a-zA-Z\d](?:[-_a-zA-Z\d]*[a-zA-Z\d])?$"
+            ).unwrap();
+        }
+        validation::validate_pattern(&self.name, &NAME_PATTERN, "name")
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateAccessTokenResponse {
+    /// The token value. It cannot be retrieved again.
+    #[serde(rename = "accessKey")]
+    pub access_key: Secret,
+    pub token: AccessToken,
+}
+
+/// Name for the token replacing `name` in a rotation: any timestamp suffix
+/// from an earlier rotation is replaced with `now`
+pub fn rotated_token_name(name: &str, now: DateTime<Utc>) -> String {
+    lazy_static::lazy_static! {
+        static ref ROTATION_SUFFIX: regex::Regex = regex::Regex::new(r"-\d{14}$").unwrap();
+    }
+    let base = ROTATION_SUFFIX.replace(name, "");
+    let suffix = now.format("%Y%m%d%H%M%S").to_string();
+    // Keep within the 50 character limit
+    let base: String = base.chars().take(50 - suffix.len() - 1).collect();
+    format!("{}-{}", base.trim_end_matches(['-', '_']), suffix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotated_token_name() {
+        let now = "2024-05-01T12:30:00Z".parse().unwrap();
+        assert_eq!(rotated_token_name("ci", now), "ci-20240501123000");
+        assert_eq!(rotated_token_name("ci-20240101000000", now), "ci-20240501123000");
+
+        let long = rotated_token_name(&"a".repeat(60), now);
+        assert_eq!(long.len(), 50);
+        assert!(CreateAccessTokenRequest { name: long }.validate().is_ok());
+    }
+
+    #[test]
+    fn test_access_key_is_masked() {
+        let response: CreateAccessTokenResponse = serde_json::from_value(serde_json::json!({
+            "accessKey": "eyJ0aWQiOjF9.secret",
+            "token": { "id": 1, "name": "ci" }
+        }))
+        .unwrap();
+        assert_eq!(response.access_key.expose(), "eyJ0aWQiOjF9.secret");
+        assert!(!format!("{:?}", response).contains("secret"));
+    }
+}
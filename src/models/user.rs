@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use super::common::{OrgId, OrgRole, WorkspaceId, WspRole};
+
+/// The authenticated user
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserInfo {
+    pub id: i64,
+    #[serde(rename = "userName")]
+    pub user_name: String,
+    pub email: String,
+    #[serde(rename = "firstName")]
+    pub first_name: Option<String>,
+    #[serde(rename = "lastName")]
+    pub last_name: Option<String>,
+    pub organization: Option<String>,
+    pub avatar: Option<String>,
+    #[serde(rename = "lastAccess")]
+    pub last_access: Option<DateTime<Utc>>,
+    #[serde(rename = "dateCreated")]
+    pub date_created: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserInfoResponse {
+    pub user: UserInfo,
+    #[serde(rename = "defaultWorkspaceId")]
+    pub default_workspace_id: Option<WorkspaceId>,
+}
+
+/// An organization, or a workspace within one, that a user has access to.
+///
+/// Rows without a workspace describe the user's membership of the
+/// organization itself.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrgAndWorkspace {
+    #[serde(rename = "orgId")]
+    pub org_id: OrgId,
+    #[serde(rename = "orgName")]
+    pub org_name: String,
+    #[serde(rename = "orgLogoUrl")]
+    pub org_logo_url: Option<String>,
+    #[serde(rename = "workspaceId")]
+    pub workspace_id: Option<WorkspaceId>,
+    #[serde(rename = "workspaceName")]
+    pub workspace_name: Option<String>,
+    #[serde(rename = "workspaceFullName")]
+    pub workspace_full_name: Option<String>,
+    pub visibility: Option<String>,
+    #[serde(default)]
+    pub roles: Vec<String>,
+}
+
+impl OrgAndWorkspace {
+    pub fn is_workspace(&self) -> bool {
+        self.workspace_id.is_some()
+    }
+
+    /// The user's role in the organization, for organization rows
+    pub fn org_role(&self) -> Option<OrgRole> {
+        if self.is_workspace() {
+            return None;
+        }
+        self.roles.iter().find_map(|role| parse_role(role))
+    }
+
+    /// The user's role in the workspace, for workspace rows
+    pub fn workspace_role(&self) -> Option<WspRole> {
+        if !self.is_workspace() {
+            return None;
+        }
+        self.roles.iter().find_map(|role| parse_role(role))
+    }
+
+    /// `org/workspace`, or just `org` for organization rows
+    pub fn full_path(&self) -> String {
+        match &self.workspace_name {
+            Some(workspace) => format!("{}/{}", self.org_name, workspace),
+            None => self.org_name.clone(),
+        }
+    }
+}
+
+fn parse_role<T: serde::de::DeserializeOwned>(role: &str) -> Option<T> {
+    serde_json::from_value(serde_json::Value::String(role.to_lowercase())).ok()
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListUserWorkspacesAndOrgsResponse {
+    #[serde(rename = "orgsAndWorkspaces")]
+    pub orgs_and_workspaces: Vec<OrgAndWorkspace>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_org_and_workspace_roles() {
+        let rows: Vec<OrgAndWorkspace> = serde_json::from_value(json!([
+            { "orgId": 1, "orgName": "acme", "workspaceId": null, "roles": ["owner"] },
+            { "orgId": 1, "orgName": "acme", "workspaceId": 10, "workspaceName": "genomics", "roles": ["maintain"] }
+        ]))
+        .unwrap();
+
+        assert_eq!(rows[0].org_role(), Some(OrgRole::Owner));
+        assert_eq!(rows[0].workspace_role(), None);
+        assert_eq!(rows[0].full_path(), "acme");
+        assert_eq!(rows[1].workspace_role(), Some(WspRole::Maintain));
+        assert_eq!(rows[1].org_role(), None);
+        assert_eq!(rows[1].full_path(), "acme/genomics");
+    }
+}
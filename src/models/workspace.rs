@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use super::common::{Validate, ValidationError, validation, WorkspaceId};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -17,13 +18,23 @@ pub struct WorkspaceList {
     pub workspaces: Vec<Workspace>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum WorkspaceVisibility {
+    #[default]
     Private,
     Shared,
 }
 
+impl fmt::Display for WorkspaceVisibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WorkspaceVisibility::Private => write!(f, "PRIVATE"),
+            WorkspaceVisibility::Shared => write!(f, "SHARED"),
+        }
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct CreateWorkspaceRequestBuilder {
     name: Option<String>,
@@ -0,0 +1,193 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Data Studios settings of a workspace
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StudiosSettings {
+    /// Hours a studio may run before it is stopped; `None` for no limit
+    #[serde(rename = "lifespanHours")]
+    pub lifespan_hours: Option<i32>,
+}
+
+/// Wave containers or Fusion file system settings of a workspace
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ToggleSettings {
+    pub enabled: bool,
+}
+
+/// Who can see the workspace's data links
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DataLinkVisibility {
+    /// Every workspace participant
+    #[default]
+    AllParticipants,
+    /// Only participants with the maintain role or above
+    Maintainers,
+}
+
+impl fmt::Display for DataLinkVisibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DataLinkVisibility::AllParticipants => write!(f, "all"),
+            DataLinkVisibility::Maintainers => write!(f, "maintainers"),
+        }
+    }
+}
+
+impl FromStr for DataLinkVisibility {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "all" | "all_participants" => Ok(DataLinkVisibility::AllParticipants),
+            "maintainers" => Ok(DataLinkVisibility::Maintainers),
+            other => Err(format!("unknown data link visibility '{}', expected all or maintainers", other)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DataLinkSettings {
+    pub visibility: DataLinkVisibility,
+}
+
+/// Response of the `settings/<section>` endpoints
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WorkspaceSettingsResponse<T> {
+    pub settings: T,
+}
+
+/// All settings of a workspace, gathered from the individual settings endpoints
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkspaceSettings {
+    /// Name of the primary compute environment
+    #[serde(rename = "defaultComputeEnv")]
+    pub default_compute_env: Option<String>,
+    #[serde(rename = "waveEnabled")]
+    pub wave_enabled: bool,
+    #[serde(rename = "fusionEnabled")]
+    pub fusion_enabled: bool,
+    /// `None` when the server doesn't support Data Studios
+    pub studios: Option<StudiosSettings>,
+    /// `None` when the server doesn't support data links
+    #[serde(rename = "dataLinks")]
+    pub data_links: Option<DataLinkSettings>,
+}
+
+/// Settings to enforce on a workspace. Unset fields are left as they are.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DesiredWorkspaceSettings {
+    /// Name of the compute environment to make primary
+    #[serde(rename = "defaultComputeEnv", skip_serializing_if = "Option::is_none")]
+    pub default_compute_env: Option<String>,
+    #[serde(rename = "waveEnabled", skip_serializing_if = "Option::is_none")]
+    pub wave_enabled: Option<bool>,
+    #[serde(rename = "fusionEnabled", skip_serializing_if = "Option::is_none")]
+    pub fusion_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub studios: Option<StudiosSettings>,
+    #[serde(rename = "dataLinks", skip_serializing_if = "Option::is_none")]
+    pub data_links: Option<DataLinkSettings>,
+}
+
+/// A setting that differs from the desired value
+#[derive(Debug, Clone, PartialEq)]
+pub struct SettingChange {
+    pub setting: &'static str,
+    pub from: String,
+    pub to: String,
+}
+
+impl fmt::Display for SettingChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} -> {}", self.setting, self.from, self.to)
+    }
+}
+
+impl DesiredWorkspaceSettings {
+    /// The changes needed to bring `current` in line with these settings
+    pub fn diff(&self, current: &WorkspaceSettings) -> Vec<SettingChange> {
+        let mut changes = Vec::new();
+        let mut check = |setting, from: String, to: Option<String>| {
+            if let Some(to) = to.filter(|to| *to != from) {
+                changes.push(SettingChange { setting, from, to });
+            }
+        };
+
+        check(
+            "default compute env",
+            current.default_compute_env.clone().unwrap_or_else(|| "none".to_string()),
+            self.default_compute_env.clone(),
+        );
+        check("wave", current.wave_enabled.to_string(), self.wave_enabled.map(|v| v.to_string()));
+        check("fusion", current.fusion_enabled.to_string(), self.fusion_enabled.map(|v| v.to_string()));
+        check(
+            "studio lifespan hours",
+            lifespan(current.studios.as_ref()),
+            self.studios.as_ref().map(|s| lifespan(Some(s))),
+        );
+        check(
+            "data link visibility",
+            current
+                .data_links
+                .as_ref()
+                .map(|d| d.visibility.to_string())
+                .unwrap_or_else(|| "unsupported".to_string()),
+            self.data_links.as_ref().map(|d| d.visibility.to_string()),
+        );
+        changes
+    }
+}
+
+fn lifespan(settings: Option<&StudiosSettings>) -> String {
+    match settings {
+        Some(StudiosSettings { lifespan_hours: Some(hours) }) => hours.to_string(),
+        Some(_) => "unlimited".to_string(),
+        None => "unsupported".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_only_reports_set_and_different_fields() {
+        let current = WorkspaceSettings {
+            default_compute_env: Some("aws-old".to_string()),
+            wave_enabled: true,
+            fusion_enabled: false,
+            studios: Some(StudiosSettings { lifespan_hours: None }),
+            data_links: Some(DataLinkSettings::default()),
+        };
+        let desired = DesiredWorkspaceSettings {
+            default_compute_env: Some("aws-prod".to_string()),
+            wave_enabled: Some(true),
+            fusion_enabled: Some(true),
+            studios: Some(StudiosSettings { lifespan_hours: Some(8) }),
+            data_links: None,
+        };
+
+        let changes: Vec<_> = desired.diff(&current).iter().map(|c| c.to_string()).collect();
+        assert_eq!(
+            changes,
+            vec![
+                "default compute env: aws-old -> aws-prod",
+                "fusion: false -> true",
+                "studio lifespan hours: unlimited -> 8",
+            ]
+        );
+        assert!(DesiredWorkspaceSettings::default().diff(&current).is_empty());
+    }
+
+    #[test]
+    fn test_data_link_visibility_serialization() {
+        let settings: DataLinkSettings =
+            serde_json::from_value(serde_json::json!({ "visibility": "MAINTAINERS" })).unwrap();
+        assert_eq!(settings.visibility, DataLinkVisibility::Maintainers);
+        assert_eq!("all".parse(), Ok(DataLinkVisibility::AllParticipants));
+    }
+}